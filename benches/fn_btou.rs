@@ -6,7 +6,10 @@ use brunch::{
 	Bench,
 	benches,
 };
-use dactyl::traits::BytesToUnsigned;
+use dactyl::traits::{
+	BytesToUnsigned,
+	BytesToUnsignedFixed,
+};
 
 
 
@@ -43,6 +46,22 @@ benches!(
 
 	Bench::spacer(),
 
+	Bench::new("u32::btou(12345678)")
+		.run(|| u32::btou(b"12345678")),
+
+	Bench::new("u32::btou_fixed(12345678)")
+		.run(|| u32::btou_fixed(b"12345678")),
+
+	Bench::spacer(),
+
+	Bench::new("u64::btou(1234567890123456)")
+		.run(|| u64::btou(b"1234567890123456")),
+
+	Bench::new("u64::btou_fixed(1234567890123456)")
+		.run(|| u64::btou_fixed(b"1234567890123456")),
+
+	Bench::spacer(),
+
 	Bench::new("u128::btou(340282366920938463463374607431768211455)")
 		.run(|| u128::btou(b"340282366920938463463374607431768211455")),
 
@@ -6,7 +6,10 @@ use brunch::{
 	Bench,
 	benches,
 };
-use dactyl::NiceU16;
+use dactyl::{
+	NiceSeparator,
+	NiceU16,
+};
 
 benches!(
 	Bench::new("dactyl::NiceU16::from(0)")
@@ -27,8 +30,28 @@ benches!(
 	Bench::new("dactyl::NiceU16::from(u16::MAX)")
 		.run(|| NiceU16::from(u16::MAX)),
 
-	Bench::new("dactyl::NiceU16::with_separator(40_999, b'_')")
-		.run(|| NiceU16::with_separator(40_999_u16, b'_')),
+	Bench::new("dactyl::NiceU16::with_nice_separator(40_999, NiceSeparator::Underscore)")
+		.run(|| NiceU16::with_nice_separator(40_999_u16, NiceSeparator::Underscore)),
+
+	Bench::spacer(),
+
+	Bench::new("nice_u16_range_from(0..1000)").run(|| {
+		let mut len = 0;
+		for i in 0..1000_u16 {
+			let last = NiceU16::from(i);
+			len += last.len();
+		}
+		len
+	}),
+
+	Bench::new("nice_u16_range_from(all)").run(|| {
+		let mut len = 0;
+		for i in 0..=u16::MAX {
+			let last = NiceU16::from(i);
+			len += last.len();
+		}
+		len
+	}),
 
 	Bench::spacer(),
 
@@ -6,7 +6,10 @@ use brunch::{
 	Bench,
 	benches,
 };
-use dactyl::NiceU32;
+use dactyl::{
+	NiceSeparator,
+	NiceU32,
+};
 
 benches!(
 	Bench::new("dactyl::NiceU32::from(0)")
@@ -27,6 +30,6 @@ benches!(
 	Bench::new("dactyl::NiceU32::from(u32::MAX)")
 		.run(|| NiceU32::from(u32::MAX)),
 
-	Bench::new("dactyl::NiceU32::with_separator(777_804_132, b'_')")
-		.run(|| NiceU32::with_separator(777_804_132_u32, b'_')),
+	Bench::new("dactyl::NiceU32::with_nice_separator(777_804_132, NiceSeparator::Underscore)")
+		.run(|| NiceU32::with_nice_separator(777_804_132_u32, NiceSeparator::Underscore)),
 );
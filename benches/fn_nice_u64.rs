@@ -6,7 +6,10 @@ use brunch::{
 	Bench,
 	benches,
 };
-use dactyl::NiceU64;
+use dactyl::{
+	NiceSeparator,
+	NiceU64,
+};
 
 benches!(
 	Bench::new("dactyl::NiceU64::from(0)")
@@ -24,6 +27,16 @@ benches!(
 	Bench::new("dactyl::NiceU64::from(u64::MAX)")
 		.run(|| NiceU64::from(u64::MAX)),
 
-	Bench::new("dactyl::NiceU64::with_separator(1_999_999_999_999, b'_')")
-		.run(|| NiceU64::with_separator(1_999_999_999_999, b'_')),
+	Bench::new("dactyl::NiceU64::with_nice_separator(1_999_999_999_999, NiceSeparator::Underscore)")
+		.run(|| NiceU64::with_nice_separator(1_999_999_999_999, NiceSeparator::Underscore)),
+
+	Bench::new("dactyl::NiceU64::extend_vec(<1000 values>)")
+		.run_seeded_with(
+			|| (0..1000_u64).map(|n| n * 1_999_999_999).collect::<Vec<u64>>(),
+			|v| {
+				let mut out = Vec::with_capacity(v.len() * 27);
+				NiceU64::extend_vec(&v, &mut out, NiceSeparator::Comma, b'\n');
+				out
+			},
+		),
 );
@@ -19,5 +19,16 @@ benches!(
 		.run(|| NiceU8::from(101_u8)),
 
 	Bench::new("dactyl::NiceU8::from(u8::MAX)")
-		.run(|| NiceU8::from(u8::MAX))
+		.run(|| NiceU8::from(u8::MAX)),
+
+	Bench::spacer(),
+
+	Bench::new("nice_u8_range_from(all)").run(|| {
+		let mut len = 0;
+		for i in 0..=u8::MAX {
+			let last = NiceU8::from(i);
+			len += last.len();
+		}
+		len
+	}),
 );
@@ -2,8 +2,8 @@
 # Dactyl: Build Script.
 
 This is used to pre-compile all of the integer-to-integer SaturatingFrom
-implementations because they're an utter nightmare without some degree of
-automation.
+implementations — and the mixed-width saturating arithmetic helpers — because
+they're an utter nightmare without some degree of automation.
 
 But don't worry, it's still a nightmare. Haha.
 */
@@ -41,6 +41,23 @@ macro_rules! numext {
 
 numext! { u8, u16, u32, u64, u128, i8, i16, i32, i64, i128 }
 
+/// # Primitive Integers.
+///
+/// Names, bit widths, and signedness of the fixed-size integer types, used
+/// for working out the go-between types for the arithmetic helpers.
+const INTS: [(&str, u32, bool); 10] = [
+	("u8", 8, false),
+	("u16", 16, false),
+	("u32", 32, false),
+	("u64", 64, false),
+	("u128", 128, false),
+	("i8", 8, true),
+	("i16", 16, true),
+	("i32", 32, true),
+	("i64", 64, true),
+	("i128", 128, true),
+];
+
 
 
 #[derive(Clone, Copy)]
@@ -186,10 +203,8 @@ fn main() {
 	assert_eq!(AnyNum::from(-12345_i32).to_string(), "-12_345", "Bug: Number formatting is wrong!");
 
 	// Compile and write the impls!
-	let data = build_impls();
-	File::create(out_path("dactyl-saturation.rs"))
-		.and_then(|mut f| f.write_all(data.as_bytes()).and_then(|_| f.flush()))
-		.expect("Unable to save drive data.");
+	write_file("dactyl-saturation.rs", &build_impls());
+	write_file("dactyl-saturating-ops.rs", &build_ops());
 }
 
 /// # Build Impls.
@@ -232,6 +247,7 @@ fn build_impls() -> String {
 				&mut out,
 				"
 #[cfg(target_pointer_width = \"{}\")]
+/// # Sized Implementations.
 mod sized {{
 	use super::SaturatingFrom;
 
@@ -284,6 +300,69 @@ mod sized {{
 	out
 }
 
+/// # Build Arithmetic Impls.
+///
+/// Generate the `saturating_ops!` invocations for all of the integer pairs
+/// supported by the mixed-width `SaturatingAdd`/`Sub`/`Mul` traits, and
+/// return them as a string.
+///
+/// Most pairs can simply be widened to a common type that holds every value
+/// of both, but there is nothing bigger than `u128`/`i128`, so those pairings
+/// get dedicated (sign-aware) implementations instead.
+fn build_ops() -> String {
+	let mut out = String::new();
+
+	for (to, to_bits, to_signed) in INTS {
+		for (from, from_bits, from_signed) in INTS {
+			// Unsigned/unsigned just needs the bigger of the two.
+			if ! to_signed && ! from_signed {
+				writeln!(out, "saturating_ops!({to}, {from}, u{});", to_bits.max(from_bits)).unwrap();
+				continue;
+			}
+
+			// Otherwise we need a signed type big enough for both.
+			let bits = |b: u32, signed: bool| if signed { b } else { b * 2 };
+			let bits = bits(to_bits, to_signed).max(bits(from_bits, from_signed));
+			if bits <= 128 {
+				writeln!(out, "saturating_ops!({to}, {from}, i{bits});").unwrap();
+			}
+			else if to_signed {
+				writeln!(out, "saturating_ops!(@u128 {to});").unwrap();
+			}
+			else {
+				writeln!(out, "saturating_ops!(@unsigned {from});").unwrap();
+			}
+		}
+	}
+
+	// The sized types piggy-back off their fixed-size equivalents.
+	let all = INTS.iter().map(|(t, _, _)| *t).collect::<Vec<_>>().join(", ");
+	for (unsigned, signed) in [("u16", "i16"), ("u32", "i32"), ("u64", "i64")] {
+		writeln!(
+			out,
+			"
+#[cfg(target_pointer_width = \"{}\")]
+/// # Sized Implementations.
+mod sized {{
+	use super::{{
+		SaturatingAdd,
+		SaturatingFrom,
+		SaturatingMul,
+		SaturatingSub,
+	}};
+
+	saturating_ops!(@sized usize as {unsigned}, {all});
+	saturating_ops!(@sized isize as {signed}, {all});
+	saturating_ops!(@blanket usize as {unsigned});
+	saturating_ops!(@blanket isize as {signed});
+}}",
+			&unsigned[1..],
+		).unwrap();
+	}
+
+	out
+}
+
 /// # Out path.
 ///
 /// This generates a (file/dir) path relative to `OUT_DIR`.
@@ -294,6 +373,15 @@ fn out_path(name: &str) -> PathBuf {
 	out
 }
 
+/// # Write File.
+///
+/// Save the generated code to `OUT_DIR`.
+fn write_file(name: &str, data: &str) {
+	File::create(out_path(name))
+		.and_then(|mut f| f.write_all(data.as_bytes()).and_then(|_| f.flush()))
+		.expect("Unable to save drive data.");
+}
+
 /// # Write Cast Conditional.
 ///
 /// This writes the body of a `saturating_from()` block, clamping as needed.
@@ -0,0 +1,310 @@
+/*!
+# Dactyl: Errors
+*/
+
+use std::{
+	error::Error,
+	fmt,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Overflow Error.
+///
+/// This error is returned by conversions that would otherwise have to
+/// truncate or saturate an out-of-range value. Its `Display` implementation
+/// formats the offending value (and the maximum) with thousands separators
+/// for easy reading.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::duration_from_millis_checked;
+///
+/// let err = duration_from_millis_checked(u128::MAX).unwrap_err();
+/// assert_eq!(err.value(), u128::MAX);
+/// assert_eq!(
+///     err.to_string(),
+///     "340,282,366,920,938,463,463,374,607,431,768,211,455 exceeds the maximum of 18,446,744,073,709,551,615,999.",
+/// );
+/// ```
+pub struct NiceOverflowError {
+	/// # Value.
+	value: u128,
+
+	/// # Maximum.
+	max: u128,
+}
+
+impl Error for NiceOverflowError {}
+
+impl fmt::Display for NiceOverflowError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut value = [0_u8; BUF_SIZE];
+		let mut max = [0_u8; BUF_SIZE];
+		write!(
+			f,
+			"{} exceeds the maximum of {}.",
+			nice_u128(self.value, &mut value),
+			nice_u128(self.max, &mut max),
+		)
+	}
+}
+
+impl NiceOverflowError {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new error for `value`, which exceeded `max`.
+	pub const fn new(value: u128, max: u128) -> Self { Self { value, max } }
+
+	#[must_use]
+	/// # Value.
+	///
+	/// Return the offending value.
+	pub const fn value(&self) -> u128 { self.value }
+
+	#[must_use]
+	/// # Maximum.
+	///
+	/// Return the maximum allowed value.
+	pub const fn max(&self) -> u128 { self.max }
+}
+
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Divide By Zero Error.
+///
+/// This error is returned by the [`NicePercent`](crate::NicePercent) integer
+/// ratio conversions when the denominator is zero.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceDivideByZeroError, NicePercent};
+///
+/// assert_eq!(
+///     NicePercent::try_from((1_u32, 0_u32)),
+///     Err(NiceDivideByZeroError),
+/// );
+/// assert_eq!(NiceDivideByZeroError.to_string(), "Cannot divide by zero.");
+/// ```
+pub struct NiceDivideByZeroError;
+
+impl Error for NiceDivideByZeroError {}
+
+impl fmt::Display for NiceDivideByZeroError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Cannot divide by zero.")
+	}
+}
+
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Separator Error.
+///
+/// This error is returned when converting a byte or `char` that doesn't
+/// correspond to any [`NiceSeparator`](crate::NiceSeparator).
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceSeparator, NiceSeparatorError};
+///
+/// assert_eq!(NiceSeparator::try_from(b'_'), Ok(NiceSeparator::Underscore));
+/// assert_eq!(NiceSeparator::try_from('7'), Err(NiceSeparatorError));
+/// assert_eq!(NiceSeparatorError.to_string(), "Invalid separator.");
+/// ```
+pub struct NiceSeparatorError;
+
+impl Error for NiceSeparatorError {}
+
+impl fmt::Display for NiceSeparatorError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Invalid separator.")
+	}
+}
+
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Parse (Byte) Error.
+///
+/// This error is returned by the `*_checked` parsing methods —
+/// [`BytesToUnsigned::btou_checked`](crate::traits::BytesToUnsigned::btou_checked),
+/// [`BytesToSigned::btoi_checked`](crate::traits::BytesToSigned::btoi_checked),
+/// [`HexToUnsigned::htou_checked`](crate::traits::HexToUnsigned::htou_checked),
+/// and [`HexToSigned::htoi_checked`](crate::traits::HexToSigned::htoi_checked)
+/// — to explain _why_ a slice could not be parsed.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{
+///     ParseByteError,
+///     traits::BytesToUnsigned,
+/// };
+///
+/// assert_eq!(u8::btou_checked(b"123"), Ok(123));
+/// assert_eq!(u8::btou_checked(b""), Err(ParseByteError::Empty));
+/// assert_eq!(u8::btou_checked(b"12x"), Err(ParseByteError::InvalidDigit(2)));
+/// assert_eq!(u8::btou_checked(b"256"), Err(ParseByteError::Overflow));
+///
+/// assert_eq!(
+///     u8::btou_checked(b"12x").unwrap_err().to_string(),
+///     "Invalid digit at index 2.",
+/// );
+/// ```
+pub enum ParseByteError {
+	/// # Empty.
+	///
+	/// The slice was empty.
+	Empty,
+
+	/// # Invalid Digit.
+	///
+	/// The slice contained a non-digit at the given index.
+	InvalidDigit(usize),
+
+	/// # Overflow.
+	///
+	/// The value was too big (or small) for the type.
+	Overflow,
+
+	/// # Zero.
+	///
+	/// The value was zero, but the type is `NonZero`.
+	Zero,
+}
+
+impl Error for ParseByteError {}
+
+impl fmt::Display for ParseByteError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Empty => f.write_str("Cannot parse an empty slice."),
+			Self::InvalidDigit(idx) => write!(f, "Invalid digit at index {idx}."),
+			Self::Overflow => f.write_str("The value is out of range."),
+			Self::Zero => f.write_str("The value cannot be zero."),
+		}
+	}
+}
+
+impl ParseByteError {
+	/// # Diagnose (Decimal).
+	///
+	/// Figure out why a decimal parse failed. If `signed`, a leading `+` or
+	/// `-` is allowed, so long as something follows it.
+	///
+	/// Note: this assumes the parse _did_ fail.
+	pub(crate) fn from_decimal(src: &[u8], signed: bool) -> Self {
+		let skip = usize::from(
+			signed && 1 < src.len() && matches!(src[0], b'+' | b'-')
+		);
+
+		if src.is_empty() { Self::Empty }
+		else if let Some(idx) = src.iter().skip(skip).position(|b| ! b.is_ascii_digit()) {
+			Self::InvalidDigit(idx + skip)
+		}
+		// Zeroes can't overflow, so must have been a NonZero thing.
+		else if src[skip..].iter().all(|&b| b == b'0') { Self::Zero }
+		else { Self::Overflow }
+	}
+
+	/// # Diagnose (Hex).
+	///
+	/// Figure out why a hex parse failed.
+	///
+	/// Note: this assumes the parse _did_ fail.
+	pub(crate) fn from_hex(src: &[u8]) -> Self {
+		if src.is_empty() { Self::Empty }
+		else if let Some(idx) = src.iter().position(|b| ! b.is_ascii_hexdigit()) {
+			Self::InvalidDigit(idx)
+		}
+		else { Self::Overflow }
+	}
+}
+
+
+/// # Buffer Size.
+///
+/// 340282366920938463463374607431768211455 + twelve commas = 51 bytes.
+const BUF_SIZE: usize = 51;
+
+#[expect(unsafe_code, reason = "For performance.")]
+/// # Nice u128.
+///
+/// There's no `NiceU128`, but error messages need only be built once, so a
+/// simple digit-by-digit approach is good enough here.
+fn nice_u128(mut num: u128, buf: &mut [u8; BUF_SIZE]) -> &str {
+	let mut from = BUF_SIZE;
+	let mut digits = 0;
+	loop {
+		if digits != 0 && digits % 3 == 0 {
+			from -= 1;
+			buf[from] = b',';
+		}
+		from -= 1;
+		buf[from] = (num % 10) as u8 + b'0';
+		digits += 1;
+		num /= 10;
+		if num == 0 { break; }
+	}
+
+	// Safety: all bytes are ASCII.
+	unsafe { std::str::from_utf8_unchecked(&buf[from..]) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_u128() {
+		let mut buf = [0_u8; BUF_SIZE];
+		let mut rng = fastrand::Rng::new();
+		for i in [0, 1, 999, 1000, u128::MAX].into_iter()
+			.chain(std::iter::repeat_with(|| rng.u128(..)).take(1000))
+		{
+			assert_eq!(nice_u128(i, &mut buf), i.to_formatted_string(&Locale::en));
+		}
+	}
+
+	#[test]
+	fn t_parse_byte_error() {
+		for (src, signed, expected) in [
+			(&b""[..], false, ParseByteError::Empty),
+			(b"", true, ParseByteError::Empty),
+			(b"-", false, ParseByteError::InvalidDigit(0)),
+			(b"-", true, ParseByteError::InvalidDigit(0)),
+			(b"-1", false, ParseByteError::InvalidDigit(0)),
+			(b"-1", true, ParseByteError::Overflow),
+			(b"+-1", true, ParseByteError::InvalidDigit(1)),
+			(b"123 ", false, ParseByteError::InvalidDigit(3)),
+			(b"000", false, ParseByteError::Zero),
+			(b"-000", true, ParseByteError::Zero),
+			(b"999", false, ParseByteError::Overflow),
+		] {
+			assert_eq!(
+				ParseByteError::from_decimal(src, signed),
+				expected,
+				"{:?}", String::from_utf8_lossy(src),
+			);
+		}
+
+		assert_eq!(ParseByteError::from_hex(b""), ParseByteError::Empty);
+		assert_eq!(ParseByteError::from_hex(b"aG"), ParseByteError::InvalidDigit(1));
+		assert_eq!(ParseByteError::from_hex(b"000"), ParseByteError::Overflow);
+	}
+}
@@ -0,0 +1,71 @@
+/*!
+# Dactyl: `defmt` Support.
+
+This (optional) module implements `defmt::Format` for the `Nice*` types,
+writing the formatted slice directly rather than requiring a `Display`
+round trip.
+*/
+
+use crate::{
+	NiceBar,
+	NiceBigint,
+	NiceBits,
+	NiceClock,
+	NiceClock12,
+	NiceDateTime,
+	NiceDiff,
+	NiceDivision,
+	NiceElapsed,
+	NiceFixed,
+	NiceHex,
+	NiceIso8601,
+	NiceMoney,
+	NicePadded,
+	NicePaddedFloat,
+	NicePercentChange,
+	NicePow2,
+	NiceShort,
+	NiceU64Sep,
+	NiceWrapper,
+};
+
+
+
+impl<const S: usize> defmt::Format for NiceWrapper<S> {
+	#[inline]
+	fn format(&self, f: defmt::Formatter<'_>) {
+		defmt::write!(f, "{=str}", self.as_str());
+	}
+}
+
+impl<const W: usize> defmt::Format for NicePadded<W> {
+	#[inline]
+	fn format(&self, f: defmt::Formatter<'_>) {
+		defmt::write!(f, "{=str}", self.as_str());
+	}
+}
+
+impl<const SEP: u8> defmt::Format for NiceU64Sep<SEP> {
+	#[inline]
+	fn format(&self, f: defmt::Formatter<'_>) {
+		defmt::write!(f, "{=str}", self.as_str());
+	}
+}
+
+/// # Helper: Format Impls.
+macro_rules! format {
+	($($nice:ty),+ $(,)?) => ($(
+		impl defmt::Format for $nice {
+			#[inline]
+			fn format(&self, f: defmt::Formatter<'_>) {
+				defmt::write!(f, "{=str}", self.as_str());
+			}
+		}
+	)+);
+}
+
+format!(
+	NiceBar, NiceBigint, NiceBits, NiceClock, NiceClock12, NiceDateTime,
+	NiceDiff, NiceDivision, NiceElapsed, NiceFixed, NiceHex, NiceIso8601,
+	NiceMoney, NicePaddedFloat, NicePercentChange, NicePow2, NiceShort,
+);
@@ -0,0 +1,187 @@
+/*!
+# Dactyl: Digit Grouping.
+*/
+
+use crate::NiceSeparator;
+
+
+/// # Group Digits.
+///
+/// Copy an already-stringified integer from `src` to `out`, inserting `sep`
+/// between every three digits, and return the number of bytes written.
+///
+/// This is the grouping step on its own, for cases where the digits are
+/// already sitting in a buffer (e.g. from `itoa`). A single leading `+` or
+/// `-` is passed through as-is.
+///
+/// The source is _not_ validated; the bytes after the sign are assumed to be
+/// ASCII digits without leading zeroes.
+///
+/// The output needs room for `src.len() + (digits - 1) / 3` bytes, where
+/// `digits` is the length of `src` minus the sign, if any. Anything past the
+/// returned length is left untouched.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceSeparator;
+///
+/// let mut buf = [0_u8; 32];
+///
+/// let len = dactyl::group_digits(b"1234567", NiceSeparator::Comma, &mut buf);
+/// assert_eq!(&buf[..len], b"1,234,567");
+///
+/// let len = dactyl::group_digits(b"-9876543210", NiceSeparator::Underscore, &mut buf);
+/// assert_eq!(&buf[..len], b"-9_876_543_210");
+///
+/// let len = dactyl::group_digits(b"123", NiceSeparator::Comma, &mut buf);
+/// assert_eq!(&buf[..len], b"123");
+///
+/// // Empty in, empty out.
+/// assert_eq!(dactyl::group_digits(b"", NiceSeparator::Comma, &mut buf), 0);
+/// ```
+///
+/// ## Panics
+///
+/// This method will panic if `out` is too small to hold the result.
+pub fn group_digits<S: Into<NiceSeparator>>(src: &[u8], sep: S, out: &mut [u8])
+-> usize {
+	let sep = sep.into().as_byte();
+
+	// Pass the sign through.
+	let (sign, digits) = match src {
+		[s @ (b'+' | b'-'), rest @ ..] => (Some(*s), rest),
+		_ => (None, src),
+	};
+	let len = src.len() + digits.len().saturating_sub(1) / 3;
+	assert!(len <= out.len(), "Output buffer is too small.");
+
+	let mut to = 0;
+	if let Some(s) = sign {
+		out[0] = s;
+		to += 1;
+	}
+	if digits.is_empty() { return to; }
+
+	// The first group might be short; the rest have three digits apiece.
+	let head = match digits.len() % 3 { 0 => 3, n => n };
+	out[to..to + head].copy_from_slice(&digits[..head]);
+	to += head;
+	for chunk in digits[head..].chunks_exact(3) {
+		out[to] = sep;
+		out[to + 1..to + 4].copy_from_slice(chunk);
+		to += 4;
+	}
+
+	to
+}
+
+
+/// # Helper: Width Predictions.
+macro_rules! nice_width {
+	($($fn:ident $ty:ident $nice:ident $ex:literal $width:literal),+ $(,)?) => ($(
+		#[must_use]
+		#[doc = concat!("# Nice Width (`", stringify!($ty), "`).")]
+		///
+		#[doc = concat!("Return the exact length — in bytes, separators and all — of the [`", stringify!($nice), "`](crate::", stringify!($nice), ")")]
+		/// representation of `num`, without actually formatting it.
+		///
+		/// This is handy for table layouts and the like, where column widths
+		/// need to be worked out before any rendering takes place.
+		///
+		/// Custom separators are always single bytes, so do not affect the
+		/// result. (Myriad grouping, however, does.)
+		///
+		/// ## Examples
+		///
+		/// ```
+		#[doc = concat!("use dactyl::{", stringify!($fn), ", ", stringify!($nice), "};")]
+		///
+		#[doc = concat!("assert_eq!(", stringify!($fn), "(0), 1);")]
+		#[doc = concat!("assert_eq!(", stringify!($fn), "(", $ex, "), ", $width, ");")]
+		#[doc = concat!("assert_eq!(", stringify!($fn), "(", $ex, "), ", stringify!($nice), "::from(", $ex, "_", stringify!($ty), ").len());")]
+		/// ```
+		pub const fn $fn(num: $ty) -> usize {
+			let digits = crate::traits::digit_count(num as u128) as usize;
+			digits + (digits - 1) / 3
+		}
+	)+);
+}
+
+nice_width!(
+	nice_width_u8 u8 NiceU8 "255" 3,
+	nice_width_u16 u16 NiceU16 "1234" 5,
+	nice_width_u32 u32 NiceU32 "1234567" 9,
+	nice_width_u64 u64 NiceU64 "1234567890" 13,
+);
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		NiceU16,
+		NiceU32,
+		NiceU64,
+		NiceU8,
+	};
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_group_digits() {
+		let mut buf = [0_u8; 40];
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.i64(..)).take(10_000).chain([0, -1, 999, -1000, i64::MIN, i64::MAX]) {
+			let src = num.to_string();
+			let len = group_digits(src.as_bytes(), NiceSeparator::Comma, &mut buf);
+			assert_eq!(
+				std::str::from_utf8(&buf[..len]),
+				Ok(num.to_formatted_string(&Locale::en).as_str()),
+			);
+
+			// With an explicit plus.
+			if 0 <= num {
+				let len = group_digits(format!("+{src}").as_bytes(), NiceSeparator::Comma, &mut buf);
+				assert_eq!(
+					std::str::from_utf8(&buf[..len]),
+					Ok(format!("+{}", num.to_formatted_string(&Locale::en)).as_str()),
+				);
+			}
+		}
+
+		// A lone sign.
+		assert_eq!(group_digits(b"-", NiceSeparator::Comma, &mut buf), 1);
+		assert_eq!(buf[0], b'-');
+
+		// Exact fit.
+		let mut buf = [0_u8; 5];
+		assert_eq!(group_digits(b"1000", NiceSeparator::Comma, &mut buf), 5);
+		assert_eq!(&buf, b"1,000");
+	}
+
+	#[test]
+	fn t_nice_width() {
+		for i in 0..=u16::MAX {
+			assert_eq!(nice_width_u16(i), NiceU16::from(i).len());
+			if let Ok(i) = u8::try_from(i) {
+				assert_eq!(nice_width_u8(i), NiceU8::from(i).len());
+			}
+		}
+
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000).chain([0, 1, 9, 10, 999, 1000, u64::MAX]) {
+			assert_eq!(nice_width_u64(i), NiceU64::from(i).len());
+			if let Ok(i) = u32::try_from(i) {
+				assert_eq!(nice_width_u32(i), NiceU32::from(i).len());
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Output buffer is too small.")]
+	fn t_group_digits_small() {
+		let mut buf = [0_u8; 4];
+		group_digits(b"1000", NiceSeparator::Comma, &mut buf);
+	}
+}
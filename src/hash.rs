@@ -4,9 +4,18 @@
 
 #![expect(clippy::cast_lossless, reason = "False positive.")]
 
-use std::hash::{
-	BuildHasherDefault,
-	Hasher,
+use std::{
+	collections::{
+		hash_map::RandomState,
+		HashMap,
+		HashSet,
+	},
+	fmt,
+	hash::{
+		BuildHasher,
+		BuildHasherDefault,
+		Hasher,
+	},
 };
 
 
@@ -114,6 +123,87 @@ use std::hash::{
 /// the last integer written to it, ignoring everything else.
 pub type NoHash = BuildHasherDefault<NoHasher>;
 
+/// # No-Hash `HashMap`.
+///
+/// This is a convenience alias for a [`HashMap`] using [`NoHash`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NoHashMap;
+///
+/// let mut map: NoHashMap<u32, &str> = NoHashMap::default();
+/// assert_eq!(map.insert(1, "Hello"), None);
+/// assert_eq!(map.insert(1, "World"), Some("Hello"));
+/// ```
+pub type NoHashMap<K, V> = HashMap<K, V, NoHash>;
+
+/// # No-Hash `HashSet`.
+///
+/// This is a convenience alias for a [`HashSet`] using [`NoHash`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NoHashSet;
+///
+/// let mut set: NoHashSet<u32> = NoHashSet::default();
+/// assert!(set.insert(1));
+/// assert!(! set.insert(1));
+/// ```
+pub type NoHashSet<T> = HashSet<T, NoHash>;
+
+
+
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+/// # No-Hash (Passthrough) Hash State (Const).
+///
+/// This works exactly like [`NoHash`], but can be constructed in `const`
+/// contexts via [`NoHashState::new`], which the standard library's
+/// [`BuildHasherDefault`] only supports as of Rust 1.85.
+///
+/// Combined with the (likewise Rust 1.85+) `const` [`HashMap::with_hasher`]
+/// and [`HashSet::with_hasher`] constructors, this allows passthrough-hashed
+/// collections to be embedded in `static`s.
+///
+/// The same type restrictions — and panics — apply as with [`NoHash`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NoHash, NoHashState};
+/// use std::{
+///     collections::HashMap,
+///     hash::BuildHasher,
+/// };
+///
+/// const STATE: NoHashState = NoHashState::new();
+///
+/// let mut map: HashMap<u16, &str, NoHashState> = HashMap::with_hasher(STATE);
+/// assert_eq!(map.insert(5, "Hello"), None);
+/// assert_eq!(map.insert(5, "World"), Some("Hello"));
+///
+/// // Same as NoHash.
+/// assert_eq!(STATE.hash_one(5_u16), NoHash::default().hash_one(5_u16));
+/// ```
+pub struct NoHashState;
+
+impl BuildHasher for NoHashState {
+	type Hasher = NoHasher;
+
+	#[inline]
+	fn build_hasher(&self) -> Self::Hasher { NoHasher(0) }
+}
+
+impl NoHashState {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new instance. This is equivalent to [`NoHashState::default`],
+	/// but `const`.
+	pub const fn new() -> Self { Self }
+}
+
 
 
 #[derive(Debug, Default, Copy, Clone)]
@@ -178,6 +268,168 @@ impl Hasher for NoHasher {
 
 
 
+#[derive(Clone, Copy)]
+/// # Randomized No-Hash Hash State.
+///
+/// [`NoHash`] is blazing fast, but because integer keys pass straight through
+/// to the hash table, an attacker able to choose the keys can easily force
+/// collisions — and quadratic performance — by picking values that share the
+/// same low bits.
+///
+/// `NoHashRandom` offers a middle ground between raw passthrough and the
+/// standard library's (relatively slow) `SipHash`: the integer written is
+/// combined with a random per-map seed and run through a single folded
+/// multiplication, spreading every input bit across the whole output.
+///
+/// This isn't cryptographically strong, but it does make collisions
+/// impractical to precompute.
+///
+/// Each call to [`NoHashRandom::default`] or [`NoHashRandom::new`] generates
+/// a fresh seed, courtesy of [`RandomState`]. For reproducible results, use
+/// [`NoHashRandom::with_seed`] instead.
+///
+/// The same type restrictions — and panics — apply as with [`NoHash`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NoHashRandom;
+/// use std::collections::{HashMap, HashSet};
+///
+/// let mut set: HashSet<u32, NoHashRandom> = HashSet::default();
+/// assert!(set.insert(0_u32));
+/// assert!(set.insert(1_u32));
+/// assert!(! set.insert(1_u32)); // Not unique!
+///
+/// let mut map: HashMap<i16, &str, NoHashRandom> = HashMap::with_hasher(
+///     NoHashRandom::with_seed(12_345),
+/// );
+/// assert_eq!(map.insert(-1_i16, "Hello"), None);
+/// assert_eq!(map.insert(-1_i16, "World"), Some("Hello")); // Not unique!
+/// ```
+pub struct NoHashRandom(u64);
+
+impl BuildHasher for NoHashRandom {
+	type Hasher = NoHasherRandom;
+
+	#[inline]
+	fn build_hasher(&self) -> Self::Hasher {
+		NoHasherRandom { inner: NoHasher(0), seed: self.0 }
+	}
+}
+
+impl Default for NoHashRandom {
+	#[inline]
+	fn default() -> Self { Self::new() }
+}
+
+impl fmt::Debug for NoHashRandom {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Keep the seed to ourselves.
+		f.debug_struct("NoHashRandom").finish_non_exhaustive()
+	}
+}
+
+impl NoHashRandom {
+	#[must_use]
+	/// # New (Random Seed).
+	///
+	/// Create a new instance with a random seed. This is equivalent to
+	/// [`NoHashRandom::default`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NoHashRandom;
+	/// use std::hash::BuildHasher;
+	///
+	/// let a = NoHashRandom::new();
+	/// let b = NoHashRandom::new();
+	///
+	/// // The same instance hashes consistently…
+	/// assert_eq!(a.hash_one(5_u32), a.hash_one(5_u32));
+	///
+	/// // …but different instances (almost certainly) disagree.
+	/// assert_ne!(a.hash_one(5_u32), b.hash_one(5_u32));
+	/// ```
+	pub fn new() -> Self {
+		Self(RandomState::new().build_hasher().finish())
+	}
+
+	#[must_use]
+	/// # With Seed.
+	///
+	/// Create a new instance with a specific seed, for reproducible hashing.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NoHashRandom;
+	/// use std::hash::BuildHasher;
+	///
+	/// let a = NoHashRandom::with_seed(123);
+	/// let b = NoHashRandom::with_seed(123);
+	/// assert_eq!(a.hash_one(5_u32), b.hash_one(5_u32));
+	/// ```
+	pub const fn with_seed(seed: u64) -> Self { Self(seed) }
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Randomized Passthrough Hasher.
+///
+/// See [`NoHashRandom`] for usage details.
+pub struct NoHasherRandom {
+	/// # Passthrough Hasher.
+	inner: NoHasher,
+
+	/// # Seed.
+	seed: u64,
+}
+
+/// # Helper: Forwarded Write Methods.
+macro_rules! write_forward {
+	($($fn:ident, $ty:ty),+ $(,)?) => ($(
+		#[inline]
+		#[doc = concat!("# Write `", stringify!($ty), "`")]
+		fn $fn(&mut self, val: $ty) { self.inner.$fn(val); }
+	)+);
+}
+
+impl Hasher for NoHasherRandom {
+	#[cold]
+	/// # Write.
+	fn write(&mut self, bytes: &[u8]) { self.inner.write(bytes); }
+
+	write_forward!(
+		write_u8, u8,
+		write_u16, u16,
+		write_u32, u32,
+		write_u64, u64,
+		write_usize, usize,
+		write_i8, i8,
+		write_i16, i16,
+		write_i32, i32,
+		write_isize, isize,
+	);
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[inline]
+	/// # Finish.
+	///
+	/// Mix the seed into the passthrough value via a folded multiply.
+	fn finish(&self) -> u64 {
+		/// # Multiplier (Golden Ratio).
+		const K: u64 = 0x9E37_79B9_7F4A_7C15;
+
+		let full = (self.inner.0 ^ self.seed) as u128 * K as u128;
+		(full as u64) ^ ((full >> 64) as u64)
+	}
+}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -198,6 +450,22 @@ mod tests {
 		assert!(!set.insert(NonZeroU8::new(1).unwrap())); // Should already be there.
 	}
 
+	#[test]
+	fn t_state() {
+		// The const state should hash the same as the default one.
+		let a = NoHash::default();
+		let b = NoHashState::new();
+		for i in 0..=u16::MAX {
+			assert_eq!(a.hash_one(i), b.hash_one(i));
+		}
+
+		let set: NoHashSet<u16> = (0..=u16::MAX).collect();
+		let mut set2: HashSet<u16, NoHashState> = HashSet::with_hasher(NoHashState::new());
+		set2.extend(0..=u16::MAX);
+		assert_eq!(set.len(), set2.len());
+		assert!(set.iter().all(|n| set2.contains(n)));
+	}
+
 	#[test]
 	fn t_wrapping() {
 		// This just verifies that Wrapping hashes its inner value directly.
@@ -280,19 +548,17 @@ mod tests {
 	}
 
 	#[test]
-	#[should_panic]
+	#[should_panic(expected = "NoHash only implements the type-specific write methods")]
 	fn t_u128() {
-		let mut set: HashSet<u128, NoHash> = HashSet::default();
-		set.insert(0);
+		HashSet::<u128, NoHash>::default().insert(0);
 	}
 
 	#[cfg(debug_assertions)]
 	#[test]
-	#[should_panic]
+	#[should_panic(expected = "cannot call `Hasher::write_*` more than once")]
 	fn t_double_write() {
 		// In debug mode, attempts to write twice will panic.
-		let mut set: HashSet<(u8, u8), NoHash> = HashSet::default();
-		set.insert((1_u8, 2_u8));
+		HashSet::<(u8, u8), NoHash>::default().insert((1_u8, 2_u8));
 	}
 
 	#[cfg(not(debug_assertions))]
@@ -306,9 +572,34 @@ mod tests {
 	}
 
 	#[test]
-	#[should_panic]
+	fn t_random() {
+		let mut set: HashSet<u64, NoHashRandom> = (0..1000_u64).collect();
+		assert_eq!(set.len(), 1000);
+		assert!(!set.insert(0)); // Should already be there.
+
+		// Seeded hashing should be reproducible.
+		let a = NoHashRandom::with_seed(1);
+		let b = NoHashRandom::with_seed(1);
+		let c = NoHashRandom::with_seed(2);
+		for i in [0_u64, 1, 2, 1 << 32, u64::MAX] {
+			assert_eq!(a.hash_one(i), b.hash_one(i));
+			assert_ne!(a.hash_one(i), c.hash_one(i));
+		}
+
+		// Values sharing the same low bits should not collide.
+		let hashes: HashSet<u64> = (0..1000_u64).map(|i| a.hash_one(i << 32) & 0xFFFF).collect();
+		assert!(hashes.len() > 900);
+	}
+
+	#[test]
+	#[should_panic(expected = "NoHash only implements the type-specific write methods")]
+	fn t_random_u128() {
+		HashSet::<u128, NoHashRandom>::default().insert(0);
+	}
+
+	#[test]
+	#[should_panic(expected = "NoHash only implements the type-specific write methods")]
 	fn t_write_bytes() {
-		let mut set: HashSet<&str, NoHash> = HashSet::default();
-		set.insert("hello");
+		HashSet::<&str, NoHash>::default().insert("hello");
 	}
 }
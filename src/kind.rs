@@ -0,0 +1,250 @@
+/*!
+# Dactyl: Nice Number Kind.
+*/
+
+use crate::{
+	NiceBar,
+	NiceBigint,
+	NiceBits,
+	NiceClock,
+	NiceClock12,
+	NiceDateTime,
+	NiceDiff,
+	NiceDivision,
+	NiceElapsed,
+	NiceFixed,
+	NiceFloat,
+	NiceHex,
+	NiceIso8601,
+	NiceMoney,
+	NicePercent,
+	NicePercentChange,
+	NicePadded,
+	NicePaddedFloat,
+	NicePercentRatio,
+	NicePow2,
+	NiceShort,
+	NiceSignedPercent,
+	NiceU16,
+	NiceU32,
+	NiceU64,
+	NiceU8,
+};
+use std::fmt;
+
+
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Nice Number Kind.
+///
+/// This enum describes the formatting category of a `Nice*` value, allowing
+/// generic code — table renderers and the like — to make decisions about
+/// alignment, labelling, etc., at runtime without having to know the concrete
+/// type.
+///
+/// Every `Nice*` type has a corresponding `kind()` method.
+///
+/// New variants may be added in future releases as new types are introduced,
+/// so matches should include a wildcard arm.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceNumberKind, NicePercent, NiceU32, NiceU64};
+///
+/// assert_eq!(NiceU32::from(5_u32).kind(), NiceNumberKind::Unsigned);
+/// assert_eq!(NicePercent::from(0.5_f32).kind(), NiceNumberKind::Percent);
+/// assert_eq!(NiceU64::delta(-5).kind(), NiceNumberKind::Signed);
+/// ```
+pub enum NiceNumberKind {
+	/// # Unsigned Integer.
+	///
+	/// [`NiceU8`], [`NiceU16`], [`NiceU32`], [`NiceU64`], [`NiceBigint`], [`NiceShort`],
+	/// [`NicePow2`], [`NiceBits`], [`NiceHex`], [`NicePadded`].
+	Unsigned,
+
+	/// # Signed Integer.
+	///
+	/// [`NiceDiff`], and [`NiceU64`] [deltas](NiceU64::delta).
+	Signed,
+
+	/// # Float.
+	///
+	/// [`NiceFloat`], [`NiceDivision`], [`NiceFixed`], [`NiceMoney`], [`NicePaddedFloat`].
+	Float,
+
+	/// # Percent.
+	///
+	/// [`NicePercent`], [`NiceSignedPercent`], [`NicePercentChange`], [`NicePercentRatio`], [`NiceBar`].
+	Percent,
+
+	/// # Clock.
+	///
+	/// [`NiceClock`], [`NiceClock12`], [`NiceDateTime`].
+	Clock,
+
+	/// # Elapsed Time.
+	///
+	/// [`NiceElapsed`], [`NiceIso8601`].
+	Elapsed,
+}
+
+impl fmt::Display for NiceNumberKind {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.pad(self.as_str())
+	}
+}
+
+impl NiceNumberKind {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return a short, lowercase label for the kind.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceNumberKind;
+	///
+	/// assert_eq!(NiceNumberKind::Unsigned.as_str(), "unsigned");
+	/// assert_eq!(NiceNumberKind::Elapsed.as_str(), "elapsed");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Unsigned => "unsigned",
+			Self::Signed => "signed",
+			Self::Float => "float",
+			Self::Percent => "percent",
+			Self::Clock => "clock",
+			Self::Elapsed => "elapsed",
+		}
+	}
+
+	#[must_use]
+	/// # Is Numeric?
+	///
+	/// Returns `true` for the strictly numeric kinds — unsigned, signed,
+	/// float, and percent — which are typically right-aligned in tabular
+	/// output.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceNumberKind;
+	///
+	/// assert!(NiceNumberKind::Float.is_numeric());
+	/// assert!(NiceNumberKind::Signed.is_numeric());
+	/// assert!(! NiceNumberKind::Elapsed.is_numeric());
+	/// ```
+	pub const fn is_numeric(self) -> bool {
+		matches!(self, Self::Unsigned | Self::Signed | Self::Float | Self::Percent)
+	}
+}
+
+
+
+/// # Helper: Kind Methods.
+macro_rules! kind {
+	($kind:ident: $($nice:ty),+) => ($(
+		impl $nice {
+			#[must_use]
+			#[inline]
+			/// # Kind.
+			///
+			/// Return the [`NiceNumberKind`] describing this value's formatting
+			/// category.
+			pub const fn kind(&self) -> NiceNumberKind { NiceNumberKind::$kind }
+		}
+	)+);
+}
+
+kind!(Unsigned: NiceU8, NiceU16, NiceU32, NiceBigint, NiceShort, NicePow2, NiceBits, NiceHex);
+kind!(Signed: NiceDiff);
+kind!(Float: NiceFloat, NiceDivision, NiceFixed, NiceMoney, NicePaddedFloat);
+kind!(Percent: NiceBar, NicePercent, NiceSignedPercent, NicePercentChange, NicePercentRatio);
+kind!(Clock: NiceClock, NiceClock12, NiceDateTime);
+kind!(Elapsed: NiceElapsed, NiceIso8601);
+
+impl NiceU64 {
+	#[must_use]
+	#[inline]
+	/// # Kind.
+	///
+	/// Return the [`NiceNumberKind`] describing this value's formatting
+	/// category.
+	///
+	/// This is [`NiceNumberKind::Signed`] for [deltas](NiceU64::delta), and
+	/// [`NiceNumberKind::Unsigned`] otherwise.
+	pub const fn kind(&self) -> NiceNumberKind {
+		if self.from < Self::MAX_LEN && matches!(self.inner[self.from], b'+' | b'-') {
+			NiceNumberKind::Signed
+		}
+		else { NiceNumberKind::Unsigned }
+	}
+}
+
+impl<const W: usize> NicePadded<W> {
+	#[must_use]
+	#[inline]
+	/// # Kind.
+	///
+	/// Return the [`NiceNumberKind`] describing this value's formatting
+	/// category.
+	pub const fn kind(&self) -> NiceNumberKind { NiceNumberKind::Unsigned }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_kind() {
+		assert_eq!(NiceU8::MIN.kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceU16::MIN.kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceU32::MIN.kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceU64::MIN.kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceFloat::ZERO.kind(), NiceNumberKind::Float);
+		assert_eq!(NiceDivision::default().kind(), NiceNumberKind::Float);
+		assert_eq!(NiceFixed::default().kind(), NiceNumberKind::Float);
+		assert_eq!(NiceMoney::default().kind(), NiceNumberKind::Float);
+		assert_eq!(NicePercent::MIN.kind(), NiceNumberKind::Percent);
+		assert_eq!(NiceBar::default().kind(), NiceNumberKind::Percent);
+		assert_eq!(NiceSignedPercent::ZERO.kind(), NiceNumberKind::Percent);
+		assert_eq!(NicePercentChange::ZERO.kind(), NiceNumberKind::Percent);
+		assert_eq!(NicePercentRatio::MAX.kind(), NiceNumberKind::Percent);
+		assert_eq!(NiceShort::default().kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NicePow2::default().kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceBigint::default().kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceBits::default().kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceHex::default().kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceDiff::default().kind(), NiceNumberKind::Signed);
+		assert_eq!(NiceDiff::new(1, 5).kind(), NiceNumberKind::Signed);
+		assert_eq!(NiceU64::delta(5).kind(), NiceNumberKind::Signed);
+		assert_eq!(NiceU64::delta(-5).kind(), NiceNumberKind::Signed);
+		assert_eq!(NiceU64::from(5_u64).kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceU8::from(7_u8).padded(3).kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceU64::from(42_u64).padded(10).kind(), NiceNumberKind::Unsigned);
+		assert_eq!(NiceFloat::padded(1.5_f64, 6, 2).kind(), NiceNumberKind::Float);
+		assert_eq!(NiceClock::MIN.kind(), NiceNumberKind::Clock);
+		assert_eq!(NiceClock12::MIN.kind(), NiceNumberKind::Clock);
+		assert_eq!(NiceDateTime::MIN.kind(), NiceNumberKind::Clock);
+		assert_eq!(NiceElapsed::min().kind(), NiceNumberKind::Elapsed);
+		assert_eq!(NiceIso8601::MIN.kind(), NiceNumberKind::Elapsed);
+
+		// The labels should match Display.
+		for kind in [
+			NiceNumberKind::Unsigned,
+			NiceNumberKind::Signed,
+			NiceNumberKind::Float,
+			NiceNumberKind::Percent,
+			NiceNumberKind::Clock,
+			NiceNumberKind::Elapsed,
+		] {
+			assert_eq!(kind.as_str(), kind.to_string());
+		}
+	}
+}
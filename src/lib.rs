@@ -21,10 +21,29 @@ Each type has its own struct, each of which works exactly the same way:
 * [`NiceU16`]
 * [`NiceU32`]
 * [`NiceU64`] (also covers `usize`)
+* [`NiceU64Sep`] (a `NiceU64` with a compile-time separator, like `NiceU64Sep<b'_'>`)
+* [`NiceDiff`] (signed differences, like `+1,234` or `±0`)
+* [`NiceBigint`] (for decimal strings of any length)
+* [`NiceShort`] (abbreviated, like `1.23M`)
+* [`NicePow2`] (powers of two, like `2^20 (1,048,576)` or `1 MiB`)
+* [`NiceBits`] (bit lengths, like `9 bits (0b1_0010_1100)`)
+* [`NiceHex`] (hex, like `deadbeef`)
 * [`NiceFloat`]
+* [`NiceFixed`] (for scaled integers, like cents)
+* [`NiceMoney`] (for currency, with symbols and accounting negatives)
+* [`NicePadded`] (zero-padded digits, like `00042`)
+* [`NicePaddedFloat`] (space-padded floats, like `   1.50`)
+* [`NiceDivision`] (for quotients, like `3 r 2`)
 * [`NiceClock`] (for durations)
-* [`NiceElapsed`] (also for durations)
+* [`NiceClock12`] (for durations, 12-hour)
+* [`NiceDateTime`] (for Unix timestamps)
+* [`NiceElapsed`] (also for durations, with [`NiceElapsedLabels`] for translations)
+* [`NiceIso8601`] (durations, again, for machines)
 * [`NicePercent`] (for floats representing percentages)
+* [`NiceSignedPercent`] (for floats representing percentage deltas)
+* [`NicePercentChange`] (for relative changes, like `+12.50%`)
+* [`NicePercentRatio`] (for unclamped ratios, like `250.00%`)
+* [`NiceBar`] (progress bars, with percentages)
 
 The intended use case is to simply call the appropriate `from()` for the type, then use either the `as_str()` or `as_bytes()` struct methods to retrieve the output in the desired format. Each struct also implements traits like `Deref`, `Display`, `AsRef<str>`, `AsRef<[u8]>`, etc., if you prefer those.
 
@@ -37,12 +56,39 @@ assert_eq!(NiceU16::from(11234_u16).as_bytes(), b"11,234");
 
 But the niceness doesn't stop there. Dactyl provides several other structs, methods, and traits to performantly work with integers, such as:
 
-* [`NoHash`]: a passthrough hasher for integer `HashSet`/`HashMap` collections
+* [`NiceStyle`]: formatting options, like separators and [`Grouping`]
+* [`NiceSeparator`]: the common thousands separators
+* [`nice!`]: compile-time formatting for `u64` constants
+* [`group_digits`]: thousands separators for already-stringified numbers
+* [`nice_width_u64`] (and friends): formatted widths, without the formatting
+* [`percent_of`]: overflow-safe whole percentages of counts, with explicit rounding
+* [`NiceNumberKind`]: runtime reflection of a `Nice*` value's formatting category
+* [`max_len_for`]: compile-time maximum lengths for each `Nice*` type
+* [`NiceTimer`]: a stopwatch for quick profiling printouts
+* [`time`]: saturating and checked `Duration` constructors, plus `const` hour/minute/second splitters
+* [`progress`]: iterator adapters for percent-based progress reporting
+* [`NoHash`]: a passthrough hasher for integer `HashSet`/`HashMap` collections (see also [`NoHashMap`], [`NoHashSet`], and the `const`-friendly [`NoHashState`])
+* [`NoHashRandom`]: a seeded, collision-resistant variant of `NoHash`
+* [`traits::BytesToDuration`]: `Duration` parsing from human-entered byte slices
+* [`traits::BytesToFloat`]: float parsing from byte slices
 * [`traits::BytesToSigned`]: signed integer parsing from byte slices
 * [`traits::BytesToUnsigned`]: unsigned integer parsing from byte slices
+* [`traits::FloatCompare`]: approximate equality checks for floats
 * [`traits::HexToSigned`]: signed integer parsing from hex
 * [`traits::HexToUnsigned`]: unsigned integer parsing from hex
+* [`traits::UnsignedToHex`]: unsigned integer formatting to hex
+* [`traits::SaturatingAdd`] / [`traits::SaturatingSub`] / [`traits::SaturatingMul`]: mixed-width saturating arithmetic for integers
+* [`traits::UnsignedDigits`]: digit counts, sums, and histograms for unsigned integers
+* [`traits::NiceEncode`]: copying `Nice*` values into caller-provided byte buffers
+* [`traits::NiceDuration`]: generic `NiceElapsed`/`NiceClock` formatting for time-like values
+* [`traits::ToNice`]: fluent `n.nice()` formatting for unsigned integers
+* [`traits::NiceWriteIo`]: allocation-free writing of `Nice*` values to `io::Write` sinks
 
+## Optional Features
+
+| Feature | Description |
+| ------- | ----------- |
+| `defmt` | Implement `defmt::Format` for the `Nice*` types, for embedded logging. |
 */
 
 #![deny(
@@ -75,7 +121,6 @@ But the niceness doesn't stop there. Dactyl provides several other structs, meth
 	clippy::rest_pat_in_fully_bound_structs,
 	clippy::semicolon_inside_block,
 	clippy::str_to_string,
-	clippy::string_to_string,
 	clippy::todo,
 	clippy::undocumented_unsafe_blocks,
 	clippy::unneeded_field_pattern,
@@ -93,32 +138,95 @@ But the niceness doesn't stop there. Dactyl provides several other structs, meth
 	unused_import_braces,
 )]
 
-#![expect(clippy::module_name_repetitions, reason = "Repetition is preferred.")]
 #![expect(clippy::redundant_pub_crate, reason = "Unresolvable.")]
 
 
 
 #[macro_use] mod macros;
+mod error;
+#[cfg(feature = "defmt")] mod fmt_defmt;
+mod group;
 mod hash;
+mod kind;
 mod nice_elapsed;
 mod nice_int;
+pub mod progress;
+mod style;
+pub mod time;
 pub mod traits;
 
-pub use hash::NoHash;
+pub use error::{
+	NiceDivideByZeroError,
+	NiceOverflowError,
+	NiceSeparatorError,
+	ParseByteError,
+};
+pub use group::{
+	group_digits,
+	nice_width_u8,
+	nice_width_u16,
+	nice_width_u32,
+	nice_width_u64,
+};
+pub use hash::{
+	NoHash,
+	NoHashMap,
+	NoHashRandom,
+	NoHashSet,
+	NoHashState,
+};
+pub use kind::NiceNumberKind;
+pub use progress::percent_of;
 pub use nice_elapsed::{
 	clock::NiceClock,
+	clock12::NiceClock12,
+	datetime::NiceDateTime,
+	iso8601::NiceIso8601,
+	timer::NiceTimer,
 	NiceElapsed,
+	NiceElapsedLabels,
 };
 pub use nice_int::{
+	nice_bar::NiceBar,
+	nice_bigint::NiceBigint,
+	nice_bits::NiceBits,
+	nice_diff::NiceDiff,
+	nice_division::NiceDivision,
+	nice_fixed::NiceFixed,
 	nice_u8::NiceU8,
 	nice_u16::NiceU16,
 	nice_u32::NiceU32,
 	nice_u64::NiceU64,
+	nice_u64_sep::NiceU64Sep,
 	nice_float::{
 		FloatKind,
 		NiceFloat,
 	},
+	nice_hex::NiceHex,
+	nice_money::NiceMoney,
+	nice_padded::{
+		NicePadded,
+		NicePaddedFloat,
+	},
 	nice_percent::NicePercent,
+	nice_percent_change::NicePercentChange,
+	nice_percent_ratio::NicePercentRatio,
+	nice_pow2::NicePow2,
+	nice_short::NiceShort,
+	nice_signed_percent::NiceSignedPercent,
+	range::NiceRangeInclusive,
+};
+
+pub use traits::max_len_for;
+
+pub use style::{
+	Grouping,
+	NanPolicy,
+	NiceSeparator,
+	NiceStyle,
+	Rounding,
+	Scale,
+	Units,
 };
 
 #[doc(hidden)]
@@ -150,23 +258,32 @@ static DOUBLE: [[u8; 2]; 100] = [
 /// This will panic if the number is greater than 99.
 pub(crate) const fn double(idx: usize) -> [u8; 2] { DOUBLE[idx] }
 
-#[inline]
 #[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 #[expect(clippy::integer_division, reason = "We want this.")]
+/// # Decimals, 000-999.
+///
+/// This is built at compile time from [`DOUBLE`], saving the hundreds
+/// division — and its remainder — from every three-digit lookup.
+static TRIPLE: [[u8; 3]; 1000] = {
+	let mut out = [[0_u8; 3]; 1000];
+	let mut idx = 0;
+	while idx < 1000 {
+		let [b, c] = DOUBLE[idx % 100];
+		out[idx] = [(idx / 100) as u8 + b'0', b, c];
+		idx += 1;
+	}
+	out
+};
+
+#[inline]
 /// # Triple Digits.
 ///
-/// Return both digits, ASCII-fied.
+/// Return all three digits, ASCII-fied.
 ///
 /// ## Panics
 ///
 /// This will panic if the number is greater than 999.
-pub(crate) const fn triple(idx: usize) -> [u8; 3] {
-	assert!(idx < 1000, "Bug: Triple must be less than 1000.");
-	let (div, rem) = (idx / 100, idx % 100);
-	let a = div as u8 + b'0';
-	let [b, c] = DOUBLE[rem];
-	[a, b, c]
-}
+pub(crate) const fn triple(idx: usize) -> [u8; 3] { TRIPLE[idx] }
 
 
 
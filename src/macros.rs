@@ -109,3 +109,37 @@ macro_rules! _total_cmp {
 	);
 	(>  $expr:expr) => ( ::std::matches!($expr, ::std::cmp::Ordering::Greater) );
 }
+
+#[macro_export]
+/// # Nice (Compile-Time).
+///
+/// Format a constant `u64` with commas at each thousand — like [`NiceU64`](crate::NiceU64) —
+/// entirely at compile time, producing a `&'static str`.
+///
+/// The argument can be any constant expression evaluating to a `u64`.
+/// Smaller unsigned types need to be cast first.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{nice, NiceU64};
+///
+/// const LIMIT: u64 = 1_234_567;
+/// const LIMIT_STR: &str = nice!(LIMIT);
+/// assert_eq!(LIMIT_STR, "1,234,567");
+///
+/// assert_eq!(nice!(0), "0");
+/// assert_eq!(nice!(u64::MAX), NiceU64::MAX.as_str());
+/// assert_eq!(nice!(u32::MAX as u64), "4,294,967,295");
+/// ```
+macro_rules! nice {
+	($num:expr) => ({
+		const LEN: usize = $crate::NiceU64::__const_len($num);
+		const BYTES: [u8; LEN] = $crate::NiceU64::__const_bytes::<LEN>($num);
+		const STR: &str = match ::std::str::from_utf8(&BYTES) {
+			Ok(s) => s,
+			Err(_) => ::std::panic!("Invalid UTF-8."),
+		};
+		STR
+	});
+}
@@ -2,7 +2,10 @@
 # Dactyl: "Nice" Elapsed (Compact)
 */
 
-use crate::NiceElapsed;
+use crate::{
+	NiceElapsed,
+	ParseByteError,
+};
 use std::{
 	fmt,
 	num::{
@@ -13,6 +16,7 @@ use std::{
 	time::{
 		Duration,
 		Instant,
+		SystemTime,
 	},
 };
 
@@ -103,10 +107,22 @@ impl Deref for NiceClock {
 }
 
 impl fmt::Debug for NiceClock {
+	/// # Debug.
+	///
+	/// The alternate form — `{:#?}` — includes the total number of seconds
+	/// alongside the string.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.debug_tuple("NiceClock")
-			.field(&self.as_str())
-			.finish()
+		if f.alternate() {
+			f.debug_struct("NiceClock")
+				.field("value", &self.total_seconds())
+				.field("nice", &self.as_str())
+				.finish()
+		}
+		else {
+			f.debug_tuple("NiceClock")
+				.field(&self.as_str())
+				.finish()
+		}
 	}
 }
 
@@ -189,6 +205,46 @@ impl From<Instant> for NiceClock {
 	fn from(src: Instant) -> Self { Self::from(src.elapsed()) }
 }
 
+impl From<SystemTime> for NiceClock {
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # From System Time.
+	///
+	/// Return the wall-clock time of day, in UTC.
+	///
+	/// This is computed from the seconds since the Unix epoch, without any
+	/// time zone or leap second support. (Partial seconds are floored.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	/// use std::time::{Duration, SystemTime};
+	///
+	/// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+	/// assert_eq!(NiceClock::from(now).as_str(), "22:13:20");
+	///
+	/// // Times before the epoch work too.
+	/// let then = SystemTime::UNIX_EPOCH - Duration::from_millis(500);
+	/// assert_eq!(NiceClock::from(then).as_str(), "23:59:59");
+	/// ```
+	fn from(src: SystemTime) -> Self {
+		/// # Seconds Per Day.
+		const DAY: u64 = 86_400;
+
+		let secs = match src.duration_since(SystemTime::UNIX_EPOCH) {
+			Ok(d) => d.as_secs() % DAY,
+			// Count backwards from the end of the day, rounding away from
+			// the epoch.
+			Err(e) => {
+				let d = e.duration();
+				let secs = d.as_secs() + u64::from(d.subsec_nanos() != 0);
+				(DAY - secs % DAY) % DAY
+			},
+		};
+		Self::from(secs as u32)
+	}
+}
+
 impl From<u32> for NiceClock {
 	#[inline]
 	fn from(num: u32) -> Self {
@@ -207,6 +263,62 @@ impl From<NonZeroU32> for NiceClock {
 	fn from(num: NonZeroU32) -> Self { Self::from(num.get()) }
 }
 
+impl std::str::FromStr for NiceClock {
+	type Err = ParseByteError;
+
+	/// # From Str.
+	///
+	/// Parse a canonically-formatted `HH:MM:SS` string — two digits apiece,
+	/// colon-separated — back into an instance.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceClock, ParseByteError};
+	///
+	/// let clock: NiceClock = "01:02:03".parse().unwrap();
+	/// assert_eq!(clock, NiceClock::from(3723_u32));
+	///
+	/// // The layout must be exact.
+	/// assert_eq!("1:02:03".parse::<NiceClock>(), Err(ParseByteError::InvalidDigit(1)));
+	/// assert_eq!("01:02:03 ".parse::<NiceClock>(), Err(ParseByteError::InvalidDigit(8)));
+	///
+	/// // And the parts must be in range.
+	/// assert_eq!("24:00:00".parse::<NiceClock>(), Err(ParseByteError::Overflow));
+	/// assert_eq!("00:60:00".parse::<NiceClock>(), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the string is empty, malformed, or any of the
+	/// parts are out of range.
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		let src = src.as_bytes();
+		if src.is_empty() { return Err(ParseByteError::Empty); }
+
+		// Check the layout byte by byte.
+		let mut inner = [0_u8; 8];
+		for (idx, b) in inner.iter_mut().enumerate() {
+			let Some(&v) = src.get(idx) else {
+				return Err(ParseByteError::InvalidDigit(src.len() - 1));
+			};
+			let ok =
+				if idx == 2 || idx == 5 { v == b':' }
+				else { v.is_ascii_digit() };
+			if ! ok { return Err(ParseByteError::InvalidDigit(idx)); }
+			*b = v;
+		}
+		if 8 < src.len() { return Err(ParseByteError::InvalidDigit(8)); }
+
+		// Check the ranges.
+		let out = Self { inner };
+		if 23 < out.hours() || 59 < out.minutes() || 59 < out.seconds() {
+			Err(ParseByteError::Overflow)
+		}
+		else { Ok(out) }
+	}
+}
+
 impl From<NiceClock> for [u8; 8] {
 	#[inline]
 	fn from(num: NiceClock) -> Self { num.inner }
@@ -252,6 +364,98 @@ impl NiceClock {
 	};
 }
 
+impl NiceClock {
+	#[must_use]
+	/// # From Hours, Minutes, and Seconds.
+	///
+	/// Build a clock directly from its components, returning `None` if any
+	/// are out of range, i.e. hours greater than `23`, or minutes or seconds
+	/// greater than `59`.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let clock = NiceClock::from_hms(4, 5, 6).unwrap();
+	/// assert_eq!(clock.as_str(), "04:05:06");
+	/// assert_eq!(clock, NiceClock::from(4 * 3600 + 5 * 60 + 6_u32));
+	///
+	/// // Out of range.
+	/// assert!(NiceClock::from_hms(24, 0, 0).is_none());
+	/// assert!(NiceClock::from_hms(0, 60, 0).is_none());
+	/// assert!(NiceClock::from_hms(0, 0, 60).is_none());
+	/// ```
+	pub const fn from_hms(h: u8, m: u8, s: u8) -> Option<Self> {
+		if h < 24 && m < 60 && s < 60 { Some(Self::from_hms_unchecked(h, m, s)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # From Hours, Minutes, and Seconds (Unchecked).
+	///
+	/// Same as [`NiceClock::from_hms`], but without the range checks, for
+	/// use with components that are already known to be valid.
+	///
+	/// Out-of-range values won't panic, but won't make much sense either.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// const NOON: NiceClock = NiceClock::from_hms_unchecked(12, 0, 0);
+	/// assert_eq!(NOON.as_str(), "12:00:00");
+	/// ```
+	pub const fn from_hms_unchecked(h: u8, m: u8, s: u8) -> Self {
+		let h = crate::double((h & TIME_MASK) as usize);
+		let m = crate::double((m & TIME_MASK) as usize);
+		let s = crate::double((s & TIME_MASK) as usize);
+		Self { inner: [h[0], h[1], b':', m[0], m[1], b':', s[0], s[1]] }
+	}
+
+	#[expect(clippy::integer_division, reason = "We want this.")]
+	#[must_use]
+	/// # From Duration (With Days).
+	///
+	/// The `From<Duration>` implementation saturates at `23:59:59`, which is
+	/// great for clocks, but less great for elapsed times that might run a
+	/// little long.
+	///
+	/// This method instead splits off the number of whole days, returning
+	/// them alongside a clock holding the remainder, so the overflow can be
+	/// rendered separately.
+	///
+	/// Sub-second precision is ignored.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	/// use std::time::Duration;
+	///
+	/// let (days, clock) = NiceClock::from_duration_with_days(
+	///     Duration::from_secs(3 * 86_400 + 4 * 3600 + 5 * 60 + 6)
+	/// );
+	/// assert_eq!(format!("{days}d {clock}"), "3d 04:05:06");
+	///
+	/// // Short durations have no days.
+	/// let (days, clock) = NiceClock::from_duration_with_days(Duration::from_secs(90));
+	/// assert_eq!(days, 0);
+	/// assert_eq!(clock.as_str(), "00:01:30");
+	/// ```
+	pub const fn from_duration_with_days(src: Duration) -> (u64, Self) {
+		let secs = src.as_secs();
+		let days = secs / 86_400;
+		let rem = (secs % 86_400) as u32;
+		let [h, m, s] = [rem / 3600, rem % 3600 / 60, rem % 60];
+		let h = crate::double(h as usize);
+		let m = crate::double(m as usize);
+		let s = crate::double(s as usize);
+		(days, Self { inner: [h[0], h[1], b':', m[0], m[1], b':', s[0], s[1]] })
+	}
+}
+
 impl NiceClock {
 	#[inline]
 	/// # Replace.
@@ -268,8 +472,11 @@ impl NiceClock {
 	///
 	/// clock.replace(2);
 	/// assert_eq!(clock.as_str(), "00:00:02");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(clock.replace(3661).as_str(), "01:01:01");
 	/// ```
-	pub fn replace(&mut self, num: u32) {
+	pub fn replace(&mut self, num: u32) -> &mut Self {
 		let [h, m, s] = NiceElapsed::hms(num);
 		let h = crate::double(usize::from(h & TIME_MASK));
 		let m = crate::double(usize::from(m & TIME_MASK));
@@ -280,6 +487,131 @@ impl NiceClock {
 		self.inner[4] = m[1];
 		self.inner[6] = s[0];
 		self.inner[7] = s[1];
+		self
+	}
+
+	/// # Set Hours.
+	///
+	/// Update the hours, leaving the minutes and seconds as they were.
+	/// Values larger than `23` are saturated.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let mut clock = NiceClock::from(61_u32);
+	/// assert_eq!(clock.as_str(), "00:01:01");
+	///
+	/// clock.set_hours(12);
+	/// assert_eq!(clock.as_str(), "12:01:01");
+	///
+	/// clock.set_hours(99);
+	/// assert_eq!(clock.as_str(), "23:01:01");
+	/// ```
+	pub const fn set_hours(&mut self, hours: u8) { self.set_part(0, hours, 23); }
+
+	/// # Set Minutes.
+	///
+	/// Update the minutes, leaving the hours and seconds as they were.
+	/// Values larger than `59` are saturated.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let mut clock = NiceClock::from(3601_u32);
+	/// assert_eq!(clock.as_str(), "01:00:01");
+	///
+	/// clock.set_minutes(30);
+	/// assert_eq!(clock.as_str(), "01:30:01");
+	/// ```
+	pub const fn set_minutes(&mut self, minutes: u8) { self.set_part(3, minutes, 59); }
+
+	/// # Set Seconds.
+	///
+	/// Update the seconds, leaving the hours and minutes as they were.
+	/// Values larger than `59` are saturated.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let mut clock = NiceClock::from(3600_u32);
+	/// assert_eq!(clock.as_str(), "01:00:00");
+	///
+	/// clock.set_seconds(45);
+	/// assert_eq!(clock.as_str(), "01:00:45");
+	/// ```
+	pub const fn set_seconds(&mut self, seconds: u8) { self.set_part(6, seconds, 59); }
+
+	/// # Add Seconds (Saturating).
+	///
+	/// Add (or subtract) a number of seconds to the current time, saturating
+	/// at `00:00:00` and `23:59:59`, consistent with the `From` conversions.
+	///
+	/// For clock-like rollover, use [`NiceClock::wrapping_add_seconds`]
+	/// instead.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let mut clock = NiceClock::from(59_u32);
+	/// clock.add_seconds(1);
+	/// assert_eq!(clock.as_str(), "00:01:00");
+	///
+	/// clock.add_seconds(-61);
+	/// assert_eq!(clock, NiceClock::MIN);
+	///
+	/// clock.add_seconds(i64::MAX);
+	/// assert_eq!(clock, NiceClock::MAX);
+	/// ```
+	pub fn add_seconds(&mut self, delta: i64) {
+		*self = Self::from(i64::from(self.total_seconds()).saturating_add(delta));
+	}
+
+	/// # Add Seconds (Wrapping).
+	///
+	/// Add (or subtract) a number of seconds to the current time, wrapping
+	/// around midnight like a real clock would.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// let mut clock = NiceClock::MAX;
+	/// clock.wrapping_add_seconds(2);
+	/// assert_eq!(clock.as_str(), "00:00:01");
+	///
+	/// clock.wrapping_add_seconds(-2);
+	/// assert_eq!(clock, NiceClock::MAX);
+	/// ```
+	pub fn wrapping_add_seconds(&mut self, delta: i64) {
+		*self = Self::from(
+			(i64::from(self.total_seconds()) + delta.rem_euclid(86_400)).rem_euclid(86_400)
+		);
+	}
+
+	/// # Set Part.
+	///
+	/// Write a two-digit value to `inner[idx..idx + 2]`, capping it at `max`.
+	const fn set_part(&mut self, idx: usize, num: u8, max: u8) {
+		let num = if num < max { num } else { max };
+		let [a, b] = crate::double(num as usize);
+		self.inner[idx] = a;
+		self.inner[idx + 1] = b;
+	}
+
+	/// # Total Seconds.
+	///
+	/// Return the time as a total number of seconds.
+	pub(super) const fn total_seconds(self) -> u32 {
+		self.hours() as u32 * 3600 + self.minutes() as u32 * 60 + self.seconds() as u32
 	}
 }
 
@@ -301,6 +633,25 @@ impl NiceClock {
 	/// ```
 	pub const fn as_bytes(&self) -> &[u8] { self.inner.as_slice() }
 
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return a copy of the backing buffer along with the index the value
+	/// starts at. `NiceClock` values are fixed-width, so the latter is always
+	/// zero; it is included for consistency with the other `Nice*` types.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock;
+	///
+	/// assert_eq!(
+	///     NiceClock::from(90_u32).to_array(),
+	///     (*b"00:01:30", 0),
+	/// );
+	/// ```
+	pub const fn to_array(self) -> ([u8; 8], usize) { (self.inner, 0) }
+
 	#[expect(unsafe_code, reason = "For performance.")]
 	#[must_use]
 	/// # As String.
@@ -442,4 +793,118 @@ mod test {
 		assert_eq!(last, NiceClock::from(i128::MIN));
 		assert_eq!(last, NiceClock::from(isize::MIN));
 	}
+
+	#[test]
+	fn t_nice_clock_math() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let start = rng.u32(0..86_400);
+			let delta = rng.i64(-200_000..200_000);
+			let mut clock = NiceClock::from(start);
+
+			// Saturating.
+			let mut sat = clock;
+			sat.add_seconds(delta);
+			assert_eq!(sat, NiceClock::from(i64::from(start) + delta));
+
+			// Wrapping.
+			clock.wrapping_add_seconds(delta);
+			assert_eq!(clock, NiceClock::from((i64::from(start) + delta).rem_euclid(86_400)));
+			assert_eq!(clock.total_seconds(), u32::try_from((i64::from(start) + delta).rem_euclid(86_400)).unwrap());
+
+			// Setters.
+			let (h, m, s) = (rng.u8(0..24), rng.u8(0..60), rng.u8(0..60));
+			clock.set_hours(h);
+			clock.set_minutes(m);
+			clock.set_seconds(s);
+			assert_eq!(clock, NiceClock::from(u32::from(h) * 3600 + u32::from(m) * 60 + u32::from(s)));
+		}
+
+		// The extremes.
+		let mut clock = NiceClock::MIN;
+		clock.wrapping_add_seconds(i64::MIN);
+		assert_eq!(clock, NiceClock::from(i64::MIN.rem_euclid(86_400)));
+		clock.wrapping_add_seconds(i64::MAX);
+		clock.add_seconds(i64::MIN);
+		assert_eq!(clock, NiceClock::MIN);
+
+		clock.set_hours(u8::MAX);
+		clock.set_minutes(u8::MAX);
+		clock.set_seconds(60);
+		assert_eq!(clock, NiceClock::MAX);
+	}
+
+	#[test]
+	fn t_from_hms() {
+		for h in 0..=u8::MAX {
+			for m in 0..=64 {
+				for s in [0, 1, 30, 59, 60, 64, 255] {
+					let clock = NiceClock::from_hms(h, m, s);
+					if h < 24 && m < 60 && s < 60 {
+						let clock = clock.expect("Clock should be valid.");
+						assert_eq!(clock, NiceClock::from_hms_unchecked(h, m, s));
+						assert_eq!(clock, NiceClock::from(u32::from(h) * 3600 + u32::from(m) * 60 + u32::from(s)));
+						assert_eq!([clock.hours(), clock.minutes(), clock.seconds()], [h, m, s]);
+					}
+					else { assert!(clock.is_none()); }
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn t_from_duration_with_days() {
+		let mut rng = fastrand::Rng::new();
+		for secs in std::iter::repeat_with(|| rng.u64(..)).take(10_000).chain([0, 86_399, 86_400, u64::MAX]) {
+			let (days, clock) = NiceClock::from_duration_with_days(Duration::from_secs(secs));
+			assert_eq!(days, secs / 86_400);
+			assert_eq!(clock, NiceClock::from(secs % 86_400));
+			assert_eq!(days * 86_400 + u64::from(clock.total_seconds()), secs);
+		}
+	}
+
+	#[test]
+	fn t_from_system_time() {
+		let mut rng = fastrand::Rng::new();
+		for (secs, nanos) in std::iter::repeat_with(|| (rng.u64(..1 << 40), rng.u32(..1_000_000_000))).take(10_000).chain([(0, 0), (86_399, 1), (86_400, 999_999_999)]) {
+			let time = SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+			assert_eq!(NiceClock::from(time), NiceClock::from(secs % 86_400));
+			let time = time + Duration::from_nanos(u64::from(nanos));
+			assert_eq!(NiceClock::from(time), NiceClock::from(secs % 86_400));
+
+			// And backwards.
+			let time = SystemTime::UNIX_EPOCH - Duration::from_secs(secs);
+			assert_eq!(NiceClock::from(time), NiceClock::from((86_400 - secs % 86_400) % 86_400));
+		}
+
+		assert_eq!(
+			NiceClock::from(SystemTime::UNIX_EPOCH - Duration::from_nanos(1)).as_str(),
+			"23:59:59",
+		);
+		assert_eq!(
+			NiceClock::from(SystemTime::UNIX_EPOCH - Duration::from_secs(86_400) - Duration::from_nanos(1)).as_str(),
+			"23:59:59",
+		);
+	}
+
+	#[test]
+	fn t_from_str() {
+		for num in 0..86_400_u32 {
+			let clock = NiceClock::from(num);
+			assert_eq!(clock.as_str().parse::<NiceClock>(), Ok(clock));
+		}
+
+		for (src, err) in [
+			("", ParseByteError::Empty),
+			("0", ParseByteError::InvalidDigit(0)),
+			("00:00:0", ParseByteError::InvalidDigit(6)),
+			("00:00:000", ParseByteError::InvalidDigit(8)),
+			("00-00-00", ParseByteError::InvalidDigit(2)),
+			("0a:00:00", ParseByteError::InvalidDigit(1)),
+			("99:00:00", ParseByteError::Overflow),
+			("00:00:60", ParseByteError::Overflow),
+		] {
+			assert_eq!(src.parse::<NiceClock>(), Err(err), "{src:?}");
+		}
+	}
 }
@@ -0,0 +1,431 @@
+/*!
+# Dactyl: "Nice" Elapsed (12-Hour Clock)
+*/
+
+use crate::NiceClock;
+use std::{
+	cmp::Ordering,
+	fmt,
+	hash,
+	ops::Deref,
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Clock (12-Hour).
+///
+/// This is a 12-hour variation of [`NiceClock`], converting some number of
+/// seconds into an `HH:MM:SS AM`-formatted string.
+///
+/// Counting begins at `12:00:00 AM` and tops out at `11:59:59 PM`. As with
+/// [`NiceClock`], negative and gigantic values are simply saturated to fit.
+///
+/// Ordering is chronological, with `AM` coming before `PM`.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceClock12;
+///
+/// let mut clock = NiceClock12::from(12_345_u32);
+/// assert_eq!(clock.as_str(), "03:25:45 AM");
+///
+/// // Update the value in place.
+/// clock.replace(55_545);
+/// assert_eq!(clock.as_str(), "03:25:45 PM");
+///
+/// // The numeric parts are still 24-hour.
+/// assert_eq!(clock.hours(), 15);
+/// assert_eq!(clock.minutes(), 25);
+/// assert_eq!(clock.seconds(), 45);
+/// assert!(clock.is_pm());
+/// ```
+pub struct NiceClock12 {
+	/// # Formatted Data.
+	inner: [u8; 11],
+}
+
+impl AsRef<[u8]> for NiceClock12 {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceClock12 {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceClock12 {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceClock12 {
+	#[inline]
+	fn default() -> Self { Self::MIN }
+}
+
+impl Deref for NiceClock12 {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceClock12 {
+	/// # Debug.
+	///
+	/// The alternate form — `{:#?}` — includes the total number of seconds
+	/// alongside the string.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			f.debug_struct("NiceClock12")
+				.field("value", &NiceClock::from(*self).total_seconds())
+				.field("nice", &self.as_str())
+				.finish()
+		}
+		else {
+			f.debug_tuple("NiceClock12")
+				.field(&self.as_str())
+				.finish()
+		}
+	}
+}
+
+impl fmt::Display for NiceClock12 {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceClock12 {}
+
+/// # Helper: From (via `NiceClock`).
+macro_rules! from_clock {
+	($($ty:ty),+ $(,)?) => ($(
+		impl From<$ty> for NiceClock12 {
+			#[inline]
+			fn from(num: $ty) -> Self { Self::from(NiceClock::from(num)) }
+		}
+	)+);
+}
+
+from_clock!(
+	u8, u16, u32, u64, u128, usize,
+	i8, i16, i32, i64, i128, isize,
+	Duration, Instant,
+);
+
+impl From<NiceClock> for NiceClock12 {
+	#[inline]
+	fn from(src: NiceClock) -> Self {
+		let (h, ap) = match src.hours() {
+			0 => (12, b'A'),
+			h @ 1..=11 => (h, b'A'),
+			12 => (12, b'P'),
+			h => (h - 12, b'P'),
+		};
+		let [h1, h2] = crate::double(usize::from(h));
+		let src = src.to_array().0;
+
+		Self {
+			inner: [h1, h2, b':', src[3], src[4], b':', src[6], src[7], b' ', ap, b'M'],
+		}
+	}
+}
+
+impl From<NiceClock12> for NiceClock {
+	#[inline]
+	fn from(src: NiceClock12) -> Self {
+		let mut out = Self::MIN;
+		out.set_hours(src.hours());
+		out.set_minutes(src.minutes());
+		out.set_seconds(src.seconds());
+		out
+	}
+}
+
+impl hash::Hash for NiceClock12 {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl Ord for NiceClock12 {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		(self.hours(), self.minutes(), self.seconds())
+			.cmp(&(other.hours(), other.minutes(), other.seconds()))
+	}
+}
+
+impl PartialEq for NiceClock12 {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
+}
+
+impl PartialOrd for NiceClock12 {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl NiceClock12 {
+	/// # Minimum Value.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert_eq!(
+	///     NiceClock12::MIN.as_str(),
+	///     "12:00:00 AM",
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceClock12::from(0_u32),
+	///     NiceClock12::MIN,
+	/// );
+	/// ```
+	pub const MIN: Self = Self {
+		inner: *b"12:00:00 AM",
+	};
+
+	/// # Maximum Value.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert_eq!(
+	///     NiceClock12::MAX.as_str(),
+	///     "11:59:59 PM",
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceClock12::from(u32::MAX),
+	///     NiceClock12::MAX,
+	/// );
+	/// ```
+	pub const MAX: Self = Self {
+		inner: *b"11:59:59 PM",
+	};
+}
+
+impl NiceClock12 {
+	#[inline]
+	/// # Replace.
+	///
+	/// Update the clock time, in place.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// let mut clock = NiceClock12::from(1_u32);
+	/// assert_eq!(clock.as_str(), "12:00:01 AM");
+	///
+	/// clock.replace(43_200);
+	/// assert_eq!(clock.as_str(), "12:00:00 PM");
+	/// ```
+	pub fn replace(&mut self, num: u32) { *self = Self::from(num); }
+}
+
+impl NiceClock12 {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert_eq!(
+	///     NiceClock12::from(90_u32).as_bytes(),
+	///     b"12:01:30 AM",
+	/// );
+	/// ```
+	pub const fn as_bytes(&self) -> &[u8] { self.inner.as_slice() }
+
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return a copy of the backing buffer along with the index the value
+	/// starts at, which is always zero.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert_eq!(
+	///     NiceClock12::from(90_u32).to_array(),
+	///     (*b"12:01:30 AM", 0),
+	/// );
+	/// ```
+	pub const fn to_array(self) -> ([u8; 11], usize) { (self.inner, 0) }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert_eq!(
+	///     NiceClock12::from(46_925_u32).as_str(),
+	///     "01:02:05 PM",
+	/// );
+	/// ```
+	pub const fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.inner.as_slice()) }
+	}
+
+	#[must_use]
+	/// # Hours.
+	///
+	/// Return the hours part as a (24-hour) number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// let clock = NiceClock12::from(46_925_u32);
+	/// assert_eq!(clock.as_str(), "01:02:05 PM");
+	/// assert_eq!(clock.hours(), 13);
+	///
+	/// let clock = NiceClock12::from(1_u32);
+	/// assert_eq!(clock.as_str(), "12:00:01 AM");
+	/// assert_eq!(clock.hours(), 0);
+	/// ```
+	pub const fn hours(&self) -> u8 {
+		let h = (self.inner[0] - b'0') * 10 + (self.inner[1] - b'0');
+		match (h, self.is_pm()) {
+			(12, false) => 0,
+			(12, true) => 12,
+			(h, false) => h,
+			(h, true) => h + 12,
+		}
+	}
+
+	#[must_use]
+	/// # Minutes.
+	///
+	/// Return the minutes part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// let clock = NiceClock12::from(46_925_u32);
+	/// assert_eq!(clock.minutes(), 2);
+	/// ```
+	pub const fn minutes(&self) -> u8 {
+		(self.inner[3] - b'0') * 10 + (self.inner[4] - b'0')
+	}
+
+	#[must_use]
+	/// # Seconds.
+	///
+	/// Return the seconds part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// let clock = NiceClock12::from(46_925_u32);
+	/// assert_eq!(clock.seconds(), 5);
+	/// ```
+	pub const fn seconds(&self) -> u8 {
+		(self.inner[6] - b'0') * 10 + (self.inner[7] - b'0')
+	}
+
+	#[must_use]
+	/// # Is PM?
+	///
+	/// Returns `true` if the time is noon or later.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceClock12;
+	///
+	/// assert!(! NiceClock12::from(43_199_u32).is_pm());
+	/// assert!(NiceClock12::from(43_200_u32).is_pm());
+	/// ```
+	pub const fn is_pm(&self) -> bool { self.inner[9] == b'P' }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nice_clock12() {
+		let mut last = NiceClock12::MIN;
+		let mut prev = None;
+		for total in 0..86_400_u32 {
+			let clock24 = NiceClock::from(total);
+			let clock = NiceClock12::from(total);
+			let (h, m, s) = (clock24.hours(), clock24.minutes(), clock24.seconds());
+
+			// Check the formatting.
+			let h12 = match h % 12 { 0 => 12, h => h };
+			let ap = if h < 12 { "AM" } else { "PM" };
+			assert_eq!(clock.as_str(), format!("{h12:02}:{m:02}:{s:02} {ap}"));
+
+			// The parts should match.
+			assert_eq!(clock.hours(), h);
+			assert_eq!(clock.minutes(), m);
+			assert_eq!(clock.seconds(), s);
+			assert_eq!(clock.is_pm(), 12 <= h);
+
+			// And round-trip.
+			assert_eq!(NiceClock::from(clock), clock24);
+
+			// Check replacements too.
+			last.replace(total);
+			assert_eq!(last, clock);
+
+			// Ordering should be chronological.
+			if let Some(prev) = prev { assert!(prev < clock); }
+			prev.replace(clock);
+		}
+
+		assert_eq!(last, NiceClock12::MAX);
+		assert_eq!(NiceClock12::from(u64::MAX), NiceClock12::MAX);
+		assert_eq!(NiceClock12::from(i64::MIN), NiceClock12::MIN);
+		assert_eq!(NiceClock12::default(), NiceClock12::MIN);
+	}
+
+	#[test]
+	fn t_debug() {
+		let clock = NiceClock12::from(55_545_u32);
+		assert_eq!(format!("{clock:?}"), "NiceClock12(\"03:25:45 PM\")");
+		assert_eq!(
+			format!("{clock:#?}"),
+			"NiceClock12 {\n    value: 55545,\n    nice: \"03:25:45 PM\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", NiceClock::from(clock)),
+			"NiceClock {\n    value: 55545,\n    nice: \"15:25:45\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NiceElapsed::from(Duration::from_millis(61_500))),
+			"NiceElapsed {\n    value: 61.5s,\n    nice: \"1 minute and 1.50 seconds\",\n}",
+		);
+	}
+}
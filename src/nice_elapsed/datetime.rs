@@ -0,0 +1,495 @@
+/*!
+# Dactyl: "Nice" Date/Time
+*/
+
+use crate::NiceElapsed;
+use std::{
+	fmt,
+	ops::Deref,
+	time::SystemTime,
+};
+
+
+
+/// # Seconds Per Day.
+const DAY: u64 = 86_400;
+
+/// # Maximum Timestamp.
+///
+/// This is `9999-12-31 23:59:59`, the last moment expressible with a
+/// four-digit year.
+const MAX_UNIXTIME: u64 = 253_402_300_799;
+
+
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Nice Date/Time.
+///
+/// This struct is used to efficiently convert a Unix timestamp (seconds)
+/// into a `YYYY-MM-DD HH:MM:SS`-formatted UTC datetime string, e.g. for
+/// stamping log lines.
+///
+/// Counting begins at `1970-01-01 00:00:00` and tops out at
+/// `9999-12-31 23:59:59`; larger values are simply saturated to fit.
+///
+/// No time zones, leap seconds, or other calendar nonsense is supported. If
+/// you need that, use a real datetime library.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceDateTime;
+///
+/// let mut date = NiceDateTime::from(1_700_000_000_u64);
+/// assert_eq!(date.as_str(), "2023-11-14 22:13:20");
+///
+/// // Update the value in place.
+/// date.replace(951_782_400);
+/// assert_eq!(date.as_str(), "2000-02-29 00:00:00");
+///
+/// // You can get the parts back as numbers too:
+/// assert_eq!(date.year(), 2000);
+/// assert_eq!(date.month(), 2);
+/// assert_eq!(date.day(), 29);
+/// ```
+pub struct NiceDateTime {
+	/// # Formatted Data.
+	inner: [u8; 19],
+}
+
+impl AsRef<[u8]> for NiceDateTime {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceDateTime {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceDateTime {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceDateTime {
+	#[inline]
+	fn default() -> Self { Self::MIN }
+}
+
+impl Deref for NiceDateTime {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceDateTime {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceDateTime")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceDateTime {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl From<u64> for NiceDateTime {
+	#[inline]
+	fn from(num: u64) -> Self {
+		let mut out = Self::MIN;
+		out.replace(num);
+		out
+	}
+}
+
+impl From<SystemTime> for NiceDateTime {
+	#[inline]
+	/// # From System Time.
+	///
+	/// Return the wall-clock date and time, in UTC.
+	///
+	/// Partial seconds are dropped, and times before the Unix epoch are
+	/// saturated to `1970-01-01 00:00:00`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	/// use std::time::{Duration, SystemTime};
+	///
+	/// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+	/// assert_eq!(NiceDateTime::from(now).as_str(), "2023-11-14 22:13:20");
+	///
+	/// let then = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+	/// assert_eq!(NiceDateTime::from(then), NiceDateTime::MIN);
+	/// ```
+	fn from(src: SystemTime) -> Self {
+		src.duration_since(SystemTime::UNIX_EPOCH)
+			.map_or(Self::MIN, |d| Self::from(d.as_secs()))
+	}
+}
+
+impl From<NiceDateTime> for [u8; 19] {
+	#[inline]
+	fn from(num: NiceDateTime) -> Self { num.inner }
+}
+
+impl NiceDateTime {
+	/// # Minimum Value.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(
+	///     NiceDateTime::MIN.as_str(),
+	///     "1970-01-01 00:00:00",
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceDateTime::MIN,
+	///     NiceDateTime::from(0_u64),
+	/// );
+	/// ```
+	pub const MIN: Self = Self {
+		inner: *b"1970-01-01 00:00:00",
+	};
+
+	/// # Maximum Value.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(
+	///     NiceDateTime::MAX.as_str(),
+	///     "9999-12-31 23:59:59",
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceDateTime::MAX,
+	///     NiceDateTime::from(u64::MAX),
+	/// );
+	/// ```
+	pub const MAX: Self = Self {
+		inner: *b"9999-12-31 23:59:59",
+	};
+}
+
+impl NiceDateTime {
+	/// # Replace.
+	///
+	/// Update the datetime, in place.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// let mut date = NiceDateTime::from(1_u64);
+	/// assert_eq!(date.as_str(), "1970-01-01 00:00:01");
+	///
+	/// date.replace(86_400);
+	/// assert_eq!(date.as_str(), "1970-01-02 00:00:00");
+	/// ```
+	pub fn replace(&mut self, num: u64) {
+		if MAX_UNIXTIME <= num {
+			*self = Self::MAX;
+			return;
+		}
+
+		let (y, m, d) = civil(num / DAY);
+		let [hh, mm, ss] = NiceElapsed::hms((num % DAY) as u32);
+
+		let [y1, y2] = crate::double(usize::from(y / 100));
+		let [y3, y4] = crate::double(usize::from(y % 100));
+		let [m1, m2] = crate::double(usize::from(m));
+		let [d1, d2] = crate::double(usize::from(d));
+		let [hh1, hh2] = crate::double(usize::from(hh));
+		let [mm1, mm2] = crate::double(usize::from(mm));
+		let [ss1, ss2] = crate::double(usize::from(ss));
+
+		self.inner = [
+			y1, y2, y3, y4, b'-', m1, m2, b'-', d1, d2,
+			b' ',
+			hh1, hh2, b':', mm1, mm2, b':', ss1, ss2,
+		];
+	}
+}
+
+impl NiceDateTime {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(
+	///     NiceDateTime::from(90_u64).as_bytes(),
+	///     b"1970-01-01 00:01:30",
+	/// );
+	/// ```
+	pub const fn as_bytes(&self) -> &[u8] { self.inner.as_slice() }
+
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return a copy of the backing buffer along with the index the value
+	/// starts at. `NiceDateTime` values are fixed-width, so the latter is always
+	/// zero; it is included for consistency with the other `Nice*` types.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(
+	///     NiceDateTime::from(90_u64).to_array(),
+	///     (*b"1970-01-01 00:01:30", 0),
+	/// );
+	/// ```
+	pub const fn to_array(self) -> ([u8; 19], usize) { (self.inner, 0) }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(
+	///     NiceDateTime::from(90_u64).as_str(),
+	///     "1970-01-01 00:01:30",
+	/// );
+	/// ```
+	pub const fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.inner.as_slice()) }
+	}
+
+	#[must_use]
+	/// # Year.
+	///
+	/// Return the year part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).year(), 2023);
+	/// ```
+	pub const fn year(&self) -> u16 {
+		(self.inner[0] - b'0') as u16 * 1000 +
+		(self.inner[1] - b'0') as u16 * 100 +
+		(self.inner[2] - b'0') as u16 * 10 +
+		(self.inner[3] - b'0') as u16
+	}
+
+	#[must_use]
+	/// # Month.
+	///
+	/// Return the month part as a number (`1..=12`).
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).month(), 11);
+	/// ```
+	pub const fn month(&self) -> u8 {
+		(self.inner[5] - b'0') * 10 + (self.inner[6] - b'0')
+	}
+
+	#[must_use]
+	/// # Day.
+	///
+	/// Return the day part as a number (`1..=31`).
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).day(), 14);
+	/// ```
+	pub const fn day(&self) -> u8 {
+		(self.inner[8] - b'0') * 10 + (self.inner[9] - b'0')
+	}
+
+	#[must_use]
+	/// # Hours.
+	///
+	/// Return the hours part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).hours(), 22);
+	/// ```
+	pub const fn hours(&self) -> u8 {
+		(self.inner[11] - b'0') * 10 + (self.inner[12] - b'0')
+	}
+
+	#[must_use]
+	/// # Minutes.
+	///
+	/// Return the minutes part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).minutes(), 13);
+	/// ```
+	pub const fn minutes(&self) -> u8 {
+		(self.inner[14] - b'0') * 10 + (self.inner[15] - b'0')
+	}
+
+	#[must_use]
+	/// # Seconds.
+	///
+	/// Return the seconds part as a number.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceDateTime;
+	///
+	/// assert_eq!(NiceDateTime::from(1_700_000_000_u64).seconds(), 20);
+	/// ```
+	pub const fn seconds(&self) -> u8 {
+		(self.inner[17] - b'0') * 10 + (self.inner[18] - b'0')
+	}
+}
+
+
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[expect(clippy::integer_division, reason = "We want this.")]
+/// # Civil From Days.
+///
+/// Convert the number of days since the Unix epoch into a (proleptic
+/// Gregorian) year, month, and day.
+///
+/// This is Howard Hinnant's `civil_from_days` algorithm, simplified for
+/// non-negative inputs. The caller is expected to keep `days` within the
+/// four-digit year range.
+const fn civil(days: u64) -> (u16, u8, u8) {
+	let z = days + 719_468;
+	let era = z / 146_097;
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+	let m = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+	let y = (yoe + era * 400) as u16 + (m <= 2) as u16;
+	(y, m, d)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nice_datetime() {
+		for (num, expected) in [
+			(0, "1970-01-01 00:00:00"),
+			(59, "1970-01-01 00:00:59"),
+			(86_399, "1970-01-01 23:59:59"),
+			(86_400, "1970-01-02 00:00:00"),
+			(68_169_600, "1972-02-29 00:00:00"),
+			(946_684_799, "1999-12-31 23:59:59"),
+			(946_684_800, "2000-01-01 00:00:00"),
+			(951_868_799, "2000-02-29 23:59:59"),
+			(951_868_800, "2000-03-01 00:00:00"),
+			(1_234_567_890, "2009-02-13 23:31:30"),
+			(2_147_483_647, "2038-01-19 03:14:07"),
+			(4_107_542_400, "2100-03-01 00:00:00"),
+			(MAX_UNIXTIME - 1, "9999-12-31 23:59:58"),
+			(MAX_UNIXTIME, "9999-12-31 23:59:59"),
+			(u64::MAX, "9999-12-31 23:59:59"),
+		] {
+			let date = NiceDateTime::from(num);
+			assert_eq!(date.as_str(), expected, "{num}");
+
+			// Check the parts.
+			let (ymd, hms) = expected.split_once(' ').unwrap();
+			let mut ymd = ymd.split('-').map(|p| p.parse::<u16>().unwrap());
+			assert_eq!(date.year(), ymd.next().unwrap());
+			assert_eq!(u16::from(date.month()), ymd.next().unwrap());
+			assert_eq!(u16::from(date.day()), ymd.next().unwrap());
+			let mut hms = hms.split(':').map(|p| p.parse::<u8>().unwrap());
+			assert_eq!(date.hours(), hms.next().unwrap());
+			assert_eq!(date.minutes(), hms.next().unwrap());
+			assert_eq!(date.seconds(), hms.next().unwrap());
+		}
+
+		// Walk the calendar a day at a time, making sure the dates roll over
+		// the way they should.
+		let mut last = NiceDateTime::MIN;
+		let mut num = 0;
+		while num + DAY <= MAX_UNIXTIME {
+			let next = NiceDateTime::from(num + DAY);
+			assert!(last < next, "{num}");
+
+			let (y, m, d) = (last.year(), last.month(), last.day());
+			let leap = (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+			let days = match m {
+				2 => if leap { 29 } else { 28 },
+				4 | 6 | 9 | 11 => 30,
+				_ => 31,
+			};
+			if d == days {
+				assert_eq!(next.day(), 1, "{num}");
+				if m == 12 {
+					assert_eq!(next.month(), 1, "{num}");
+					assert_eq!(next.year(), y + 1, "{num}");
+				}
+				else { assert_eq!(next.month(), m + 1, "{num}"); }
+			}
+			else {
+				assert_eq!(next.day(), d + 1, "{num}");
+				assert_eq!(next.month(), m, "{num}");
+			}
+
+			last.replace(num + DAY);
+			assert_eq!(last, next);
+			num += DAY;
+		}
+	}
+
+	#[test]
+	fn t_from_system_time() {
+		use std::time::Duration;
+
+		let mut rng = fastrand::Rng::new();
+		for (secs, nanos) in std::iter::repeat_with(|| (rng.u64(..MAX_UNIXTIME), rng.u32(..1_000_000_000))).take(10_000) {
+			let time = SystemTime::UNIX_EPOCH + Duration::new(secs, nanos);
+			assert_eq!(NiceDateTime::from(time), NiceDateTime::from(secs));
+		}
+
+		assert_eq!(NiceDateTime::from(SystemTime::UNIX_EPOCH), NiceDateTime::MIN);
+		assert_eq!(
+			NiceDateTime::from(SystemTime::UNIX_EPOCH - Duration::from_nanos(1)),
+			NiceDateTime::MIN,
+		);
+	}
+}
@@ -0,0 +1,323 @@
+/*!
+# Dactyl: "Nice" Elapsed (ISO-8601)
+*/
+
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+/// # Array Size.
+///
+/// `PT` + 5124095576030431 + `H` + `59M` + `59.999S` = 29 bytes.
+const SIZE: usize = 29;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice ISO-8601 Duration.
+///
+/// This struct is used to efficiently convert a [`Duration`] into an
+/// ISO-8601 duration string like `PT1H2M3.450S`, suitable for JSON APIs,
+/// metric metadata, and the like.
+///
+/// The largest unit is hours — days are not used, as their length is
+/// ambiguous under the standard — and the smallest unit is milliseconds,
+/// included (as three decimal places) only when non-zero. Empty units are
+/// omitted, except for zero itself, which is written as `PT0S`.
+///
+/// For human-readable output, see [`NiceClock`](crate::NiceClock) or
+/// [`NiceElapsed`](crate::NiceElapsed) instead.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceIso8601;
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     NiceIso8601::from(Duration::from_millis(3_723_450)).as_str(),
+///     "PT1H2M3.450S",
+/// );
+/// assert_eq!(
+///     NiceIso8601::from(Duration::from_secs(60)).as_str(),
+///     "PT1M",
+/// );
+///
+/// // Update the value in place.
+/// let mut iso = NiceIso8601::MIN;
+/// assert_eq!(iso.as_str(), "PT0S");
+/// iso.replace(Duration::from_millis(1500));
+/// assert_eq!(iso.as_str(), "PT1.500S");
+/// ```
+pub struct NiceIso8601 {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Actual Length.
+	len: usize,
+}
+
+impl AsRef<[u8]> for NiceIso8601 {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceIso8601 {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceIso8601 {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceIso8601 {
+	#[inline]
+	fn default() -> Self { Self::MIN }
+}
+
+impl Deref for NiceIso8601 {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceIso8601 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceIso8601")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceIso8601 {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceIso8601 {}
+
+impl From<Duration> for NiceIso8601 {
+	#[inline]
+	fn from(src: Duration) -> Self {
+		let mut out = Self::MIN;
+		out.replace(src);
+		out
+	}
+}
+
+impl From<Instant> for NiceIso8601 {
+	#[inline]
+	fn from(src: Instant) -> Self { Self::from(src.elapsed()) }
+}
+
+impl hash::Hash for NiceIso8601 {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceIso8601 {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceIso8601 {
+	/// # Minimum Value.
+	///
+	/// ```
+	/// use dactyl::NiceIso8601;
+	/// use std::time::Duration;
+	///
+	/// assert_eq!(
+	///     NiceIso8601::MIN.as_str(),
+	///     "PT0S",
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceIso8601::MIN,
+	///     NiceIso8601::from(Duration::ZERO),
+	/// );
+	/// ```
+	pub const MIN: Self = Self {
+		inner: *b"PT0S0000000000000000000000000",
+		len: 4,
+	};
+}
+
+impl NiceIso8601 {
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new duration.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceIso8601;
+	/// use std::time::Duration;
+	///
+	/// let mut iso = NiceIso8601::from(Duration::from_secs(7200));
+	/// assert_eq!(iso.as_str(), "PT2H");
+	///
+	/// iso.replace(Duration::from_secs(7201));
+	/// assert_eq!(iso.as_str(), "PT2H1S");
+	/// ```
+	pub fn replace(&mut self, src: Duration) {
+		let secs = src.as_secs();
+		let ms = src.subsec_millis();
+
+		// Zero is special.
+		if secs == 0 && ms == 0 {
+			*self = Self::MIN;
+			return;
+		}
+
+		// Everything else starts with the same prefix.
+		self.len = 2;
+
+		let h = secs / 3600;
+		let m = ((secs % 3600) / 60) as u8;
+		let s = (secs % 60) as u8;
+
+		// Hours.
+		if h != 0 {
+			self.push_u64(h);
+			self.push(b'H');
+		}
+
+		// Minutes.
+		if m != 0 {
+			self.push_u64(u64::from(m));
+			self.push(b'M');
+		}
+
+		// Seconds and milliseconds.
+		if s != 0 || ms != 0 {
+			self.push_u64(u64::from(s));
+			if ms != 0 {
+				self.push(b'.');
+				for b in crate::triple(ms as usize) { self.push(b); }
+			}
+			self.push(b'S');
+		}
+	}
+
+	/// # Push Byte.
+	const fn push(&mut self, b: u8) {
+		self.inner[self.len] = b;
+		self.len += 1;
+	}
+
+	/// # Push Integer.
+	///
+	/// Write the digits of `num` to the end of the buffer, without any
+	/// padding or separators.
+	fn push_u64(&mut self, mut num: u64) {
+		let mut buf = [b'0'; 20];
+		let mut from = buf.len();
+		loop {
+			from -= 1;
+			buf[from] = (num % 10) as u8 + b'0';
+			num /= 10;
+			if num == 0 { break; }
+		}
+
+		let digits = &buf[from..];
+		self.inner[self.len..self.len + digits.len()].copy_from_slice(digits);
+		self.len += digits.len();
+	}
+}
+
+impl NiceIso8601 {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceIso8601;
+	/// use std::time::Duration;
+	///
+	/// assert_eq!(
+	///     NiceIso8601::from(Duration::from_secs(90)).as_bytes(),
+	///     b"PT1M30S",
+	/// );
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[..self.len] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceIso8601;
+	/// use std::time::Duration;
+	///
+	/// assert_eq!(
+	///     NiceIso8601::from(Duration::from_secs(90)).as_str(),
+	///     "PT1M30S",
+	/// );
+	/// ```
+	pub fn as_str(&self) -> &str {
+		debug_assert!(std::str::from_utf8(self.as_bytes()).is_ok(), "Bug: NiceIso8601 is not UTF.");
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nice_iso8601() {
+		for (src, expected) in [
+			(Duration::ZERO, "PT0S"),
+			(Duration::from_millis(1), "PT0.001S"),
+			(Duration::from_micros(999), "PT0S"),
+			(Duration::from_secs(1), "PT1S"),
+			(Duration::from_secs(59), "PT59S"),
+			(Duration::from_secs(60), "PT1M"),
+			(Duration::from_secs(61), "PT1M1S"),
+			(Duration::from_secs(3600), "PT1H"),
+			(Duration::from_secs(3601), "PT1H1S"),
+			(Duration::from_millis(3_723_450), "PT1H2M3.450S"),
+			(Duration::from_secs(86_400), "PT24H"),
+			(Duration::from_millis(86_400_001), "PT24H0.001S"),
+			(Duration::MAX, "PT5124095576030431H15.999S"),
+		] {
+			let iso = NiceIso8601::from(src);
+			assert_eq!(iso.as_str(), expected);
+			assert_eq!(iso.as_bytes(), expected.as_bytes());
+			assert_eq!(iso.to_string(), expected);
+		}
+
+		// Replacement should land in the same place, even going from long to
+		// short.
+		let mut last = NiceIso8601::from(Duration::MAX);
+		for i in (0..100_000_u64).step_by(7) {
+			let src = Duration::from_millis(i * 37);
+			last.replace(src);
+			assert_eq!(last, NiceIso8601::from(src));
+		}
+		last.replace(Duration::ZERO);
+		assert_eq!(last, NiceIso8601::MIN);
+	}
+}
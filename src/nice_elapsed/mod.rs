@@ -3,12 +3,20 @@
 */
 
 pub(super) mod clock;
+pub(super) mod clock12;
+pub(super) mod datetime;
+pub(super) mod iso8601;
+pub(super) mod timer;
 
 use crate::{
+	NiceClock,
+	NiceIso8601,
+	NiceU8,
 	NiceU16,
 	traits::SaturatingFrom,
 };
 use std::{
+	cmp::Ordering,
 	fmt,
 	hash,
 	ops::Deref,
@@ -21,7 +29,13 @@ use std::{
 
 
 /// # Array Size.
-const SIZE: usize = 52;
+const SIZE: usize = 59;
+
+/// # Default Sub-Second Precision.
+const DEFAULT_PRECISION: u8 = 2;
+
+/// # Maximum Sub-Second Precision.
+const MAX_PRECISION: u8 = 9;
 
 /// # Helper: Generate Impl
 macro_rules! elapsed_from {
@@ -43,6 +57,31 @@ macro_rules! elapsed_from {
 	)+);
 }
 
+/// # Helper: Generate Impl (Signed).
+macro_rules! elapsed_from_signed {
+	($($type:ty),+) => ($(
+		impl From<$type> for NiceElapsed {
+			#[inline]
+			/// Negative values — e.g. overdue countdowns — are rendered with
+			/// a leading minus sign. As with the unsigned implementations,
+			/// the absolute value is capped to [`u32::MAX`].
+			///
+			/// ```
+			/// use dactyl::NiceElapsed;
+			///
+			#[doc = concat!("let nice = NiceElapsed::from(-65_", stringify!($type), ");")]
+			/// assert_eq!(nice.as_str(), "-1 minute and 5 seconds");
+			/// assert!(nice.is_negative());
+			/// ```
+			fn from(num: $type) -> Self {
+				let out = Self::from(num.unsigned_abs());
+				if num < 0 { out.negate() }
+				else { out }
+			}
+		}
+	)+);
+}
+
 
 
 #[derive(Clone, Copy)]
@@ -54,8 +93,14 @@ macro_rules! elapsed_from {
 /// `From<Duration>` or `From<Instant>`, in which case milliseconds (to two
 /// decimal places) will be included, unless zero.
 ///
+/// For more (or less) sub-second precision — up to nanoseconds — use
+/// [`NiceElapsed::from_duration_precise`] instead.
+///
 /// For a more clock-like output, see [`NiceClock`](crate::NiceClock).
 ///
+/// Ordering is chronological, based on the (displayed) amount of time
+/// elapsed.
+///
 /// ## Examples
 ///
 /// ```
@@ -64,6 +109,17 @@ macro_rules! elapsed_from {
 ///     NiceElapsed::from(61_u32).as_str(),
 ///     "1 minute and 1 second"
 /// );
+///
+/// // Sorting works chronologically.
+/// let mut all = vec![
+///     NiceElapsed::from(3600_u32),
+///     NiceElapsed::from(61_u32),
+///     NiceElapsed::from(90_000_u32),
+/// ];
+/// all.sort();
+/// assert_eq!(all[0].as_str(), "1 minute and 1 second");
+/// assert_eq!(all[1].as_str(), "1 hour");
+/// assert_eq!(all[2].as_str(), "1 day and 1 hour");
 /// ```
 pub struct NiceElapsed {
 	/// # Buffer.
@@ -71,6 +127,13 @@ pub struct NiceElapsed {
 
 	/// # Actual Length.
 	len: usize,
+
+	/// # Elapsed Time.
+	///
+	/// This is the amount of time actually represented by the formatted
+	/// value — i.e. after saturation and sub-second truncation — used for
+	/// ordering.
+	elapsed: Duration,
 }
 
 impl AsRef<[u8]> for NiceElapsed {
@@ -94,6 +157,7 @@ impl Default for NiceElapsed {
 		Self {
 			inner: [b' '; SIZE],
 			len: 0,
+			elapsed: Duration::ZERO,
 		}
 	}
 }
@@ -105,11 +169,24 @@ impl Deref for NiceElapsed {
 	fn deref(&self) -> &Self::Target { self.as_bytes() }
 }
 
+#[expect(clippy::missing_fields_in_debug, reason = "Buffers are internal.")]
 impl fmt::Debug for NiceElapsed {
+	/// # Debug.
+	///
+	/// The alternate form — `{:#?}` — includes the elapsed time (as a
+	/// [`Duration`]) alongside the string.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.debug_tuple("NiceElapsed")
-			.field(&self.as_str())
-			.finish()
+		if f.alternate() {
+			f.debug_struct("NiceElapsed")
+				.field("value", &self.elapsed)
+				.field("nice", &self.as_str())
+				.finish()
+		}
+		else {
+			f.debug_tuple("NiceElapsed")
+				.field(&self.as_str())
+				.finish()
+		}
 	}
 }
 
@@ -123,22 +200,9 @@ impl fmt::Display for NiceElapsed {
 impl Eq for NiceElapsed {}
 
 impl From<Duration> for NiceElapsed {
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[inline]
 	fn from(src: Duration) -> Self {
-		let s = src.as_secs();
-		let ms =
-			(src.as_millis() - u128::from(s) * 1000) // Only ms.
-			.wrapping_div(10) // Truncate to max 2 digits (from possible 3).
-			as u8; // 0-99 fits u8.
-
-		// Nothing.
-		if s == 0 && ms == 0 { Self::min() }
-		// Something.
-		else {
-			debug_assert!(ms < 100, "BUG: Milliseconds should never be more than two digits.");
-			let (d, h, m, s) = Self::dhms(u32::saturating_from(s));
-			Self::from_parts(d, h, m, s, ms)
-		}
+		Self::from_duration_precise(src, DEFAULT_PRECISION)
 	}
 }
 
@@ -155,24 +219,49 @@ impl From<u32> for NiceElapsed {
 		// Something.
 		else {
 			let (d, h, m, s) = Self::dhms(num);
-			Self::from_parts(d, h, m, s, 0)
+			Self::from_parts(d, h, m, s, 0, 0)
 		}
 	}
 }
 
 // These all work the same way.
 elapsed_from!(usize, u64, u128);
+elapsed_from_signed!(i32, i64);
 
 impl hash::Hash for NiceElapsed {
 	#[inline]
 	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
 }
 
+impl Ord for NiceElapsed {
+	#[inline]
+	/// # Compare.
+	///
+	/// Values are compared by the amount of time elapsed, negatives first.
+	/// Different representations of the same amount of time — e.g. `1.5`
+	/// versus `1.50` seconds — fall back to a byte comparison to remain
+	/// consistent with `Eq`.
+	fn cmp(&self, other: &Self) -> Ordering {
+		let elapsed = match (self.is_negative(), other.is_negative()) {
+			(false, false) => self.elapsed.cmp(&other.elapsed),
+			(true, true) => other.elapsed.cmp(&self.elapsed),
+			(true, false) => Ordering::Less,
+			(false, true) => Ordering::Greater,
+		};
+		elapsed.then_with(|| self.as_bytes().cmp(other.as_bytes()))
+	}
+}
+
 impl PartialEq for NiceElapsed {
 	#[inline]
 	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
 }
 
+impl PartialOrd for NiceElapsed {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
 impl NiceElapsed {
 	#[must_use]
 	#[inline]
@@ -189,13 +278,60 @@ impl NiceElapsed {
 	/// ```
 	pub const fn min() -> Self {
 		Self {
-			inner: *b"0 seconds                                           ",
+			inner: *b"0 seconds                                                  ",
 			len: 9,
+			elapsed: Duration::ZERO,
+		}
+	}
+
+	#[must_use]
+	/// # From Duration (Precise).
+	///
+	/// Like `From<Duration>`, but with a custom number of decimal places for
+	/// the sub-second part, from `0` (none) to `9` (nanoseconds). Values
+	/// outside that range are clamped.
+	///
+	/// As with the default — two places — the fractional part is truncated
+	/// rather than rounded, and omitted entirely if zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// use std::time::Duration;
+	///
+	/// let d = Duration::from_nanos(61_234_567_890);
+	/// assert_eq!(
+	///     NiceElapsed::from_duration_precise(d, 6).as_str(),
+	///     "1 minute and 1.234567 seconds"
+	/// );
+	/// assert_eq!(
+	///     NiceElapsed::from_duration_precise(d, 0).as_str(),
+	///     "1 minute and 1 second"
+	/// );
+	///
+	/// // Two places is the default.
+	/// assert_eq!(
+	///     NiceElapsed::from_duration_precise(d, 2),
+	///     NiceElapsed::from(d),
+	/// );
+	/// ```
+	pub fn from_duration_precise(src: Duration, precision: u8) -> Self {
+		let precision = precision.min(MAX_PRECISION);
+		let s = src.as_secs();
+		let frac = src.subsec_nanos() / 10_u32.pow(u32::from(MAX_PRECISION - precision));
+
+		// Nothing.
+		if s == 0 && frac == 0 { Self::min() }
+		// Something.
+		else {
+			let (d, h, m, s) = Self::dhms(u32::saturating_from(s));
+			Self::from_parts(d, h, m, s, frac, precision)
 		}
 	}
 
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
+	#[inline]
 	/// # Time Chunks (with Days).
 	///
 	/// This works just like [`NiceElapsed::hms`], but counts up days too.
@@ -203,6 +339,8 @@ impl NiceElapsed {
 	/// Note that unlike the time units, which have really small caps, days can
 	/// reach up to `49,710`, so are returned as a `u16`.
 	///
+	/// This is an alias of [`time::dhms`](crate::time::dhms).
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -210,28 +348,16 @@ impl NiceElapsed {
 	/// assert_eq!(NiceElapsed::dhms(1_123_321), (13_u16, 0_u8, 2_u8, 1_u8));
 	/// assert_eq!(NiceElapsed::dhms(3661), (0_u16, 1_u8, 1_u8, 1_u8));
 	/// ```
-	pub const fn dhms(num: u32) -> (u16, u8, u8, u8) {
-		let (d, [h, m, s]) =
-			if num < 86_400 {
-				(0, Self::hms(num))
-			}
-			else {
-				(num.wrapping_div(86_400) as u16, Self::hms(num % 86_400))
-			};
-
-		(d, h, m, s)
-	}
+	pub const fn dhms(num: u32) -> (u16, u8, u8, u8) { crate::time::dhms(num) }
 
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
+	#[inline]
 	/// # Time Chunks.
 	///
 	/// This method splits seconds into hours, minutes, and seconds. Days are not
 	/// supported; the maximum return value is `[23, 59, 59]`.
 	///
-	/// Given the limited range of digits involved, we're able to use some data
-	/// rounding trickery to achieve conversion, bypassing the need for
-	/// (relatively) expensive division and remainder calculations.
+	/// This is an alias of [`time::hms`](crate::time::hms).
 	///
 	/// ## Examples
 	///
@@ -239,30 +365,101 @@ impl NiceElapsed {
 	/// use dactyl::NiceElapsed;
 	/// assert_eq!(NiceElapsed::hms(121), [0_u8, 2_u8, 1_u8]);
 	/// ```
-	pub const fn hms(mut num: u32) -> [u8; 3] {
-		if num < 60 { [0, 0, num as u8] }
-		else if num < 86399 {
-			let mut buf = [0_u8; 3];
+	pub const fn hms(num: u32) -> [u8; 3] { crate::time::hms(num) }
 
-			// There are hours.
-			if num >= 3600 {
-				buf[0] = ((num * 0x91A3) >> 27) as u8;
-				num -= buf[0] as u32 * 3600;
-			}
-
-			// There are minutes.
-			if num >= 60 {
-				buf[1] = ((num * 0x889) >> 17) as u8;
-				buf[2] = (num - buf[1] as u32 * 60) as u8;
-			}
-			// There are seconds.
-			else if num > 0 { buf[2] = num as u8; }
+	#[must_use]
+	/// # Seconds From Time Chunks (with Days).
+	///
+	/// This is the inverse of [`NiceElapsed::dhms`], rebuilding the total
+	/// number of seconds from days, hours, minutes, and seconds.
+	///
+	/// The parts need not be normalized — `90` minutes is fine — but the
+	/// result saturates at `u32::MAX`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// assert_eq!(NiceElapsed::seconds_from_dhms(13, 0, 2, 1), 1_123_321);
+	/// assert_eq!(NiceElapsed::seconds_from_dhms(0, 0, 90, 0), 5400);
+	///
+	/// // Round trip!
+	/// let (d, h, m, s) = NiceElapsed::dhms(3661);
+	/// assert_eq!(NiceElapsed::seconds_from_dhms(d, h, m, s), 3661);
+	///
+	/// // Saturation.
+	/// assert_eq!(NiceElapsed::seconds_from_dhms(u16::MAX, 0, 0, 0), u32::MAX);
+	/// ```
+	pub const fn seconds_from_dhms(d: u16, h: u8, m: u8, s: u8) -> u32 {
+		(d as u32).saturating_mul(86_400)
+			.saturating_add(Self::seconds_from_hms(h, m, s))
+	}
 
-			buf
-		}
-		else { [23, 59, 59] }
+	#[must_use]
+	/// # Seconds From Time Chunks.
+	///
+	/// This is the inverse of [`NiceElapsed::hms`], rebuilding the total number
+	/// of seconds from hours, minutes, and seconds.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// assert_eq!(NiceElapsed::seconds_from_hms(0, 2, 1), 121);
+	///
+	/// // Round trip!
+	/// let [h, m, s] = NiceElapsed::hms(45_296);
+	/// assert_eq!(NiceElapsed::seconds_from_hms(h, m, s), 45_296);
+	/// ```
+	pub const fn seconds_from_hms(h: u8, m: u8, s: u8) -> u32 {
+		// This can't overflow; the maximum is less than a million.
+		h as u32 * 3600 + m as u32 * 60 + s as u32
 	}
 
+	#[must_use]
+	/// # Total Minutes.
+	///
+	/// Return the number of whole minutes in `num` seconds, rounding down.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// assert_eq!(NiceElapsed::total_minutes(59), 0);
+	/// assert_eq!(NiceElapsed::total_minutes(1_123_321), 18_722);
+	/// ```
+	pub const fn total_minutes(num: u32) -> u32 { num.wrapping_div(60) }
+
+	#[must_use]
+	/// # Total Hours.
+	///
+	/// Return the number of whole hours in `num` seconds, rounding down.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// assert_eq!(NiceElapsed::total_hours(3599), 0);
+	/// assert_eq!(NiceElapsed::total_hours(1_123_321), 312);
+	/// ```
+	pub const fn total_hours(num: u32) -> u32 { num.wrapping_div(3600) }
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Total Days.
+	///
+	/// Return the number of whole days in `num` seconds, rounding down. This
+	/// matches the first value returned by [`NiceElapsed::dhms`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// assert_eq!(NiceElapsed::total_days(86_399), 0);
+	/// assert_eq!(NiceElapsed::total_days(1_123_321), 13);
+	/// ```
+	pub const fn total_days(num: u32) -> u16 { num.wrapping_div(86_400) as u16 }
+
 	#[must_use]
 	#[inline]
 	/// # As Bytes.
@@ -280,7 +477,25 @@ impl NiceElapsed {
 	/// ```
 	pub fn as_bytes(&self) -> &[u8] { &self.inner[0..self.len] }
 
-	#[expect(unsafe_code, reason = "Content is ASCII.")]
+	#[must_use]
+	#[inline]
+	/// # Is Negative?
+	///
+	/// Returns `true` if the value was created from a negative number of
+	/// seconds, e.g. `-1 minute and 5 seconds`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	///
+	/// assert!(NiceElapsed::from(-5_i32).is_negative());
+	/// assert!(! NiceElapsed::from(5_i32).is_negative());
+	/// assert!(! NiceElapsed::from(0_i32).is_negative());
+	/// ```
+	pub const fn is_negative(&self) -> bool { self.len != 0 && self.inner[0] == b'-' }
+
+	#[expect(unsafe_code, reason = "Content is valid UTF-8.")]
 	#[must_use]
 	#[inline]
 	/// # As Str.
@@ -297,28 +512,180 @@ impl NiceElapsed {
 	/// );
 	/// ```
 	pub fn as_str(&self) -> &str {
-		debug_assert!(self.as_bytes().is_ascii(), "Bug: NiceElapsed is not ASCII.");
-		// Safety: numbers and labels are valid ASCII.
+		debug_assert!(std::str::from_utf8(self.as_bytes()).is_ok(), "Bug: NiceElapsed is not UTF-8.");
+		// Safety: numbers are ASCII, and labels are either ASCII or — if
+		// custom — copied whole from valid string slices.
 		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
 	}
 }
 
 impl NiceElapsed {
+	#[must_use]
+	#[inline]
+	/// # To Clock.
+	///
+	/// Return the same amount of time as a [`NiceClock`], like `01:02:03`.
+	///
+	/// Clocks have no room for days, so values of a day or more saturate to
+	/// [`NiceClock::MAX`]; sub-second precision, if any, is dropped, as is
+	/// the sign of [negative](NiceElapsed::is_negative) values.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceClock, NiceElapsed};
+	///
+	/// let elapsed = NiceElapsed::from(3723_u32);
+	/// assert_eq!(elapsed.as_str(), "1 hour, 2 minutes, and 3 seconds");
+	/// assert_eq!(elapsed.to_clock().as_str(), "01:02:03");
+	///
+	/// // Too big!
+	/// assert_eq!(NiceElapsed::from(86_400_u32).to_clock(), NiceClock::MAX);
+	/// ```
+	pub fn to_clock(&self) -> NiceClock { NiceClock::from(self.elapsed) }
+
+	#[must_use]
+	#[inline]
+	/// # To ISO-8601.
+	///
+	/// Return the same amount of time as a [`NiceIso8601`] duration, like
+	/// `PT1H2M3S`. The sign of [negative](NiceElapsed::is_negative) values is
+	/// dropped.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceElapsed;
+	/// use std::time::Duration;
+	///
+	/// let elapsed = NiceElapsed::from(3723_u32);
+	/// assert_eq!(elapsed.to_iso8601().as_str(), "PT1H2M3S");
+	///
+	/// // Sub-seconds carry over too, at the displayed precision.
+	/// let elapsed = NiceElapsed::from(Duration::from_millis(1_259));
+	/// assert_eq!(elapsed.as_str(), "1.25 seconds");
+	/// assert_eq!(elapsed.to_iso8601().as_str(), "PT1.250S");
+	/// ```
+	pub fn to_iso8601(&self) -> NiceIso8601 { NiceIso8601::from(self.elapsed) }
+}
+
+impl NiceElapsed {
+	#[must_use]
+	/// # With Labels.
+	///
+	/// Like `From<u32>`, but with custom unit labels and joining glue, e.g.
+	/// for translation. See [`NiceElapsedLabels`] for details.
+	///
+	/// Because the buffer is fixed, this will return `None` if the result
+	/// would be longer than [`NiceElapsed::MAX_LEN`] bytes, which is possible
+	/// with wordier labels and larger values.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceElapsed, NiceElapsedLabels};
+	///
+	/// const SHORT: NiceElapsedLabels = NiceElapsedLabels {
+	///     day: ("d", "d"),
+	///     hour: ("h", "h"),
+	///     minute: ("m", "m"),
+	///     second: ("s", "s"),
+	///     and: " ",
+	///     comma: " ",
+	///     comma_and: " ",
+	/// };
+	///
+	/// assert_eq!(NiceElapsed::with_labels(0, &SHORT).unwrap().as_str(), "0 s");
+	/// assert_eq!(NiceElapsed::with_labels(93_784, &SHORT).unwrap().as_str(), "1 d 2 h 3 m 4 s");
+	/// ```
+	pub fn with_labels(num: u32, labels: &NiceElapsedLabels) -> Option<Self> {
+		let (d, h, m, s) = Self::dhms(num);
+		let d = NiceU16::from(d);
+		let (h, m, s) = (NiceU8::from(h), NiceU8::from(m), NiceU8::from(s));
+		let mut parts = [
+			(d.as_bytes(), labels.day),
+			(h.as_bytes(), labels.hour),
+			(m.as_bytes(), labels.minute),
+			(s.as_bytes(), labels.second),
+		];
+
+		// Drop the zeroes, unless everything is zero.
+		let mut total = 0;
+		for idx in 0..parts.len() {
+			if parts[idx].0 != b"0" {
+				parts[total] = parts[idx];
+				total += 1;
+			}
+		}
+		if total == 0 {
+			parts[0] = parts[3];
+			total = 1;
+		}
+
+		let mut out = Self {
+			inner: [b' '; SIZE],
+			len: 0,
+			elapsed: Duration::from_secs(u64::from(num)),
+		};
+		for (idx, (num, (singular, plural))) in parts[..total].iter().copied().enumerate() {
+			let glue =
+				if idx + 1 == total { "" }
+				else if total == 2 { labels.and }
+				else if idx + 2 == total { labels.comma_and }
+				else { labels.comma };
+			let label = if num == b"1" { singular } else { plural };
+
+			out.push(num)?;
+			out.push(b" ")?;
+			out.push(label.as_bytes())?;
+			out.push(glue.as_bytes())?;
+		}
+
+		Some(out)
+	}
+
+	/// # Negate.
+	///
+	/// Prepend a minus sign to the (non-zero, integer) value.
+	///
+	/// The longest whole-second value is well under the buffer size, so
+	/// there will always be room.
+	fn negate(mut self) -> Self {
+		if self.elapsed.is_zero() || SIZE <= self.len { return self; }
+		self.inner.copy_within(..self.len, 1);
+		self.inner[0] = b'-';
+		self.len += 1;
+		self
+	}
+
+	/// # Push.
+	///
+	/// Append `src` to the buffer, or return `None` if it won't fit.
+	fn push(&mut self, src: &[u8]) -> Option<()> {
+		let end = self.len + src.len();
+		self.inner.get_mut(self.len..end)?.copy_from_slice(src);
+		self.len = end;
+		Some(())
+	}
+
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
-	#[expect(
-		clippy::many_single_char_names,
-		clippy::similar_names,
-		reason = "Consistency is preferred.",
-	)]
+	#[expect(clippy::similar_names, reason = "Consistency is preferred.")]
 	/// # From DHMS.ms.
 	///
-	/// Build with days, hours, minutes, seconds, and milliseconds (hundredths).
-	fn from_parts(d: u16, h: u8, m: u8, s: u8, ms: u8) -> Self {
+	/// Build with days, hours, minutes, seconds, and fractional seconds
+	/// (`frac`, in `precision` decimal places).
+	fn from_parts(d: u16, h: u8, m: u8, s: u8, mut frac: u32, precision: u8) -> Self {
+		// Note the actual amount of time being represented.
+		let elapsed = Duration::new(
+			u64::from(Self::seconds_from_dhms(d, h, m, s)),
+			frac * 10_u32.pow(u32::from(MAX_PRECISION - precision)),
+		);
+
 		// Figure out which parts apply.
 		let has_d = 0 != d;
 		let has_h = 0 != h;
 		let has_m = 0 != m;
-		let has_ms = 0 != ms;
+		let has_ms = 0 != frac;
 		let has_s = has_ms || 0 != s;
 
 		// How many sections are there to write?
@@ -380,22 +747,114 @@ impl NiceElapsed {
 			idx += 1;
 			len += write_u8_to_slice(s, &mut inner[len..]);
 
-			// They might need milliseconds before the label.
+			// They might need fractional seconds before the label.
 			if has_ms {
-				let [b, c] = crate::double(ms as usize);
-				inner[len..len + 3].copy_from_slice(&[b'.', b, c]);
-				len += 3;
+				inner[len] = b'.';
+				len += usize::from(precision) + 1;
+				for v in inner[len - usize::from(precision)..len].iter_mut().rev() {
+					*v = (frac % 10) as u8 + b'0';
+					frac /= 10;
+				}
 			}
 
 			len += LabelKind::Second.write_to_slice(1 == s && ! has_ms, idx, total, &mut inner[len..]);
 		}
 
-		Self { inner, len }
+		Self { inner, len, elapsed }
 	}
 }
 
 
 
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Nice Elapsed Labels.
+///
+/// This struct holds the unit labels and joining glue used by
+/// [`NiceElapsed::with_labels`], allowing translations — or just different
+/// wording — to be supplied without allocation.
+///
+/// Each unit is a `(singular, plural)` pair. Labels are separated from their
+/// numbers by a single space; the glue strings should include whatever
+/// spacing they need.
+///
+/// Lists of two use `and`; longer lists use `comma` between parts, and
+/// `comma_and` before the last one. (English uses an Oxford comma; many
+/// other languages don't, so would set `comma_and` the same as `and`.)
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceElapsed, NiceElapsedLabels};
+///
+/// const DE: NiceElapsedLabels = NiceElapsedLabels {
+///     day: ("Tag", "Tage"),
+///     hour: ("Stunde", "Stunden"),
+///     minute: ("Minute", "Minuten"),
+///     second: ("Sekunde", "Sekunden"),
+///     and: " und ",
+///     comma: ", ",
+///     comma_and: " und ",
+/// };
+///
+/// assert_eq!(
+///     NiceElapsed::with_labels(3723, &DE).unwrap().as_str(),
+///     "1 Stunde, 2 Minuten und 3 Sekunden",
+/// );
+/// ```
+pub struct NiceElapsedLabels {
+	/// # Day(s).
+	pub day: (&'static str, &'static str),
+
+	/// # Hour(s).
+	pub hour: (&'static str, &'static str),
+
+	/// # Minute(s).
+	pub minute: (&'static str, &'static str),
+
+	/// # Second(s).
+	pub second: (&'static str, &'static str),
+
+	/// # Join (Pairs).
+	pub and: &'static str,
+
+	/// # Join (Lists).
+	pub comma: &'static str,
+
+	/// # Join (Last in List).
+	pub comma_and: &'static str,
+}
+
+impl Default for NiceElapsedLabels {
+	#[inline]
+	fn default() -> Self { Self::ENGLISH }
+}
+
+impl NiceElapsedLabels {
+	/// # English.
+	///
+	/// The labels used by the regular [`NiceElapsed`] constructors.
+	///
+	/// ```
+	/// use dactyl::{NiceElapsed, NiceElapsedLabels};
+	///
+	/// assert_eq!(
+	///     NiceElapsed::with_labels(90_061, &NiceElapsedLabels::ENGLISH),
+	///     Some(NiceElapsed::from(90_061_u32)),
+	/// );
+	/// ```
+	pub const ENGLISH: Self = Self {
+		day: ("day", "days"),
+		hour: ("hour", "hours"),
+		minute: ("minute", "minutes"),
+		second: ("second", "seconds"),
+		and: " and ",
+		comma: ", ",
+		comma_and: ", and ",
+	};
+}
+
+
+
 #[derive(Debug, Clone, Copy)]
 /// # Join Style.
 ///
@@ -532,76 +991,272 @@ mod tests {
 
 	#[test]
 	fn t_from() {
-		_from(0, "0 seconds");
-		_from(1, "1 second");
-		_from(50, "50 seconds");
-
-		_from(60, "1 minute");
-		_from(61, "1 minute and 1 second");
-		_from(100, "1 minute and 40 seconds");
-		_from(2101, "35 minutes and 1 second");
-		_from(2121, "35 minutes and 21 seconds");
-
-		_from(3600, "1 hour");
-		_from(3601, "1 hour and 1 second");
-		_from(3602, "1 hour and 2 seconds");
-		_from(3660, "1 hour and 1 minute");
-		_from(3661, "1 hour, 1 minute, and 1 second");
-		_from(3662, "1 hour, 1 minute, and 2 seconds");
-		_from(3720, "1 hour and 2 minutes");
-		_from(3721, "1 hour, 2 minutes, and 1 second");
-		_from(3723, "1 hour, 2 minutes, and 3 seconds");
-		_from(36001, "10 hours and 1 second");
-		_from(36015, "10 hours and 15 seconds");
-		_from(36060, "10 hours and 1 minute");
-		_from(37732, "10 hours, 28 minutes, and 52 seconds");
-		_from(37740, "10 hours and 29 minutes");
-
-		_from(86400, "1 day");
-		_from(86401, "1 day and 1 second");
-		_from(86461, "1 day, 1 minute, and 1 second");
-		_from(428_390, "4 days, 22 hours, 59 minutes, and 50 seconds");
-		_from(878_428_390, "10,166 days, 23 hours, 53 minutes, and 10 seconds");
-		_from(u32::MAX, "49,710 days, 6 hours, 28 minutes, and 15 seconds");
+		check_from(0, "0 seconds");
+		check_from(1, "1 second");
+		check_from(50, "50 seconds");
+
+		check_from(60, "1 minute");
+		check_from(61, "1 minute and 1 second");
+		check_from(100, "1 minute and 40 seconds");
+		check_from(2101, "35 minutes and 1 second");
+		check_from(2121, "35 minutes and 21 seconds");
+
+		check_from(3600, "1 hour");
+		check_from(3601, "1 hour and 1 second");
+		check_from(3602, "1 hour and 2 seconds");
+		check_from(3660, "1 hour and 1 minute");
+		check_from(3661, "1 hour, 1 minute, and 1 second");
+		check_from(3662, "1 hour, 1 minute, and 2 seconds");
+		check_from(3720, "1 hour and 2 minutes");
+		check_from(3721, "1 hour, 2 minutes, and 1 second");
+		check_from(3723, "1 hour, 2 minutes, and 3 seconds");
+		check_from(36001, "10 hours and 1 second");
+		check_from(36015, "10 hours and 15 seconds");
+		check_from(36060, "10 hours and 1 minute");
+		check_from(37732, "10 hours, 28 minutes, and 52 seconds");
+		check_from(37740, "10 hours and 29 minutes");
+
+		check_from(86400, "1 day");
+		check_from(86401, "1 day and 1 second");
+		check_from(86461, "1 day, 1 minute, and 1 second");
+		check_from(428_390, "4 days, 22 hours, 59 minutes, and 50 seconds");
+		check_from(878_428_390, "10,166 days, 23 hours, 53 minutes, and 10 seconds");
+		check_from(u32::MAX, "49,710 days, 6 hours, 28 minutes, and 15 seconds");
 	}
 
 	#[test]
+	#[expect(clippy::duration_suboptimal_units, reason = "Consistency is preferred.")]
 	fn t_from_duration() {
-		_from_d(Duration::from_millis(0), "0 seconds");
-		_from_d(Duration::from_millis(1), "0 seconds");
-		_from_d(Duration::from_millis(10), "0.01 seconds");
-		_from_d(Duration::from_millis(100), "0.10 seconds");
-		_from_d(Duration::from_millis(1000), "1 second");
-		_from_d(Duration::from_millis(50000), "50 seconds");
-		_from_d(Duration::from_millis(50020), "50.02 seconds");
-
-		_from_d(Duration::from_millis(60000), "1 minute");
-		_from_d(Duration::from_millis(60001), "1 minute");
-		_from_d(Duration::from_millis(60340), "1 minute and 0.34 seconds");
-		_from_d(Duration::from_millis(61000), "1 minute and 1 second");
-		_from_d(Duration::from_millis(61999), "1 minute and 1.99 seconds");
-		_from_d(Duration::from_millis(2_101_000), "35 minutes and 1 second");
-		_from_d(Duration::from_millis(2_101_050), "35 minutes and 1.05 seconds");
-		_from_d(Duration::from_millis(2_121_000), "35 minutes and 21 seconds");
-		_from_d(Duration::from_millis(2_121_820), "35 minutes and 21.82 seconds");
-		_from_d(Duration::from_nanos(2_121_999_999_999), "35 minutes and 21.99 seconds");
-
-		_from_d(Duration::from_millis(3_600_000), "1 hour");
-		_from_d(Duration::from_millis(3_600_300), "1 hour and 0.30 seconds");
-		_from_d(Duration::from_millis(3_660_000), "1 hour and 1 minute");
-		_from_d(Duration::from_millis(3_661_000), "1 hour, 1 minute, and 1 second");
-		_from_d(Duration::from_millis(3_661_100), "1 hour, 1 minute, and 1.10 seconds");
-		_from_d(Duration::from_millis(37_732_000), "10 hours, 28 minutes, and 52 seconds");
-		_from_d(Duration::from_millis(37_732_030), "10 hours, 28 minutes, and 52.03 seconds");
-		_from_d(Duration::from_millis(37_740_000), "10 hours and 29 minutes");
-		_from_d(Duration::from_millis(37_740_030), "10 hours, 29 minutes, and 0.03 seconds");
-
-		_from_d(Duration::from_millis(428_390_000), "4 days, 22 hours, 59 minutes, and 50 seconds");
-		_from_d(Duration::from_millis(428_390_999), "4 days, 22 hours, 59 minutes, and 50.99 seconds");
-		_from_d(Duration::from_millis(878_428_390_999), "10,166 days, 23 hours, 53 minutes, and 10.99 seconds");
+		check_from_d(Duration::from_millis(0), "0 seconds");
+		check_from_d(Duration::from_millis(1), "0 seconds");
+		check_from_d(Duration::from_millis(10), "0.01 seconds");
+		check_from_d(Duration::from_millis(100), "0.10 seconds");
+		check_from_d(Duration::from_millis(1000), "1 second");
+		check_from_d(Duration::from_millis(50000), "50 seconds");
+		check_from_d(Duration::from_millis(50020), "50.02 seconds");
+
+		check_from_d(Duration::from_millis(60000), "1 minute");
+		check_from_d(Duration::from_millis(60001), "1 minute");
+		check_from_d(Duration::from_millis(60340), "1 minute and 0.34 seconds");
+		check_from_d(Duration::from_millis(61000), "1 minute and 1 second");
+		check_from_d(Duration::from_millis(61999), "1 minute and 1.99 seconds");
+		check_from_d(Duration::from_millis(2_101_000), "35 minutes and 1 second");
+		check_from_d(Duration::from_millis(2_101_050), "35 minutes and 1.05 seconds");
+		check_from_d(Duration::from_millis(2_121_000), "35 minutes and 21 seconds");
+		check_from_d(Duration::from_millis(2_121_820), "35 minutes and 21.82 seconds");
+		check_from_d(Duration::from_nanos(2_121_999_999_999), "35 minutes and 21.99 seconds");
+
+		check_from_d(Duration::from_millis(3_600_000), "1 hour");
+		check_from_d(Duration::from_millis(3_600_300), "1 hour and 0.30 seconds");
+		check_from_d(Duration::from_millis(3_660_000), "1 hour and 1 minute");
+		check_from_d(Duration::from_millis(3_661_000), "1 hour, 1 minute, and 1 second");
+		check_from_d(Duration::from_millis(3_661_100), "1 hour, 1 minute, and 1.10 seconds");
+		check_from_d(Duration::from_millis(37_732_000), "10 hours, 28 minutes, and 52 seconds");
+		check_from_d(Duration::from_millis(37_732_030), "10 hours, 28 minutes, and 52.03 seconds");
+		check_from_d(Duration::from_millis(37_740_000), "10 hours and 29 minutes");
+		check_from_d(Duration::from_millis(37_740_030), "10 hours, 29 minutes, and 0.03 seconds");
+
+		check_from_d(Duration::from_millis(428_390_000), "4 days, 22 hours, 59 minutes, and 50 seconds");
+		check_from_d(Duration::from_millis(428_390_999), "4 days, 22 hours, 59 minutes, and 50.99 seconds");
+		check_from_d(Duration::from_millis(878_428_390_999), "10,166 days, 23 hours, 53 minutes, and 10.99 seconds");
+	}
+
+	#[test]
+	fn t_from_duration_precise() {
+		for (d, precision, expected) in [
+			(Duration::from_nanos(1), 9, "0.000000001 seconds"),
+			(Duration::from_nanos(1), 8, "0 seconds"),
+			(Duration::from_nanos(1_000_000_001), 9, "1.000000001 seconds"),
+			(Duration::from_nanos(61_234_567_890), 0, "1 minute and 1 second"),
+			(Duration::from_nanos(61_234_567_890), 1, "1 minute and 1.2 seconds"),
+			(Duration::from_nanos(61_234_567_890), 3, "1 minute and 1.234 seconds"),
+			(Duration::from_nanos(61_234_567_890), 6, "1 minute and 1.234567 seconds"),
+			(Duration::from_nanos(61_234_567_890), 9, "1 minute and 1.234567890 seconds"),
+			(Duration::from_nanos(61_234_567_890), 200, "1 minute and 1.234567890 seconds"),
+			(Duration::from_millis(60_001), 3, "1 minute and 0.001 seconds"),
+			(Duration::from_secs(3661), 9, "1 hour, 1 minute, and 1 second"),
+			(
+				Duration::new(u64::from(u32::MAX), 999_999_999),
+				9,
+				"49,710 days, 6 hours, 28 minutes, and 15.999999999 seconds",
+			),
+		] {
+			assert_eq!(
+				NiceElapsed::from_duration_precise(d, precision).as_str(),
+				expected,
+				"{d:?} ({precision})",
+			);
+		}
+
+		// The default precision should match From.
+		for i in 0..2000_u64 {
+			let d = Duration::from_millis(i * 37);
+			assert_eq!(
+				NiceElapsed::from_duration_precise(d, DEFAULT_PRECISION),
+				NiceElapsed::from(d),
+			);
+		}
+	}
+
+	#[test]
+	fn t_dhms_inverse() {
+		// Spot-check a spread of values for round-trip consistency.
+		let mut rng = fastrand::Rng::new();
+		for num in (0..100_000).chain(std::iter::repeat_with(|| rng.u32(..)).take(100_000)).chain([u32::MAX]) {
+			let (d, h, m, s) = NiceElapsed::dhms(num);
+			assert_eq!(NiceElapsed::seconds_from_dhms(d, h, m, s), num);
+			assert_eq!(NiceElapsed::total_days(num), d);
+			assert_eq!(NiceElapsed::total_hours(num), num / 3600);
+			assert_eq!(NiceElapsed::total_minutes(num), num / 60);
+
+			if num < 86_400 {
+				let [h, m, s] = NiceElapsed::hms(num);
+				assert_eq!(NiceElapsed::seconds_from_hms(h, m, s), num);
+			}
+		}
+
+		// The maximum values shouldn't overflow.
+		assert_eq!(NiceElapsed::seconds_from_hms(u8::MAX, u8::MAX, u8::MAX), 933_555);
+		assert_eq!(NiceElapsed::seconds_from_dhms(u16::MAX, u8::MAX, u8::MAX, u8::MAX), u32::MAX);
+	}
+
+	#[test]
+	fn t_with_labels() {
+		// English should match the normal output.
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u32(..)).take(10_000).chain([0, 1, 60, 61, 3600, 86_400, u32::MAX]) {
+			assert_eq!(
+				NiceElapsed::with_labels(num, &NiceElapsedLabels::ENGLISH),
+				Some(NiceElapsed::from(num)),
+			);
+		}
+
+		// Non-ASCII is fine.
+		let labels = NiceElapsedLabels {
+			day: ("jour", "jours"),
+			hour: ("heure", "heures"),
+			minute: ("minute", "minutes"),
+			second: ("seconde", "secondes"),
+			and: " et ",
+			comma: ", ",
+			comma_and: " et ",
+		};
+		assert_eq!(
+			NiceElapsed::with_labels(90_061, &labels).unwrap().as_str(),
+			"1 jour, 1 heure, 1 minute et 1 seconde",
+		);
+		let labels = NiceElapsedLabels {
+			day: ("日", "日"),
+			hour: ("時間", "時間"),
+			minute: ("分", "分"),
+			second: ("秒", "秒"),
+			and: "",
+			comma: "",
+			comma_and: "",
+		};
+		assert_eq!(NiceElapsed::with_labels(3661, &labels).unwrap().as_str(), "1 時間1 分1 秒");
+
+		// Too long.
+		let labels = NiceElapsedLabels {
+			second: ("second", "seconds and seconds and seconds and seconds and seconds and seconds"),
+			..NiceElapsedLabels::ENGLISH
+		};
+		assert!(NiceElapsed::with_labels(1, &labels).is_some());
+		assert!(NiceElapsed::with_labels(2, &labels).is_none());
+	}
+
+	#[test]
+	fn t_interchange() {
+		let mut rng = fastrand::Rng::new();
+		for s in std::iter::repeat_with(|| rng.u32(..) >> rng.u32(0..32)).take(10_000).chain([0, 86_399, 86_400, u32::MAX]) {
+			let elapsed = NiceElapsed::from(s);
+			assert_eq!(elapsed.to_clock(), NiceClock::from(s));
+			assert_eq!(elapsed.to_iso8601(), NiceIso8601::from(Duration::from_secs(u64::from(s))));
+		}
+
+		// Sub-second precision is whatever was displayed.
+		let d = Duration::new(5, 123_456_789);
+		assert_eq!(NiceElapsed::from(d).to_iso8601().as_str(), "PT5.120S");
+		assert_eq!(NiceElapsed::from_duration_precise(d, 0).to_iso8601().as_str(), "PT5S");
+		assert_eq!(NiceElapsed::from(d).to_clock().as_str(), "00:00:05");
+
+		// Labels don't matter.
+		let custom = NiceElapsed::with_labels(3723, &NiceElapsedLabels::default()).unwrap();
+		assert_eq!(custom.to_clock().as_str(), "01:02:03");
+	}
+
+	#[test]
+	fn t_ord() {
+		// Orderings should match the underlying numbers.
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let (a, b) = (rng.u32(..), rng.u32(..));
+			assert_eq!(NiceElapsed::from(a).cmp(&NiceElapsed::from(b)), a.cmp(&b));
+		}
+
+		// Sub-second precision should count too.
+		let a = NiceElapsed::from(Duration::from_millis(1500));
+		let b = NiceElapsed::from(Duration::from_millis(1510));
+		let c = NiceElapsed::from_duration_precise(Duration::from_millis(1500), 1);
+		assert!(NiceElapsed::from(1_u32) < a);
+		assert!(a < b);
+		assert!(b < NiceElapsed::from(2_u32));
+
+		// Values truncated to the same thing are equal.
+		assert_eq!(a, NiceElapsed::from(Duration::from_nanos(1_500_999_999)));
+		assert_eq!(a.cmp(&NiceElapsed::from(Duration::from_nanos(1_500_999_999))), Ordering::Equal);
+
+		// But different representations of the same magnitude are not.
+		assert_ne!(a, c);
+		assert_ne!(a.cmp(&c), Ordering::Equal);
+
+		// Zero and saturation.
+		assert_eq!(NiceElapsed::min().cmp(&NiceElapsed::from(0_u64)), Ordering::Equal);
+		assert_eq!(NiceElapsed::from(u64::MAX).cmp(&NiceElapsed::from(u32::MAX)), Ordering::Equal);
+	}
+
+	#[test]
+	fn t_signed() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.i64(..) >> rng.u32(0..64)).take(10_000) {
+			let nice = NiceElapsed::from(num);
+			let abs = NiceElapsed::from(num.unsigned_abs());
+			if num < 0 {
+				assert!(nice.is_negative());
+				assert_eq!(nice.as_str(), format!("-{}", abs.as_str()));
+			}
+			else {
+				assert!(! nice.is_negative());
+				assert_eq!(nice, abs);
+			}
+
+			// The magnitude is all that matters here.
+			assert_eq!(nice.to_clock(), abs.to_clock());
+			assert_eq!(nice.to_iso8601(), abs.to_iso8601());
+
+			// The i32 version should match.
+			if let Ok(num32) = i32::try_from(num) {
+				assert_eq!(NiceElapsed::from(num32), nice);
+			}
+		}
+
+		// Orderings should match the underlying numbers.
+		for _ in 0..10_000 {
+			let (a, b) = (rng.i32(..), rng.i32(..));
+			assert_eq!(NiceElapsed::from(a).cmp(&NiceElapsed::from(b)), a.cmp(&b), "{a} vs {b}");
+		}
+
+		// Edges.
+		assert_eq!(NiceElapsed::from(0_i32), NiceElapsed::min());
+		assert_eq!(NiceElapsed::from(-1_i32).as_str(), "-1 second");
+		assert_eq!(NiceElapsed::from(i64::MIN), NiceElapsed::from(-i64::from(u32::MAX)));
+		assert_eq!(
+			NiceElapsed::from(i32::MIN).as_str(),
+			"-24,855 days, 3 hours, 14 minutes, and 8 seconds",
+		);
 	}
 
-	fn _from(num: u32, expected: &str) {
+	fn check_from(num: u32, expected: &str) {
 		assert_eq!(
 			&*NiceElapsed::from(num),
 			expected.as_bytes(),
@@ -612,7 +1267,7 @@ mod tests {
 		);
 	}
 
-	fn _from_d(num: Duration, expected: &str) {
+	fn check_from_d(num: Duration, expected: &str) {
 		assert_eq!(
 			&*NiceElapsed::from(num),
 			expected.as_bytes(),
@@ -0,0 +1,208 @@
+/*!
+# Dactyl: "Nice" Timer
+*/
+
+use crate::{
+	NiceClock,
+	NiceElapsed,
+};
+use std::{
+	fmt,
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Nice Timer.
+///
+/// This is a simple stopwatch wrapping an [`Instant`], with methods to
+/// return the elapsed time as a [`NiceElapsed`] or [`NiceClock`], making
+/// quick profiling printouts a one-liner.
+///
+/// Its [`Display`](fmt::Display) implementation prints the total elapsed
+/// time, the same as [`NiceTimer::elapsed_str`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use dactyl::NiceTimer;
+///
+/// let mut timer = NiceTimer::start();
+///
+/// // Do some stuff…
+/// println!("Step one took {}.", timer.lap());
+///
+/// // Do some more stuff…
+/// println!("Step two took {}.", timer.lap());
+///
+/// // All done!
+/// println!("Finished in {timer}.");
+/// ```
+pub struct NiceTimer {
+	/// # Start Time.
+	start: Instant,
+
+	/// # Last Lap.
+	lap: Instant,
+}
+
+impl Default for NiceTimer {
+	#[inline]
+	fn default() -> Self { Self::start() }
+}
+
+impl fmt::Display for NiceTimer {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.elapsed_str(), f)
+	}
+}
+
+impl From<Instant> for NiceTimer {
+	#[inline]
+	fn from(src: Instant) -> Self { Self { start: src, lap: src } }
+}
+
+impl NiceTimer {
+	#[must_use]
+	#[inline]
+	/// # Start.
+	///
+	/// Create a new timer, starting now.
+	///
+	/// To start from some other moment, use [`NiceTimer::from`] instead.
+	pub fn start() -> Self { Self::from(Instant::now()) }
+
+	#[must_use]
+	#[inline]
+	/// # Elapsed.
+	///
+	/// Return the total time elapsed since the timer was started (or
+	/// [reset](NiceTimer::reset)).
+	pub fn elapsed(&self) -> Duration { self.elapsed_at(Instant::now()) }
+
+	#[must_use]
+	#[inline]
+	/// # Elapsed (Nice).
+	///
+	/// Return the total elapsed time as a [`NiceElapsed`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceTimer;
+	/// use std::time::Duration;
+	///
+	/// let timer = NiceTimer::start();
+	/// std::thread::sleep(Duration::from_millis(10));
+	/// assert!(timer.elapsed_str().as_str().ends_with("seconds"));
+	/// assert_ne!(timer.elapsed_str().as_str(), "0 seconds");
+	/// ```
+	pub fn elapsed_str(&self) -> NiceElapsed { NiceElapsed::from(self.elapsed()) }
+
+	#[must_use]
+	#[inline]
+	/// # Elapsed (Clock).
+	///
+	/// Return the total elapsed time as a [`NiceClock`]. Note that clocks
+	/// saturate at `23:59:59`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceTimer;
+	///
+	/// let timer = NiceTimer::start();
+	/// assert!(timer.clock().as_str().starts_with("00:00:"));
+	/// ```
+	pub fn clock(&self) -> NiceClock { NiceClock::from(self.elapsed()) }
+
+	/// # Lap.
+	///
+	/// Return the time elapsed since the previous lap — or the start, for
+	/// the first — as a [`NiceElapsed`], and begin a new lap.
+	///
+	/// This does not affect the timer's total elapsed time.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceTimer;
+	/// use std::time::Duration;
+	///
+	/// let mut timer = NiceTimer::start();
+	/// std::thread::sleep(Duration::from_millis(10));
+	///
+	/// // The first lap covers everything.
+	/// assert_ne!(timer.lap().as_str(), "0 seconds");
+	///
+	/// // The total is unaffected.
+	/// assert!(Duration::from_millis(10) <= timer.elapsed());
+	/// ```
+	pub fn lap(&mut self) -> NiceElapsed { self.lap_at(Instant::now()) }
+
+	#[inline]
+	/// # Reset.
+	///
+	/// Restart the timer (and lap) from now.
+	pub fn reset(&mut self) { *self = Self::start(); }
+}
+
+impl NiceTimer {
+	/// # Elapsed (At).
+	///
+	/// Return the total time elapsed as of `now`.
+	fn elapsed_at(&self, now: Instant) -> Duration {
+		now.saturating_duration_since(self.start)
+	}
+
+	/// # Lap (At).
+	///
+	/// Return the time elapsed between the previous lap and `now`, and begin
+	/// a new lap.
+	fn lap_at(&mut self, now: Instant) -> NiceElapsed {
+		let out = now.saturating_duration_since(self.lap);
+		self.lap = now;
+		NiceElapsed::from(out)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_timer() {
+		// Fake the passage of time by looking ahead instead of back; the
+		// host may not have been up long enough to subtract an hour.
+		let start = Instant::now();
+		let mut timer = NiceTimer::from(start);
+		let now = start + Duration::from_secs(3600);
+		assert_eq!(timer.elapsed_at(now), Duration::from_secs(3600));
+
+		// Laps shouldn't affect the total.
+		assert_eq!(timer.lap_at(now).as_str(), "1 hour");
+		assert_eq!(timer.lap_at(now + Duration::from_secs(90)).as_str(), "1 minute and 30 seconds");
+		assert_eq!(timer.elapsed_at(now + Duration::from_secs(90)), Duration::from_secs(3690));
+
+		// Nor should earlier moments.
+		assert_eq!(timer.lap_at(start).as_str(), "0 seconds");
+		assert_eq!(timer.elapsed_at(start), Duration::ZERO);
+
+		// Real-time checks.
+		assert!(timer.elapsed() < Duration::from_secs(3600));
+		assert!(timer.clock().as_str().starts_with("00:00:"));
+
+		// Resets should start over.
+		let mut timer = NiceTimer::from(start);
+		timer.reset();
+		assert!(start <= timer.start);
+		assert_eq!(timer.start, timer.lap);
+	}
+}
@@ -4,15 +4,32 @@
 See the main crate documentation for details.
 */
 
+pub(super) mod nice_bar;
+pub(super) mod nice_bigint;
+pub(super) mod nice_bits;
+pub(super) mod nice_diff;
 pub(super) mod nice_u8;
 pub(super) mod nice_u16;
 pub(super) mod nice_u32;
 pub(super) mod nice_u64;
+pub(super) mod nice_u64_sep;
+pub(super) mod nice_division;
+pub(super) mod nice_fixed;
 pub(super) mod nice_float;
+pub(super) mod nice_hex;
+pub(super) mod nice_money;
+pub(super) mod nice_padded;
 pub(super) mod nice_percent;
+pub(super) mod nice_percent_change;
+pub(super) mod nice_percent_ratio;
+pub(super) mod nice_pow2;
+pub(super) mod nice_short;
+pub(super) mod nice_signed_percent;
+pub(super) mod range;
 
 
 
+use crate::ParseByteError;
 use std::{
 	cmp::Ordering,
 	fmt,
@@ -51,14 +68,6 @@ impl<const S: usize> ::std::borrow::Borrow<str> for NiceWrapper<S> {
 	fn borrow(&self) -> &str { self.as_str() }
 }
 
-impl<const S: usize> fmt::Debug for NiceWrapper<S> {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.debug_tuple(&format!("NiceWrapper<{S}>"))
-			.field(&self.as_str())
-			.finish()
-	}
-}
-
 impl<const S: usize> Deref for NiceWrapper<S> {
 	type Target = [u8];
 	#[inline]
@@ -138,6 +147,56 @@ impl<const S: usize> NiceWrapper<S> {
 		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
 	}
 
+	#[inline]
+	/// # Bytes.
+	///
+	/// Return an iterator over the formatted bytes, straight from the buffer.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU64;
+	///
+	/// let nice = NiceU64::from(1234_u64);
+	/// let mut iter = nice.bytes();
+	/// assert_eq!(iter.len(), 5);
+	/// assert_eq!(iter.next(), Some(b'1'));
+	/// assert_eq!(iter.next_back(), Some(b'4'));
+	/// assert_eq!(iter.len(), 3);
+	/// ```
+	pub fn bytes(&self) -> std::iter::Copied<std::slice::Iter<'_, u8>> {
+		self.as_bytes().iter().copied()
+	}
+
+	#[inline]
+	/// # Chars.
+	///
+	/// Return an iterator over the formatted characters, straight from the
+	/// buffer.
+	///
+	/// Unlike [`str::chars`], this is an [`ExactSizeIterator`], because the
+	/// contents are always ASCII.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU64;
+	///
+	/// let nice = NiceU64::from(1234_u64);
+	/// assert_eq!(nice.chars().len(), 5);
+	/// assert_eq!(nice.chars().rev().collect::<String>(), "432,1");
+	///
+	/// // Pad it however you like.
+	/// let padded: String = std::iter::repeat('.')
+	///     .take(8 - nice.len())
+	///     .chain(nice.chars())
+	///     .collect();
+	/// assert_eq!(padded, "...1,234");
+	/// ```
+	pub fn chars(&self) -> impl DoubleEndedIterator<Item=char> + ExactSizeIterator + '_ {
+		self.bytes().map(char::from)
+	}
+
 	#[must_use]
 	/// # Is Empty?
 	pub const fn is_empty(&self) -> bool { S <= self.from }
@@ -145,10 +204,342 @@ impl<const S: usize> NiceWrapper<S> {
 	#[must_use]
 	/// # Length.
 	pub const fn len(&self) -> usize { S.wrapping_sub(self.from) }
+
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return a copy of the full backing buffer — with the value right-aligned
+	/// and any unused leading bytes replaced with spaces — along with the
+	/// index the value starts at.
+	///
+	/// This is useful for embedding values into larger fixed-layout buffers
+	/// without any slice bookkeeping.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU16;
+	///
+	/// let (arr, from) = NiceU16::from(1234_u16).to_array();
+	/// assert_eq!(arr, *b" 1,234");
+	/// assert_eq!(from, 1);
+	/// assert_eq!(&arr[from..], NiceU16::from(1234_u16).as_bytes());
+	/// ```
+	pub const fn to_array(self) -> ([u8; S], usize) {
+		let mut out = self.inner;
+		let mut idx = 0;
+		while idx < self.from && idx < S {
+			out[idx] = b' ';
+			idx += 1;
+		}
+		(out, self.from)
+	}
+
+	/// # Zero Stale Bytes.
+	///
+	/// Replacing a value with a shorter one leaves the old leading digits
+	/// (and sign, if any) behind in the unused part of the buffer. This
+	/// resets everything between the old and new starting indices other
+	/// than separators back to zero, keeping the full buffer tidy.
+	///
+	/// This is only needed when the new value is shorter than the old one,
+	/// so callers on hot paths should check that first.
+	#[cold]
+	pub(crate) fn zero_stale(&mut self, old: usize) {
+		let step = if self.is_myriad() { 5 } else { 4 };
+		for idx in old..self.from {
+			if (S - idx) % step != 0 { self.inner[idx] = b'0'; }
+		}
+	}
+
+	/// # Digits.
+	///
+	/// Recompute the (absolute) numeric value from the formatted digits,
+	/// ignoring any signs or (non-digit) separators.
+	///
+	/// Returns zero if empty.
+	pub(crate) const fn digits(&self) -> u64 {
+		let mut num = 0_u64;
+		let mut idx = self.from;
+		while idx < S {
+			let b = self.inner[idx];
+			if b.is_ascii_digit() { num = num * 10 + (b - b'0') as u64; }
+			idx += 1;
+		}
+		num
+	}
+
+	/// # Numeric Value.
+	///
+	/// Same as [`NiceWrapper::digits`], but signed, and `None` if empty. This
+	/// is used for comparisons against primitives.
+	pub(crate) const fn value(&self) -> Option<i128> {
+		if S <= self.from { None }
+		else if self.inner[self.from] == b'-' { Some(-(self.digits() as i128)) }
+		else { Some(self.digits() as i128) }
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "Percents are small.")]
+	/// # Percent Value.
+	///
+	/// Same as [`NiceWrapper::value`], but scaled down from basis points to
+	/// a fraction, for the percent types.
+	pub(crate) fn percent_value(&self) -> Option<f64> {
+		self.value().map(|v| v as f64 / 10_000.0)
+	}
+
+	#[must_use]
+	/// # Sanitize Separator.
+	///
+	/// Digit separators would be indistinguishable from the number itself,
+	/// so are swapped out for the default comma.
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the separator is invalid ASCII.
+	pub(crate) const fn sanitize_separator(sep: u8) -> u8 {
+		assert!(sep.is_ascii(), "Invalid separator.");
+		if sep.is_ascii_digit() { b',' }
+		else { sep }
+	}
+}
+
+
+/// ## Parsing.
+impl<const S: usize> NiceWrapper<S> {
+	/// # Parse Canonical.
+	///
+	/// Total up the digits in `src` — skipping any of the `extra` non-digit
+	/// bytes — and pass the result to `build`, which should return `None` if
+	/// the value is out of range.
+	///
+	/// Because there is only one correct way to format any given value, the
+	/// result is then compared against the original; if the separators,
+	/// padding, precision, etc., are off, the index of the first discrepancy
+	/// is returned as an [`InvalidDigit`](ParseByteError::InvalidDigit).
+	///
+	/// This powers the `FromStr` implementations.
+	pub(crate) fn parse_canonical<F>(src: &[u8], extra: &[u8], build: F)
+	-> Result<Self, ParseByteError>
+	where F: FnOnce(u64) -> Option<Self> {
+		if src.is_empty() { return Err(ParseByteError::Empty); }
+
+		let mut num = 0_u64;
+		for (idx, &b) in src.iter().enumerate() {
+			if b.is_ascii_digit() {
+				num = num.checked_mul(10)
+					.and_then(|n| n.checked_add(u64::from(b - b'0')))
+					.ok_or(ParseByteError::Overflow)?;
+			}
+			else if ! extra.contains(&b) { return Err(ParseByteError::InvalidDigit(idx)); }
+		}
+
+		let out = build(num).ok_or(ParseByteError::Overflow)?;
+		let nice = out.as_bytes();
+		if nice == src { Ok(out) }
+		else {
+			let idx = nice.iter()
+				.zip(src)
+				.position(|(a, b)| a != b)
+				.unwrap_or_else(|| nice.len().min(src.len() - 1));
+			Err(ParseByteError::InvalidDigit(idx))
+		}
+	}
+}
+
+
+/// ## Myriad Grouping.
+///
+/// These helpers support the alternative
+/// [`Grouping::Myriad`](crate::Grouping::Myriad) layout, which places a
+/// separator every four digits instead of three.
+///
+/// As with the default layout, the separators are written to the buffer
+/// ahead of time, leaving only the digits to be filled in. Because the two
+/// layouts have separators in different positions, the byte at `S - 5` —
+/// always a digit for thousands, always a separator for myriads — can be used
+/// to tell them apart after the fact, allowing `replace` to preserve the
+/// grouping.
+impl<const S: usize> NiceWrapper<S> {
+	/// # New Myriad.
+	///
+	/// Return a new myriad-grouped instance using the given separator.
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the separator is invalid ASCII or a digit.
+	pub(crate) fn myriad(num: u64, sep: u8) -> Self {
+		assert!(sep.is_ascii() && ! sep.is_ascii_digit(), "Invalid separator.");
+		let mut inner = [b'0'; S];
+		let mut idx = S;
+		while 5 <= idx {
+			idx -= 5;
+			inner[idx] = sep;
+		}
+
+		let mut out = Self { inner, from: S };
+		out.parse_myriad(num);
+		out
+	}
+
+	#[must_use]
+	/// # Is Myriad?
+	pub(crate) const fn is_myriad(&self) -> bool {
+		5 <= S && ! self.inner[S - 5].is_ascii_digit()
+	}
+
+	#[cold]
+	#[inline(never)]
+	/// # Replace Myriad.
+	///
+	/// This is the `replace` slow path for myriad-grouped buffers, kept out
+	/// of line so the more common thousands path stays small.
+	pub(crate) fn replace_myriad(&mut self, num: u64) {
+		let old = self.from;
+		self.parse_myriad(num);
+		self.zero_stale(old);
+	}
+
+	/// # Parse Myriad.
+	///
+	/// Write the digits of `num` into the (myriad-templated) buffer.
+	pub(crate) fn parse_myriad(&mut self, mut num: u64) {
+		self.from = S;
+		for chunk in self.inner.rchunks_exact_mut(5) {
+			if 9999 < num {
+				let rem = (num % 10_000) as usize;
+				num /= 10_000;
+				chunk[1..3].copy_from_slice(crate::double(rem / 100).as_slice());
+				chunk[3..].copy_from_slice(crate::double(rem % 100).as_slice());
+				self.from -= 5;
+			}
+			else { break; }
+		}
+
+		// The remaining one to four digits.
+		loop {
+			self.from -= 1;
+			self.inner[self.from] = (num % 10) as u8 + b'0';
+			num /= 10;
+			if num == 0 { break; }
+		}
+	}
 }
 
 
 
+#[doc(hidden)]
+/// # Helper: Grouping.
+macro_rules! nice_grouping {
+	($nice:ident, $uint:ty, $num:literal, $myriad:literal, $eu:literal) => (
+		impl $nice {
+			#[must_use]
+			/// # New Instance w/ Custom Grouping.
+			///
+			/// Create a new instance with an alternative digit [`Grouping`],
+			/// e.g. [`Grouping::Myriad`] for Chinese/Japanese-style output,
+			/// and an alternative [`NiceSeparator`](crate::NiceSeparator).
+			///
+			/// The grouping and separator are both preserved by subsequent
+			#[doc = concat!("calls to [`", stringify!($nice), "::replace`].")]
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::{Grouping, NiceSeparator, ", stringify!($nice), "};")]
+			///
+			#[doc = concat!("let mut num = ", stringify!($nice), "::with_grouping(", $num, ", Grouping::Myriad, NiceSeparator::Comma);")]
+			#[doc = concat!("assert_eq!(num.as_str(), \"", $myriad, "\");")]
+			///
+			/// num.replace(1234);
+			/// assert_eq!(num.as_str(), "1234");
+			///
+			/// num.replace(12345);
+			/// assert_eq!(num.as_str(), "1,2345");
+			/// ```
+			pub fn with_grouping<S: Into<crate::NiceSeparator>>(num: $uint, grouping: Grouping, sep: S) -> Self {
+				let sep = sep.into();
+				match grouping {
+					Grouping::Thousands => Self::with_nice_separator(num, sep),
+					Grouping::Myriad => Self::myriad(u64::from(num), sep.as_byte()),
+				}
+			}
+
+			#[must_use]
+			/// # New Instance w/ Style.
+			///
+			/// Create a new instance using the separator and [`Grouping`]
+			/// from a [`NiceStyle`](crate::NiceStyle), saving the usual
+			/// argument ceremony when the same style is used everywhere.
+			///
+			/// Options that don't apply to integers, like the decimal point,
+			/// are ignored.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::{Grouping, NiceStyle, ", stringify!($nice), "};")]
+			///
+			/// let style = NiceStyle::new().with_grouping(Grouping::Myriad);
+			#[doc = concat!("let num = ", stringify!($nice), "::with_style(", $num, ", style);")]
+			#[doc = concat!("assert_eq!(num.as_str(), \"", $myriad, "\");")]
+			/// ```
+			pub fn with_style(num: $uint, style: crate::NiceStyle) -> Self {
+				Self::with_grouping(num, style.grouping(), style.separator())
+			}
+
+			#[must_use]
+			#[inline]
+			/// # New Instance (European).
+			///
+			/// Create a new instance using period thousands separators, i.e.
+			/// [`NiceStyle::eu`](crate::NiceStyle::eu).
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let num = ", stringify!($nice), "::eu(", $num, ");")]
+			#[doc = concat!("assert_eq!(num.as_str(), \"", $eu, "\");")]
+			/// ```
+			pub fn eu(num: $uint) -> Self {
+				Self::with_nice_separator(num, crate::NiceSeparator::Period)
+			}
+		}
+	);
+}
+
+#[doc(hidden)]
+/// # Helper: Debug.
+///
+/// This is implemented per alias — rather than for all `NiceWrapper`s — so
+/// the alternate form can report the right type name, and only present a
+/// numeric value where one can be recovered exactly.
+macro_rules! nice_debug {
+	($nice:ident $(, $value:ident)?) => (
+		impl ::std::fmt::Debug for $nice {
+			/// # Debug.
+			///
+			/// The alternate form — `{:#?}` — includes the type name and,
+			/// where it can be recovered exactly, the numeric value alongside
+			/// the string.
+			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				if f.alternate() {
+					let mut out = f.debug_struct(stringify!($nice));
+					$( if let Some(v) = self.$value() { out.field("value", &v); } )?
+					out.field("nice", &self.as_str()).finish()
+				}
+				else {
+					f.debug_tuple(&format!("NiceWrapper<{}>", self.inner.len()))
+						.field(&self.as_str())
+						.finish()
+				}
+			}
+		}
+	);
+}
+
 #[doc(hidden)]
 /// # Helper: From<nonzero>
 macro_rules! nice_from_nz {
@@ -160,6 +551,90 @@ macro_rules! nice_from_nz {
 	)+);
 }
 
+#[doc(hidden)]
+/// # Helper: Replace From Option.
+macro_rules! nice_replace_from {
+	($nice:ident, $uint:ty, $num:literal, $expected:literal) => (
+		impl $nice {
+			#[inline]
+			/// # Replace (Option).
+			///
+			/// Same as `replace`, except `None` is treated as zero, mirroring the
+			/// `From<Option>` implementation.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let mut num = ", stringify!($nice), "::default();")]
+			#[doc = concat!("assert_eq!(num.replace_from(Some(", $num, ")).as_str(), \"", $expected, "\");")]
+			/// assert_eq!(num.replace_from(None).as_str(), "0");
+			/// ```
+			pub fn replace_from(&mut self, num: Option<$uint>) -> &mut Self {
+				self.replace(num.unwrap_or(0))
+			}
+		}
+	);
+}
+
+#[doc(hidden)]
+/// # Helper: Comparisons With Primitives.
+macro_rules! nice_cmp {
+	($nice:ty, $($uint:ty),+ $(,)?) => ($(
+		impl PartialEq<$uint> for $nice {
+			#[inline]
+			fn eq(&self, other: &$uint) -> bool {
+				self.value() == Some(i128::from(*other))
+			}
+		}
+
+		impl PartialEq<$nice> for $uint {
+			#[inline]
+			fn eq(&self, other: &$nice) -> bool { other == self }
+		}
+
+		impl PartialOrd<$uint> for $nice {
+			#[inline]
+			fn partial_cmp(&self, other: &$uint) -> Option<::std::cmp::Ordering> {
+				self.value().map(|v| v.cmp(&i128::from(*other)))
+			}
+		}
+
+		impl PartialOrd<$nice> for $uint {
+			#[inline]
+			fn partial_cmp(&self, other: &$nice) -> Option<::std::cmp::Ordering> {
+				other.partial_cmp(self).map(::std::cmp::Ordering::reverse)
+			}
+		}
+	)+);
+}
+
+#[doc(hidden)]
+/// # Helper: Get.
+macro_rules! nice_get {
+	($nice:ty, $uint:ty, $example:literal) => (
+		impl $nice {
+			#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+			#[must_use]
+			#[doc = concat!("# Get (`", stringify!($uint), "`).")]
+			///
+			/// Return the numeric value, recomputed from the formatted buffer
+			/// (so the number and string forms can travel together).
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let nice = ", stringify!($nice), "::from(", $example, ");")]
+			#[doc = concat!("assert_eq!(nice.get(), ", $example, ");")]
+			/// ```
+			pub const fn get(&self) -> $uint { self.digits() as $uint }
+		}
+	);
+}
+
 #[doc(hidden)]
 /// # Helper: Default and Min.
 macro_rules! nice_default {
@@ -181,6 +656,198 @@ macro_rules! nice_default {
 	);
 }
 
+#[doc(hidden)]
+/// # Helper: Batch Formatting.
+macro_rules! nice_extend {
+	($nice:ident, $uint:ty, $example:literal) => (
+		impl $nice {
+			/// # Extend Vec.
+			///
+			/// Format each of the `values`, writing them — separated by
+			/// `delim` — to the end of `out`.
+			///
+			/// This is a faster alternative to looping through the values and
+			/// building (and copying) a new instance for each, as a single
+			/// buffer is reused throughout.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::{NiceSeparator, ", stringify!($nice), "};")]
+			///
+			/// let mut out = Vec::new();
+			#[doc = concat!(stringify!($nice), "::extend_vec(&[1, 1000, ", $example, "], &mut out, NiceSeparator::Underscore, b'\\n');")]
+			#[doc = concat!("assert_eq!(out, b\"1\\n1_000\\n", $example, "\");")]
+			/// ```
+			pub fn extend_vec<S: Into<crate::NiceSeparator>>(
+				values: &[$uint],
+				out: &mut Vec<u8>,
+				sep: S,
+				delim: u8,
+			) {
+				let mut nice = Self::with_nice_separator(0, sep);
+				let mut iter = values.iter();
+				if let Some(&first) = iter.next() {
+					nice.replace(first);
+					out.extend_from_slice(nice.as_bytes());
+					for &num in iter {
+						nice.replace(num);
+						out.push(delim);
+						out.extend_from_slice(nice.as_bytes());
+					}
+				}
+			}
+		}
+	);
+}
+
+#[doc(hidden)]
+/// # Helper: Padded Digits.
+macro_rules! nice_pad {
+	($nice:ident, $uint:ty, $digits:literal, $example:literal, $expected:literal) => (
+		impl $nice {
+			#[must_use]
+			/// # Padded.
+			///
+			/// Return a copy of the value with the thousands separators
+			/// removed, and the digits left-padded with zeroes to (at least)
+			#[doc = concat!("`width` places, up to a maximum of ", $digits, ".")]
+			///
+			/// This is useful for building column-aligned numeric output.
+			///
+			/// Like the standard library's `{:0width$}` formatting, values
+			/// with more digits than `width` are left as-is.
+			///
+			/// Note: the result is a display-only
+			/// [`NicePadded`](crate::NicePadded), not another instance of this
+			/// type.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let nice = ", stringify!($nice), "::from(", $example, "_", stringify!($uint), ");")]
+			#[doc = concat!("assert_eq!(nice.padded(", $digits, ").as_str(), \"", $expected, "\");")]
+			#[doc = concat!("assert_eq!(nice.padded(0).as_str(), \"", $example, "\");")]
+			/// ```
+			pub fn padded(&self, width: usize) -> crate::NicePadded<$digits> {
+				crate::NicePadded::new(self.as_bytes(), width)
+			}
+		}
+	);
+}
+
+#[doc(hidden)]
+/// # Helper: Full Buffer.
+macro_rules! nice_full {
+	($nice:ident, $example:literal, $expected:literal) => (
+		impl $nice {
+			#[must_use]
+			#[inline]
+			/// # Full Bytes.
+			///
+			/// Return the entire backing buffer, with the value left-padded
+			/// with zeroes — and separators — to the type's maximum width.
+			///
+			/// This is useful for column-stable, machine-generated reports.
+			///
+			/// Note that signed [deltas](crate::NiceU64::delta) keep their
+			/// sign directly in front of the leading digit.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let nice = ", stringify!($nice), "::from(", $example, ");")]
+			#[doc = concat!("assert_eq!(nice.as_bytes_full(), b\"", $expected, "\");")]
+			/// ```
+			pub const fn as_bytes_full(&self) -> &[u8] { &self.inner }
+
+			#[expect(unsafe_code, reason = "For performance.")]
+			#[must_use]
+			#[inline]
+			/// # Full String.
+			///
+			/// Return the entire backing buffer as a string slice, with the
+			/// value left-padded with zeroes — and separators — to the
+			#[doc = concat!("type's maximum width. See [`", stringify!($nice), "::as_bytes_full`] for more details.")]
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let nice = ", stringify!($nice), "::from(", $example, ");")]
+			#[doc = concat!("assert_eq!(nice.as_str_full(), \"", $expected, "\");")]
+			/// ```
+			pub const fn as_str_full(&self) -> &str {
+				// Safety: numbers are valid ASCII.
+				unsafe { std::str::from_utf8_unchecked(self.as_bytes_full()) }
+			}
+		}
+	);
+}
+
+#[doc(hidden)]
+/// # Helper: Separator-Specific Extremes.
+macro_rules! nice_extremes_with {
+	($nice:ident, $max:literal, $expected:literal) => (
+		impl $nice {
+			#[must_use]
+			/// # Minimum Value (Custom Separator).
+			///
+			#[doc = concat!("This is the same as [`", stringify!($nice), "::MIN`], but using `sep` as the")]
+			/// thousands separator, for use in constants and statics.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::{", stringify!($nice), ", NiceSeparator};")]
+			///
+			#[doc = concat!("const MIN: ", stringify!($nice), " = ", stringify!($nice), "::min_with(NiceSeparator::Underscore);")]
+			#[doc = concat!("assert_eq!(MIN, ", stringify!($nice), "::with_nice_separator(0, NiceSeparator::Underscore));")]
+			/// ```
+			pub const fn min_with(sep: crate::NiceSeparator) -> Self {
+				Self::MIN.swap_separator(sep.as_byte())
+			}
+
+			#[must_use]
+			/// # Maximum Value (Custom Separator).
+			///
+			#[doc = concat!("This is the same as [`", stringify!($nice), "::MAX`], but using `sep` as the")]
+			/// thousands separator, for use in constants and statics.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::{", stringify!($nice), ", NiceSeparator};")]
+			///
+			#[doc = concat!("const MAX: ", stringify!($nice), " = ", stringify!($nice), "::max_with(NiceSeparator::Underscore);")]
+			#[doc = concat!("assert_eq!(MAX.as_str(), \"", $expected, "\");")]
+			#[doc = concat!("assert_eq!(MAX, ", stringify!($nice), "::with_nice_separator(", $max, ", NiceSeparator::Underscore));")]
+			/// ```
+			pub const fn max_with(sep: crate::NiceSeparator) -> Self {
+				Self::MAX.swap_separator(sep.as_byte())
+			}
+
+			#[must_use]
+			/// # Swap Separator.
+			///
+			/// Replace the (default) commas in the buffer with `sep`.
+			const fn swap_separator(mut self, sep: u8) -> Self {
+				let mut idx = 0;
+				while idx < self.inner.len() {
+					if self.inner[idx] == b',' { self.inner[idx] = sep; }
+					idx += 1;
+				}
+				self
+			}
+		}
+	);
+}
+
 #[doc(hidden)]
 /// # Helper: Generic From/Parsing (u32 and larger).
 macro_rules! nice_parse {
@@ -229,8 +896,58 @@ macro_rules! nice_parse {
 	);
 }
 
+#[doc(hidden)]
+/// # Helper: `FromStr`.
+macro_rules! nice_from_str {
+	($nice:ty, $uint:ty, $example:literal) => (
+		impl ::std::str::FromStr for $nice {
+			type Err = crate::ParseByteError;
+
+			#[inline]
+			/// # From Str.
+			///
+			/// Parse a canonically-formatted value — comma separators in all
+			/// the right places, no leading zeroes — back into an instance.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let nice: ", stringify!($nice), " = \"", $example, "\".parse().unwrap();")]
+			#[doc = concat!("assert_eq!(nice.as_str(), \"", $example, "\");")]
+			///
+			/// // Anything else is an error.
+			#[doc = concat!("assert!(\"\".parse::<", stringify!($nice), ">().is_err());")]
+			#[doc = concat!("assert!(\"01\".parse::<", stringify!($nice), ">().is_err());")]
+			#[doc = concat!("assert!(\"1.5\".parse::<", stringify!($nice), ">().is_err());")]
+			/// ```
+			///
+			/// ## Errors
+			///
+			/// Returns an error if the string is empty, malformed, or its
+			/// value is too big for the type.
+			fn from_str(src: &str) -> Result<Self, Self::Err> {
+				Self::parse_canonical(src.as_bytes(), b",", |num|
+					<$uint>::try_from(num).ok().map(Self::from)
+				)
+			}
+		}
+	);
+}
+
 use {
+	nice_cmp,
+	nice_debug,
 	nice_default,
+	nice_extend,
+	nice_extremes_with,
 	nice_from_nz,
+	nice_from_str,
+	nice_full,
+	nice_get,
+	nice_grouping,
+	nice_pad,
 	nice_parse,
+	nice_replace_from,
 };
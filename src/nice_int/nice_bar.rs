@@ -0,0 +1,291 @@
+/*!
+# Dactyl: Nice Progress Bar.
+*/
+
+use crate::NicePercent;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Default Bar Width.
+const DEFAULT_WIDTH: u8 = 20;
+
+/// # Maximum Bar Width.
+const MAX_WIDTH: u8 = 64;
+
+/// # Total Buffer Size.
+///
+/// `[` + 64 + `] ` + `100.00%` = 74 bytes.
+const SIZE: usize = MAX_WIDTH as usize + 10;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Progress Bar.
+///
+/// This struct renders a simple, fixed-width ASCII progress bar — with a
+/// [`NicePercent`] tacked onto the end — from a `(done, total)` pair, like
+/// `[=====>    ] 55.01%`.
+///
+/// The bar width (excluding the brackets) defaults to twenty, but can be
+/// anywhere from `1..=64` if constructed with [`NiceBar::with_width`].
+///
+/// Values of `done` greater than `total` are clamped to `total`. A `total` of
+/// zero is treated as zero percent.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceBar;
+///
+/// assert_eq!(
+///     NiceBar::with_width(5501, 10_000, 10).as_str(),
+///     "[=====>    ] 55.01%",
+/// );
+///
+/// // Update the value in place.
+/// let mut bar = NiceBar::from((0_u64, 10_u64));
+/// assert_eq!(bar.as_str(), "[>                   ] 0.00%");
+///
+/// bar.replace(10, 10);
+/// assert_eq!(bar.as_str(), "[====================] 100.00%");
+/// ```
+pub struct NiceBar {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Actual Length.
+	len: usize,
+
+	/// # Bar Width.
+	width: u8,
+}
+
+impl AsRef<[u8]> for NiceBar {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceBar {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceBar {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceBar {
+	#[inline]
+	fn default() -> Self { Self::with_width(0, 0, DEFAULT_WIDTH) }
+}
+
+impl Deref for NiceBar {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceBar {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceBar")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceBar {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceBar {}
+
+impl From<(u64, u64)> for NiceBar {
+	#[inline]
+	fn from(src: (u64, u64)) -> Self { Self::with_width(src.0, src.1, DEFAULT_WIDTH) }
+}
+
+impl hash::Hash for NiceBar {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceBar {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceBar {
+	#[must_use]
+	/// # New Instance w/ Custom Width.
+	///
+	/// Create a new progress bar `width` characters wide (excluding the
+	/// brackets). The width is clamped to `1..=64`.
+	///
+	/// If you're good with the default width of twenty, just use
+	/// [`NiceBar::from`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBar;
+	///
+	/// assert_eq!(NiceBar::with_width(1, 4, 4).as_str(), "[=>  ] 25.00%");
+	/// assert_eq!(NiceBar::with_width(1, 4, 0).as_str(), "[>] 25.00%");
+	/// ```
+	pub fn with_width(done: u64, total: u64, width: u8) -> Self {
+		let mut out = Self {
+			inner: [b' '; SIZE],
+			len: 0,
+			width: width.clamp(1, MAX_WIDTH),
+		};
+		out.replace(done, total);
+		out
+	}
+
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new value. The
+	/// width is preserved.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceBar;
+	///
+	/// let mut bar = NiceBar::with_width(1, 2, 4);
+	/// assert_eq!(bar.as_str(), "[==> ] 50.00%");
+	///
+	/// bar.replace(3, 4);
+	/// assert_eq!(bar.as_str(), "[===>] 75.00%");
+	/// ```
+	pub fn replace(&mut self, done: u64, total: u64) {
+		let done = done.min(total);
+		let width = usize::from(self.width);
+
+		// The filled portion of the bar, rounded down. (This is done in u128
+		// space to avoid any chance of overflow.)
+		let filled =
+			if total == 0 { 0 }
+			else {
+				(u128::from(done) * u128::from(self.width) / u128::from(total)) as usize
+			};
+
+		self.inner[0] = b'[';
+		self.inner[1..=filled].fill(b'=');
+		if filled < width {
+			self.inner[filled + 1] = b'>';
+			self.inner[filled + 2..=width].fill(b' ');
+		}
+		self.inner[width + 1] = b']';
+		self.inner[width + 2] = b' ';
+
+		// And the percent.
+		let pct = NicePercent::try_from((done, total)).unwrap_or(NicePercent::MIN);
+		let pct = pct.as_bytes();
+		self.len = width + 3 + pct.len();
+		self.inner[width + 3..self.len].copy_from_slice(pct);
+	}
+}
+
+impl NiceBar {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceBar;
+	///
+	/// assert_eq!(
+	///     NiceBar::with_width(1, 2, 2).as_bytes(),
+	///     b"[=>] 50.00%",
+	/// );
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[..self.len] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceBar;
+	///
+	/// assert_eq!(
+	///     NiceBar::with_width(1, 2, 2).as_str(),
+	///     "[=>] 50.00%",
+	/// );
+	/// ```
+	pub fn as_str(&self) -> &str {
+		debug_assert!(std::str::from_utf8(self.as_bytes()).is_ok(), "Bug: NiceBar is not UTF.");
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	/// # Width.
+	///
+	/// Return the width of the bar itself, excluding the brackets and
+	/// percentage.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceBar;
+	///
+	/// assert_eq!(NiceBar::default().width(), 20);
+	/// assert_eq!(NiceBar::with_width(1, 2, 100).width(), 64);
+	/// ```
+	pub const fn width(&self) -> u8 { self.width }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nice_bar() {
+		for (done, total, width, expected) in [
+			(0, 0, 10, "[>         ] 0.00%"),
+			(5, 0, 10, "[>         ] 0.00%"),
+			(0, 10, 10, "[>         ] 0.00%"),
+			(1, 10, 10, "[=>        ] 10.00%"),
+			(5501, 10_000, 10, "[=====>    ] 55.01%"),
+			(9, 10, 10, "[=========>] 90.00%"),
+			(10, 10, 10, "[==========] 100.00%"),
+			(11, 10, 10, "[==========] 100.00%"),
+			(u64::MAX - 1, u64::MAX, 1, "[>] 100.00%"),
+			(u64::MAX, u64::MAX, 1, "[=] 100.00%"),
+		] {
+			let bar = NiceBar::with_width(done, total, width);
+			assert_eq!(bar.as_str(), expected);
+			assert_eq!(bar.to_string(), expected);
+		}
+
+		// Replacement should match fresh instances, big or small.
+		let mut last = NiceBar::with_width(u64::MAX, u64::MAX, MAX_WIDTH);
+		assert_eq!(last.len, SIZE);
+		for done in 0..=1000_u16 {
+			last.replace(u64::from(done), 1000);
+			assert_eq!(last, NiceBar::with_width(u64::from(done), 1000, MAX_WIDTH));
+			assert_eq!(last.as_str().matches('=').count(), usize::from(done) * 64 / 1000);
+		}
+		last.replace(0, 0);
+		assert_eq!(last, NiceBar::with_width(0, 0, MAX_WIDTH));
+	}
+}
@@ -0,0 +1,393 @@
+/*!
+# Dactyl: Nice Bigint.
+*/
+
+use crate::{
+	NiceSeparator,
+	ParseByteError,
+};
+use std::{
+	cmp::Ordering,
+	fmt,
+	ops::Deref,
+};
+
+
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+/// # Nice Bigint.
+///
+/// This struct formats unsigned decimal strings of _any_ length — like the
+/// oversized IDs and balances returned by some APIs — with separators every
+/// thousand, the same as [`NiceU64`](crate::NiceU64).
+///
+/// The digits are validated and copied as-are; they are never converted to
+/// an integer, so there's no upper limit. Leading zeroes are trimmed.
+///
+/// Because the length is unbounded, this is the one `Nice*` type that
+/// allocates, and does not implement `Copy` or [`NiceMaxLen`](crate::traits::NiceMaxLen).
+///
+/// Ordering is numeric.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceBigint;
+///
+/// let nice = NiceBigint::from_digits(b"340282366920938463463374607431768211456").unwrap();
+/// assert_eq!(
+///     nice.as_str(),
+///     "340,282,366,920,938,463,463,374,607,431,768,211,456",
+/// );
+///
+/// // Leading zeroes are dropped.
+/// assert_eq!(NiceBigint::from_digits(b"0001234").unwrap().as_str(), "1,234");
+///
+/// // Anything other than digits is an error.
+/// assert!(NiceBigint::from_digits(b"12.34").is_err());
+/// assert!(NiceBigint::from_digits(b"-1").is_err());
+/// assert!(NiceBigint::from_digits(b"").is_err());
+///
+/// // Formatted values can be parsed back too.
+/// let parsed: NiceBigint = nice.as_str().parse().unwrap();
+/// assert_eq!(parsed, nice);
+/// ```
+pub struct NiceBigint {
+	/// # Buffer.
+	inner: Box<[u8]>,
+}
+
+impl AsRef<[u8]> for NiceBigint {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceBigint {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceBigint {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceBigint {
+	#[inline]
+	fn default() -> Self { Self { inner: Box::new([b'0']) } }
+}
+
+impl Deref for NiceBigint {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceBigint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceBigint")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceBigint {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl From<NiceBigint> for String {
+	#[expect(unsafe_code, reason = "Content is ASCII.")]
+	#[inline]
+	fn from(src: NiceBigint) -> Self {
+		// Safety: the buffer is ASCII.
+		unsafe { Self::from_utf8_unchecked(src.inner.into_vec()) }
+	}
+}
+
+impl Ord for NiceBigint {
+	#[inline]
+	/// # Compare.
+	///
+	/// Without leading zeroes, longer is bigger; same-length values sort
+	/// lexically.
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.inner.len().cmp(&other.inner.len())
+			.then_with(|| self.inner.cmp(&other.inner))
+	}
+}
+
+impl PartialOrd for NiceBigint {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl TryFrom<&[u8]> for NiceBigint {
+	type Error = ParseByteError;
+
+	/// # From Formatted Bytes.
+	///
+	/// Parse a canonically-formatted value — comma separators in all the
+	/// right places, no leading zeroes — back into an instance.
+	///
+	/// Use [`NiceBigint::from_digits`] for raw digit strings instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBigint;
+	///
+	/// let nice = NiceBigint::try_from(&b"1,234,567,890,123,456,789,012"[..]).unwrap();
+	/// assert_eq!(nice.as_str(), "1,234,567,890,123,456,789,012");
+	///
+	/// // Anything else is an error.
+	/// assert!(NiceBigint::try_from(&b"1234"[..]).is_err());
+	/// assert!(NiceBigint::try_from(&b"01,234"[..]).is_err());
+	/// assert!(NiceBigint::try_from(&b"12,34"[..]).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty or malformed.
+	fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+		if src.is_empty() { return Err(ParseByteError::Empty); }
+
+		// Collect the digits, making sure there's nothing else weird.
+		let mut digits = Vec::with_capacity(src.len());
+		for (idx, &b) in src.iter().enumerate() {
+			if b.is_ascii_digit() { digits.push(b); }
+			else if b != b',' { return Err(ParseByteError::InvalidDigit(idx)); }
+		}
+		if digits.is_empty() { return Err(ParseByteError::InvalidDigit(0)); }
+
+		// There's only one correct way to format any given value, so if the
+		// result doesn't match, the source was malformed.
+		let out = Self::from_digits(&digits)?;
+		let nice = out.as_bytes();
+		if nice == src { Ok(out) }
+		else {
+			let idx = nice.iter()
+				.zip(src)
+				.position(|(a, b)| a != b)
+				.unwrap_or_else(|| nice.len().min(src.len() - 1));
+			Err(ParseByteError::InvalidDigit(idx))
+		}
+	}
+}
+
+impl TryFrom<&str> for NiceBigint {
+	type Error = ParseByteError;
+
+	#[inline]
+	/// # From Formatted String.
+	///
+	/// See the `TryFrom<&[u8]>` implementation for details.
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		Self::try_from(src.as_bytes())
+	}
+}
+
+impl std::str::FromStr for NiceBigint {
+	type Err = ParseByteError;
+
+	#[inline]
+	/// # From Str.
+	///
+	/// Parse a canonically-formatted value — comma separators in all the
+	/// right places, no leading zeroes — back into an instance.
+	///
+	/// Use [`NiceBigint::from_digits`] for raw digit strings instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBigint;
+	///
+	/// let nice: NiceBigint = "12,345".parse().unwrap();
+	/// assert_eq!(nice.as_str(), "12,345");
+	///
+	/// // Anything else is an error.
+	/// assert!("".parse::<NiceBigint>().is_err());
+	/// assert!("12345".parse::<NiceBigint>().is_err());
+	/// assert!("1.5".parse::<NiceBigint>().is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the string is empty or malformed.
+	fn from_str(src: &str) -> Result<Self, Self::Err> { Self::try_from(src) }
+}
+
+impl NiceBigint {
+	#[inline]
+	/// # From Digits.
+	///
+	/// Validate and format a slice of raw ASCII digits — no separators —
+	/// like `b"1234567"`. Leading zeroes are trimmed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBigint;
+	///
+	/// let nice = NiceBigint::from_digits(b"0001234567").unwrap();
+	/// assert_eq!(nice.as_str(), "1,234,567");
+	///
+	/// assert!(NiceBigint::from_digits(b"1,234").is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty or contains anything other than
+	/// ASCII digits.
+	pub fn from_digits(src: &[u8]) -> Result<Self, ParseByteError> {
+		Self::with_separator(src, NiceSeparator::Comma)
+	}
+
+	/// # New Instance w/ Custom Separator.
+	///
+	/// Validate and format a slice of raw ASCII digits — like
+	/// [`NiceBigint::from_digits`] — using an alternative [`NiceSeparator`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceBigint, NiceSeparator};
+	///
+	/// let nice = NiceBigint::with_separator(
+	///     b"123456789012345678901234567890",
+	///     NiceSeparator::Underscore,
+	/// ).unwrap();
+	/// assert_eq!(nice.as_str(), "123_456_789_012_345_678_901_234_567_890");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty or contains anything other than
+	/// ASCII digits.
+	pub fn with_separator<S: Into<NiceSeparator>>(src: &[u8], sep: S)
+	-> Result<Self, ParseByteError> {
+		if src.is_empty() { return Err(ParseByteError::Empty); }
+		if let Some(idx) = src.iter().position(|b| ! b.is_ascii_digit()) {
+			return Err(ParseByteError::InvalidDigit(idx));
+		}
+
+		// Trim leading zeroes, but keep at least one digit.
+		let start = src.iter().position(|&b| b != b'0').unwrap_or(src.len() - 1);
+		let src = &src[start..];
+
+		let mut out = vec![0; src.len() + (src.len() - 1) / 3];
+		crate::group_digits(src, sep, &mut out);
+
+		Ok(Self { inner: out.into_boxed_slice() })
+	}
+
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBigint;
+	///
+	/// assert_eq!(NiceBigint::from_digits(b"1000").unwrap().as_bytes(), b"1,000");
+	/// ```
+	pub const fn as_bytes(&self) -> &[u8] { &self.inner }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBigint;
+	///
+	/// assert_eq!(NiceBigint::from_digits(b"1000").unwrap().as_str(), "1,000");
+	/// ```
+	pub const fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(&self.inner) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Length.
+	///
+	/// Return the length of the formatted value, in bytes.
+	pub const fn len(&self) -> usize { self.inner.len() }
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	///
+	/// This is always `false`; there's at least one digit.
+	pub const fn is_empty(&self) -> bool { self.inner.is_empty() }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NiceU64;
+
+	#[test]
+	fn t_nice_bigint() {
+		// Within range, this should match NiceU64.
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u64(..)).take(10_000).chain([0, 1, 999, 1000, u64::MAX]) {
+			let src = num.to_string();
+			let nice = NiceBigint::from_digits(src.as_bytes()).unwrap();
+			assert_eq!(nice.as_str(), NiceU64::from(num).as_str());
+			assert_eq!(String::from(nice.clone()), NiceU64::from(num).as_str());
+
+			// With leading zeroes too.
+			assert_eq!(
+				NiceBigint::from_digits(format!("000{src}").as_bytes()).unwrap().as_str(),
+				NiceU64::from(num).as_str(),
+			);
+
+			// The formatted value should parse back to the same thing.
+			assert_eq!(nice.as_str().parse::<NiceBigint>(), Ok(nice.clone()));
+			assert_eq!(NiceBigint::try_from(nice.as_bytes()), Ok(nice));
+		}
+
+		// Bigger.
+		let nice = NiceBigint::from_digits(u128::MAX.to_string().as_bytes()).unwrap();
+		assert_eq!(nice.as_str(), "340,282,366,920,938,463,463,374,607,431,768,211,455");
+		assert_eq!(nice.len(), nice.as_str().len());
+
+		// Ordering.
+		let mut all: Vec<NiceBigint> = ["99", "100", "0", "1000000000000000000000000", "101", "00098"]
+			.into_iter()
+			.map(|s| NiceBigint::from_digits(s.as_bytes()).unwrap())
+			.collect();
+		all.sort();
+		let all: Vec<&str> = all.iter().map(NiceBigint::as_str).collect();
+		assert_eq!(all, ["0", "98", "99", "100", "101", "1,000,000,000,000,000,000,000,000"]);
+
+		// Zero.
+		assert_eq!(NiceBigint::from_digits(b"0000").unwrap(), NiceBigint::default());
+		assert_eq!(NiceBigint::try_from("0").unwrap(), NiceBigint::default());
+
+		// Errors.
+		assert_eq!(NiceBigint::from_digits(b""), Err(ParseByteError::Empty));
+		assert_eq!(NiceBigint::from_digits(b"1,000"), Err(ParseByteError::InvalidDigit(1)));
+		assert_eq!(NiceBigint::from_digits(b"+1"), Err(ParseByteError::InvalidDigit(0)));
+		assert_eq!(NiceBigint::from_digits(b"12 "), Err(ParseByteError::InvalidDigit(2)));
+		assert_eq!(NiceBigint::try_from(""), Err(ParseByteError::Empty));
+		assert_eq!(NiceBigint::try_from(","), Err(ParseByteError::InvalidDigit(0)));
+		assert_eq!(NiceBigint::try_from("1000"), Err(ParseByteError::InvalidDigit(1)));
+		assert_eq!(NiceBigint::try_from("01,000"), Err(ParseByteError::InvalidDigit(0)));
+		assert_eq!(NiceBigint::try_from("1,000,"), Err(ParseByteError::InvalidDigit(5)));
+		assert_eq!(NiceBigint::try_from("+1"), Err(ParseByteError::InvalidDigit(0)));
+	}
+}
@@ -0,0 +1,241 @@
+/*!
+# Dactyl: Nice Bits.
+*/
+
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `64 bits (0b` + 64 digits + 15 underscores + `)` = 91 bytes.
+const SIZE: usize = 91;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Bits.
+///
+/// This struct formats the bit length of an unsigned integer — the number of
+/// bits needed to represent it, i.e. its width without leading zeroes —
+/// alongside its binary representation, grouped into nibbles for
+/// readability.
+///
+/// This is mostly useful for protocol and bitflag debugging output.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceBits;
+///
+/// assert_eq!(NiceBits::from(300_u16).as_str(), "9 bits (0b1_0010_1100)");
+/// assert_eq!(NiceBits::from(1_u8).as_str(), "1 bit (0b1)");
+/// assert_eq!(NiceBits::from(0_u32).as_str(), "0 bits (0b0)");
+///
+/// // The parts are available as numbers too.
+/// assert_eq!(NiceBits::from(300_u16).bits(), 9);
+/// ```
+pub struct NiceBits {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NiceBits {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceBits {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceBits {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceBits {
+	#[inline]
+	fn default() -> Self { Self::from(0_u64) }
+}
+
+impl Deref for NiceBits {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceBits {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceBits")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceBits {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceBits {}
+
+impl From<u64> for NiceBits {
+	fn from(mut num: u64) -> Self {
+		let bits = u64::BITS - num.leading_zeros();
+		let mut out = Self { inner: [b' '; SIZE], from: SIZE };
+		out.push_front(b")");
+
+		// The binary digits, with an underscore between nibbles.
+		let mut idx = 0;
+		loop {
+			if idx != 0 && idx % 4 == 0 { out.push_front(b"_"); }
+			out.push_front(if num & 1 == 1 { b"1" } else { b"0" });
+			num >>= 1;
+			idx += 1;
+			if num == 0 { break; }
+		}
+
+		out.push_front(if bits == 1 { b" bit (0b" } else { b" bits (0b" });
+		let [a, b] = crate::double(bits as usize);
+		out.push_front(&[b]);
+		if a != b'0' { out.push_front(&[a]); }
+		out
+	}
+}
+
+/// # Helper: From Smaller Types.
+macro_rules! from {
+	($($ty:ty),+) => ($(
+		impl From<$ty> for NiceBits {
+			#[inline]
+			fn from(num: $ty) -> Self { Self::from(u64::from(num)) }
+		}
+	)+);
+}
+
+from!(u8, u16, u32);
+
+impl From<usize> for NiceBits {
+	#[inline]
+	fn from(num: usize) -> Self { Self::from(num as u64) }
+}
+
+impl hash::Hash for NiceBits {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceBits {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceBits {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBits;
+	///
+	/// assert_eq!(NiceBits::from(5_u8).as_bytes(), b"3 bits (0b101)");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBits;
+	///
+	/// assert_eq!(NiceBits::from(5_u8).as_str(), "3 bits (0b101)");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	/// # Bit Length.
+	///
+	/// Return the bit length, i.e. the number of binary digits, not counting
+	/// leading zeroes. (Zero has a bit length of zero.)
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceBits;
+	///
+	/// assert_eq!(NiceBits::from(0_u8).bits(), 0);
+	/// assert_eq!(NiceBits::from(255_u8).bits(), 8);
+	/// assert_eq!(NiceBits::from(u64::MAX).bits(), 64);
+	/// ```
+	pub fn bits(&self) -> u8 {
+		self.as_bytes().iter()
+			.take_while(|b| b.is_ascii_digit())
+			.fold(0, |acc, b| acc * 10 + (b - b'0'))
+	}
+}
+
+impl NiceBits {
+	/// # Push Front.
+	///
+	/// Prepend some bytes.
+	fn push_front(&mut self, src: &[u8]) {
+		self.from -= src.len();
+		self.inner[self.from..self.from + src.len()].copy_from_slice(src);
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_nice_bits() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000).chain([0, 1, 2, 15, 16, u64::MAX]) {
+			let bits = u64::BITS - num.leading_zeros();
+			let bin = format!("{num:b}")
+				.as_bytes()
+				.rchunks(4)
+				.rev()
+				.map(|c| std::str::from_utf8(c).unwrap())
+				.collect::<Vec<_>>()
+				.join("_");
+			let expected = format!("{bits} {} (0b{bin})", if bits == 1 { "bit" } else { "bits" });
+
+			let nice = NiceBits::from(num);
+			assert_eq!(nice.as_str(), expected);
+			assert_eq!(u32::from(nice.bits()), bits);
+
+			if let Ok(n) = u32::try_from(num) { assert_eq!(NiceBits::from(n), nice); }
+			if let Ok(n) = u8::try_from(num) { assert_eq!(NiceBits::from(n), nice); }
+		}
+
+		assert_eq!(NiceBits::default().as_str(), "0 bits (0b0)");
+		assert_eq!(NiceBits::from(u64::MAX).len(), SIZE);
+	}
+}
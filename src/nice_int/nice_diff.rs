@@ -0,0 +1,284 @@
+/*!
+# Dactyl: Nice Diff.
+*/
+
+use crate::{
+	NiceSeparator,
+	NiceU64,
+};
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `-18,446,744,073,709,551,615` = twenty-seven bytes.
+const SIZE: usize = 27;
+
+/// # Plus-Minus Sign (UTF-8).
+const PLUS_MINUS: [u8; 2] = [0xC2, 0xB1];
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Diff.
+///
+/// This struct renders the signed difference between two unsigned values —
+/// `after - before` — like `+1,234`, `-567`, or `±0`, for changelogs,
+/// metrics, and the like.
+///
+/// The magnitude is computed with [`u64::abs_diff`], so the full range is
+/// supported without any risk of overflow.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceDiff;
+///
+/// assert_eq!(NiceDiff::new(1000, 2234).as_str(), "+1,234");
+/// assert_eq!(NiceDiff::new(1000, 433).as_str(), "-567");
+/// assert_eq!(NiceDiff::new(5, 5).as_str(), "±0");
+///
+/// // No overflow here!
+/// assert_eq!(
+///     NiceDiff::new(u64::MAX, 0).as_str(),
+///     "-18,446,744,073,709,551,615",
+/// );
+/// ```
+pub struct NiceDiff {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NiceDiff {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceDiff {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceDiff {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceDiff {
+	#[inline]
+	fn default() -> Self { Self::ZERO }
+}
+
+impl Deref for NiceDiff {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceDiff {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceDiff")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceDiff {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceDiff {}
+
+impl From<(u64, u64)> for NiceDiff {
+	#[inline]
+	/// # From Before/After.
+	///
+	/// This is equivalent to [`NiceDiff::new`].
+	fn from(src: (u64, u64)) -> Self { Self::new(src.0, src.1) }
+}
+
+impl hash::Hash for NiceDiff {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceDiff {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceDiff {
+	/// # Zero.
+	///
+	/// ```
+	/// use dactyl::NiceDiff;
+	///
+	/// assert_eq!(NiceDiff::ZERO.as_str(), "±0");
+	/// assert_eq!(NiceDiff::ZERO, NiceDiff::new(3, 3));
+	/// ```
+	pub const ZERO: Self = {
+		let mut inner = [b'0'; SIZE];
+		inner[SIZE - 3] = PLUS_MINUS[0];
+		inner[SIZE - 2] = PLUS_MINUS[1];
+		Self { inner, from: SIZE - 3 }
+	};
+
+	#[must_use]
+	/// # New.
+	///
+	/// Format the difference between `before` and `after`, i.e. how much
+	/// the value went up or down.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDiff;
+	///
+	/// assert_eq!(NiceDiff::new(10, 15).as_str(), "+5");
+	/// assert_eq!(NiceDiff::new(15, 10).as_str(), "-5");
+	/// ```
+	pub fn new(before: u64, after: u64) -> Self {
+		Self::with_separator(before, after, NiceSeparator::Comma)
+	}
+
+	#[must_use]
+	/// # New Instance w/ Custom Separator.
+	///
+	/// Same as [`NiceDiff::new`], but with an alternative [`NiceSeparator`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceDiff, NiceSeparator};
+	///
+	/// assert_eq!(
+	///     NiceDiff::with_separator(0, 1_234_567, NiceSeparator::Underscore).as_str(),
+	///     "+1_234_567",
+	/// );
+	/// ```
+	pub fn with_separator<S: Into<NiceSeparator>>(before: u64, after: u64, sep: S) -> Self {
+		let sign = match after.cmp(&before) {
+			std::cmp::Ordering::Less => b'-',
+			std::cmp::Ordering::Equal => return Self::ZERO,
+			std::cmp::Ordering::Greater => b'+',
+		};
+
+		let nice = NiceU64::with_nice_separator(after.abs_diff(before), sep);
+		let nice = nice.as_bytes();
+
+		let mut out = Self::ZERO;
+		out.from = SIZE - nice.len() - 1;
+		out.inner[out.from] = sign;
+		out.inner[out.from + 1..].copy_from_slice(nice);
+		out
+	}
+}
+
+impl NiceDiff {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDiff;
+	///
+	/// assert_eq!(NiceDiff::new(1, 0).as_bytes(), b"-1");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDiff;
+	///
+	/// assert_eq!(NiceDiff::new(0, 1).as_str(), "+1");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: the buffer is ASCII, except for the (valid UTF-8) ±.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Is Zero?
+	///
+	/// Returns `true` if `before` and `after` were the same.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDiff;
+	///
+	/// assert!(NiceDiff::new(5, 5).is_zero());
+	/// assert!(! NiceDiff::new(5, 6).is_zero());
+	/// ```
+	pub const fn is_zero(&self) -> bool { self.inner[self.from] == PLUS_MINUS[0] }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_diff() {
+		let mut rng = fastrand::Rng::new();
+		for (a, b) in std::iter::repeat_with(|| (rng.u64(..) >> rng.u32(0..64), rng.u64(..) >> rng.u32(0..64))).take(10_000) {
+			let nice = NiceDiff::new(a, b);
+			let expected =
+				if a == b { "±0".to_owned() }
+				else {
+					format!(
+						"{}{}",
+						if a < b { '+' } else { '-' },
+						a.abs_diff(b).to_formatted_string(&Locale::en),
+					)
+				};
+			assert_eq!(nice.as_str(), expected);
+			assert_eq!(nice, NiceDiff::from((a, b)));
+			assert_eq!(nice.is_zero(), a == b);
+			assert_eq!(
+				NiceDiff::with_separator(a, b, NiceSeparator::Underscore).as_str(),
+				expected.replace(',', "_"),
+			);
+
+			// This should match NiceU64::delta where the latter can be used.
+			if a != b {
+				if let Ok(delta) = i64::try_from(i128::from(b) - i128::from(a)) {
+					assert_eq!(nice.as_str(), NiceU64::delta(delta).as_str());
+				}
+			}
+		}
+
+		// Edges.
+		assert_eq!(NiceDiff::default(), NiceDiff::ZERO);
+		assert!(NiceDiff::ZERO.is_zero());
+		assert_eq!(NiceDiff::new(0, u64::MAX).as_str(), "+18,446,744,073,709,551,615");
+		assert_eq!(NiceDiff::new(u64::MAX, 0).as_str(), "-18,446,744,073,709,551,615");
+		assert_eq!(NiceDiff::new(10, 0).as_str(), "-10");
+		assert!(! NiceDiff::new(10, 0).is_zero());
+	}
+}
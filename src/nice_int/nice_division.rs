@@ -0,0 +1,299 @@
+/*!
+# Dactyl: Nice Division.
+*/
+
+use crate::NiceU64;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Maximum Decimal Places.
+const MAX_PRECISION: u8 = 8;
+
+/// # Total Buffer Size.
+///
+/// The longest possible value is `u64::MAX / 1` to eight places:
+/// `18,446,744,073,709,551,615` + `.` + `00000000` = 35 bytes.
+///
+/// (Quotient/remainder pairs can have at most 21 digits between them, so top
+/// out at 30 bytes.)
+const SIZE: usize = 35;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Division.
+///
+/// This struct formats the result of an integer division, either as a
+/// quotient and remainder — `17 / 5` as `3 r 2` — or as a decimal rounded
+/// to a fixed number of places — `3.40` — for teaching tools, calculators,
+/// and the like.
+///
+/// Whole numbers are formatted with commas, like [`NiceU64`].
+///
+/// Division by zero is unsupported; the constructors return `None` in such
+/// cases.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceDivision;
+///
+/// assert_eq!(NiceDivision::remainder(17, 5).unwrap().as_str(), "3 r 2");
+/// assert_eq!(NiceDivision::decimal(17, 5, 2).unwrap().as_str(), "3.40");
+/// assert_eq!(NiceDivision::decimal(2, 3, 3).unwrap().as_str(), "0.667");
+/// assert_eq!(NiceDivision::remainder(12_345, 10).unwrap().as_str(), "1,234 r 5");
+///
+/// // No dividing by zero!
+/// assert!(NiceDivision::remainder(17, 0).is_none());
+/// ```
+pub struct NiceDivision {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NiceDivision {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceDivision {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceDivision {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceDivision {
+	#[inline]
+	fn default() -> Self {
+		let mut out = Self { inner: [b' '; SIZE], from: SIZE };
+		out.push_remainder(0, 0);
+		out
+	}
+}
+
+impl Deref for NiceDivision {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceDivision {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceDivision")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceDivision {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceDivision {}
+
+impl hash::Hash for NiceDivision {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceDivision {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceDivision {
+	#[must_use]
+	/// # Quotient and Remainder.
+	///
+	/// Divide `num` by `den`, formatting the result as `Q r R`. The
+	/// remainder is always included, even if zero.
+	///
+	/// Returns `None` if `den` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDivision;
+	///
+	/// assert_eq!(NiceDivision::remainder(17, 5).unwrap().as_str(), "3 r 2");
+	/// assert_eq!(NiceDivision::remainder(15, 5).unwrap().as_str(), "3 r 0");
+	/// assert_eq!(NiceDivision::remainder(3, 5).unwrap().as_str(), "0 r 3");
+	/// assert_eq!(
+	///     NiceDivision::remainder(u64::MAX, 1000).unwrap().as_str(),
+	///     "18,446,744,073,709,551 r 615",
+	/// );
+	/// ```
+	pub fn remainder(num: u64, den: u64) -> Option<Self> {
+		if den == 0 { return None; }
+		let mut out = Self { inner: [b' '; SIZE], from: SIZE };
+		out.push_remainder(num / den, num % den);
+		Some(out)
+	}
+
+	#[must_use]
+	/// # Decimal.
+	///
+	/// Divide `num` by `den`, formatting the result as a decimal with exactly
+	/// `precision` places (up to `8`), rounded tie-to-even, like
+	/// [`NiceFloat::from_ratio`](crate::NiceFloat::from_ratio).
+	///
+	/// A precision of zero rounds to the nearest whole number.
+	///
+	/// Returns `None` if `den` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDivision;
+	///
+	/// assert_eq!(NiceDivision::decimal(17, 5, 2).unwrap().as_str(), "3.40");
+	/// assert_eq!(NiceDivision::decimal(17, 5, 0).unwrap().as_str(), "3");
+	/// assert_eq!(NiceDivision::decimal(5, 2, 0).unwrap().as_str(), "2");
+	/// assert_eq!(NiceDivision::decimal(7, 2, 0).unwrap().as_str(), "4");
+	/// assert_eq!(NiceDivision::decimal(1, 3, 99).unwrap().as_str(), "0.33333333");
+	/// assert_eq!(
+	///     NiceDivision::decimal(u64::MAX, 2, 1).unwrap().as_str(),
+	///     "9,223,372,036,854,775,807.5",
+	/// );
+	/// ```
+	pub fn decimal(num: u64, den: u64, precision: u8) -> Option<Self> {
+		if den == 0 { return None; }
+
+		let precision = precision.min(MAX_PRECISION);
+		let (top, mut bottom) = super::nice_float::div_round(
+			num,
+			den,
+			10_u32.pow(u32::from(precision)),
+		);
+
+		let mut out = Self { inner: [b' '; SIZE], from: SIZE };
+		if precision != 0 {
+			for _ in 0..precision {
+				out.from -= 1;
+				out.inner[out.from] = (bottom % 10) as u8 + b'0';
+				bottom /= 10;
+			}
+			out.from -= 1;
+			out.inner[out.from] = b'.';
+		}
+		out.push_nice(top);
+		Some(out)
+	}
+
+	/// # Push Quotient/Remainder.
+	///
+	/// Write `Q r R` to the front of the buffer.
+	fn push_remainder(&mut self, q: u64, r: u64) {
+		self.push_nice(r);
+		self.from -= 3;
+		self.inner[self.from..self.from + 3].copy_from_slice(b" r ");
+		self.push_nice(q);
+	}
+
+	/// # Push Number.
+	///
+	/// Write a nicely-formatted number to the front of the buffer.
+	fn push_nice(&mut self, num: u64) {
+		let nice = NiceU64::from(num);
+		let nice = nice.as_bytes();
+		self.from -= nice.len();
+		self.inner[self.from..self.from + nice.len()].copy_from_slice(nice);
+	}
+}
+
+impl NiceDivision {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDivision;
+	///
+	/// assert_eq!(NiceDivision::remainder(17, 5).unwrap().as_bytes(), b"3 r 2");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceDivision;
+	///
+	/// assert_eq!(NiceDivision::remainder(17, 5).unwrap().as_str(), "3 r 2");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NiceFloat;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_division() {
+		let mut rng1 = fastrand::Rng::new();
+		let mut rng2 = fastrand::Rng::new();
+		for (num, den) in [(0, 1), (1, 1), (u64::MAX, 1), (u64::MAX, u64::MAX - 1), (1, u64::MAX)].into_iter()
+			.chain(std::iter::repeat_with(|| (rng1.u64(..), rng1.u64(1..))).take(1000))
+			.chain(std::iter::repeat_with(|| (rng2.u64(..1_000_000), rng2.u64(1..1000))).take(1000))
+		{
+			// Quotient/remainder.
+			assert_eq!(
+				NiceDivision::remainder(num, den).unwrap().as_str(),
+				format!(
+					"{} r {}",
+					(num / den).to_formatted_string(&Locale::en),
+					(num % den).to_formatted_string(&Locale::en),
+				),
+			);
+
+			// At full precision, the decimal should match the float ratio.
+			assert_eq!(
+				NiceDivision::decimal(num, den, 8).unwrap().as_str(),
+				NiceFloat::from_ratio(num, den).as_str(),
+			);
+
+			// Lower precisions should be the right length.
+			for p in 0..8 {
+				let nice = NiceDivision::decimal(num, den, p).unwrap();
+				let frac = nice.as_str().split_once('.').map_or(0, |(_, b)| b.len());
+				assert_eq!(frac, usize::from(p));
+			}
+
+			assert!(NiceDivision::remainder(num, 0).is_none());
+			assert!(NiceDivision::decimal(num, 0, 2).is_none());
+		}
+
+		assert_eq!(NiceDivision::default().as_str(), "0 r 0");
+	}
+}
@@ -0,0 +1,364 @@
+/*!
+# Dactyl: Nice Fixed.
+*/
+
+use crate::{
+	NiceSeparator,
+	NiceU64,
+};
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Maximum Scale.
+///
+/// `i64` values have at most nineteen digits; there's no point shifting any
+/// further than that.
+const MAX_SCALE: u8 = 19;
+
+/// # Total Buffer Size.
+///
+/// `-` + `9,223,372,036,854,775,808` = 26 bytes.
+const SIZE: usize = 26;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Fixed.
+///
+/// This struct formats fixed-point (scaled) integers — like money stored as
+/// integer cents — as decimals, using pure integer math, without any lossy
+/// round-trips through `f64`.
+///
+/// The scale is the number of implied decimal places, up to `19`. The whole
+/// part is comma-separated, just like [`NiceU64`], but both the separator and
+/// decimal point can be customized with [`NiceFixed::with_separator`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceFixed, NiceSeparator};
+///
+/// assert_eq!(NiceFixed::from_scaled(123_456, 2).as_str(), "1,234.56");
+/// assert_eq!(NiceFixed::from_scaled(-5, 2).as_str(), "-0.05");
+/// assert_eq!(NiceFixed::from_scaled(123_456, 0).as_str(), "123,456");
+///
+/// // European punctuation.
+/// assert_eq!(
+///     NiceFixed::with_separator(123_456, 2, NiceSeparator::Period, b',').as_str(),
+///     "1.234,56",
+/// );
+///
+/// // Update the value in place; the scale and punctuation are preserved.
+/// let mut num = NiceFixed::from_scaled(100, 2);
+/// assert_eq!(num.as_str(), "1.00");
+///
+/// num.replace(987_654_321);
+/// assert_eq!(num.as_str(), "9,876,543.21");
+/// ```
+pub struct NiceFixed {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+
+	/// # Scale.
+	scale: u8,
+
+	/// # Thousands Separator.
+	sep: NiceSeparator,
+
+	/// # Decimal Point.
+	point: u8,
+}
+
+impl AsRef<[u8]> for NiceFixed {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceFixed {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceFixed {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceFixed {
+	#[inline]
+	fn default() -> Self { Self::from_scaled(0, 0) }
+}
+
+impl Deref for NiceFixed {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+#[expect(clippy::missing_fields_in_debug, reason = "Buffers are internal.")]
+impl fmt::Debug for NiceFixed {
+	/// # Debug.
+	///
+	/// The alternate form — `{:#?}` — includes the raw (scaled) value and
+	/// scale alongside the string.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			f.debug_struct("NiceFixed")
+				.field("value", &self.value())
+				.field("scale", &self.scale)
+				.field("nice", &self.as_str())
+				.finish()
+		}
+		else {
+			f.debug_tuple("NiceFixed")
+				.field(&self.as_str())
+				.finish()
+		}
+	}
+}
+
+impl fmt::Display for NiceFixed {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceFixed {}
+
+impl hash::Hash for NiceFixed {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceFixed {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceFixed {
+	#[must_use]
+	/// # From Scaled.
+	///
+	/// Create a new instance from an integer `num` with `scale` implied
+	/// decimal places, e.g. `123456` with a scale of `2` is `1,234.56`.
+	///
+	/// Scales larger than `19` are capped.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFixed;
+	///
+	/// assert_eq!(NiceFixed::from_scaled(123_456, 3).as_str(), "123.456");
+	/// assert_eq!(NiceFixed::from_scaled(1, 5).as_str(), "0.00001");
+	/// assert_eq!(
+	///     NiceFixed::from_scaled(i64::MIN, 2).as_str(),
+	///     "-92,233,720,368,547,758.08",
+	/// );
+	/// ```
+	pub fn from_scaled(num: i64, scale: u8) -> Self {
+		Self::with_separator(num, scale, NiceSeparator::Comma, b'.')
+	}
+
+	#[must_use]
+	/// # From Scaled (Custom Punctuation).
+	///
+	/// Same as [`NiceFixed::from_scaled`], but with an alternative
+	/// [`NiceSeparator`] and an arbitrary ASCII decimal point.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceFixed, NiceSeparator};
+	///
+	/// assert_eq!(
+	///     NiceFixed::with_separator(-123_456_789, 4, NiceSeparator::Underscore, b'.').as_str(),
+	///     "-12_345.6789",
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the point is invalid ASCII.
+	pub fn with_separator<S: Into<NiceSeparator>>(num: i64, scale: u8, sep: S, point: u8)
+	-> Self {
+		assert!(point.is_ascii(), "Invalid decimal point.");
+
+		let mut out = Self {
+			inner: [b'0'; SIZE],
+			from: SIZE,
+			scale: scale.min(MAX_SCALE),
+			sep: sep.into(),
+			point,
+		};
+		out.replace(num);
+		out
+	}
+
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new value, keeping
+	/// the original scale and punctuation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceFixed, NiceSeparator};
+	///
+	/// let mut num = NiceFixed::with_separator(0, 2, NiceSeparator::Period, b',');
+	/// assert_eq!(num.as_str(), "0,00");
+	///
+	/// num.replace(-123_456);
+	/// assert_eq!(num.as_str(), "-1.234,56");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace(5).as_str(), "0,05");
+	/// ```
+	pub fn replace(&mut self, num: i64) -> &mut Self {
+		let abs = num.unsigned_abs();
+		self.from = SIZE;
+
+		// Fractional digits, if any.
+		let whole =
+			if self.scale == 0 { abs }
+			else {
+				let div = 10_u64.pow(u32::from(self.scale));
+				let mut frac = abs % div;
+				for _ in 0..self.scale {
+					self.from -= 1;
+					self.inner[self.from] = (frac % 10) as u8 + b'0';
+					frac /= 10;
+				}
+				self.from -= 1;
+				self.inner[self.from] = self.point;
+				abs / div
+			};
+
+		// The whole part.
+		let nice = NiceU64::with_nice_separator(whole, self.sep);
+		let nice = nice.as_bytes();
+		self.from -= nice.len();
+		self.inner[self.from..self.from + nice.len()].copy_from_slice(nice);
+
+		// The sign.
+		if num < 0 {
+			self.from -= 1;
+			self.inner[self.from] = b'-';
+		}
+
+		self
+	}
+}
+
+impl NiceFixed {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFixed;
+	///
+	/// assert_eq!(NiceFixed::from_scaled(12_345, 2).as_bytes(), b"123.45");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFixed;
+	///
+	/// assert_eq!(NiceFixed::from_scaled(12_345, 2).as_str(), "123.45");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Scale.
+	///
+	/// Return the number of implied decimal places.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFixed;
+	///
+	/// assert_eq!(NiceFixed::from_scaled(12_345, 2).scale(), 2);
+	/// assert_eq!(NiceFixed::from_scaled(12_345, 200).scale(), 19);
+	/// ```
+	pub const fn scale(&self) -> u8 { self.scale }
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Value.
+	///
+	/// Recover the raw (scaled) integer from the formatted digits.
+	pub(super) fn value(&self) -> i64 {
+		let abs = self.as_bytes().iter()
+			.filter(|b| b.is_ascii_digit())
+			.fold(0_i128, |acc, b| acc * 10 + i128::from(b - b'0'));
+
+		// The digits came from an i64, so will fit in one.
+		if self.inner[self.from] == b'-' { (-abs) as i64 }
+		else { abs as i64 }
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_fixed() {
+		let mut rng = fastrand::Rng::new();
+		for num in [0, 1, -1, i64::MIN, i64::MAX].into_iter()
+			.chain(std::iter::repeat_with(|| rng.i64(..)).take(500))
+		{
+			for scale in 0..=MAX_SCALE {
+				// Build the expected value the slow way.
+				let digits = format!("{:0>width$}", num.unsigned_abs(), width = usize::from(scale) + 1);
+				let (whole, frac) = digits.split_at(digits.len() - usize::from(scale));
+				let whole = whole.parse::<u64>().unwrap().to_formatted_string(&Locale::en);
+				let sign = if num < 0 { "-" } else { "" };
+				let expected =
+					if scale == 0 { format!("{sign}{whole}") }
+					else { format!("{sign}{whole}.{frac}") };
+
+				let nice = NiceFixed::from_scaled(num, scale);
+				assert_eq!(nice.as_str(), expected, "{num} / 10^{scale}");
+
+				// Replacement should match too.
+				let mut last = NiceFixed::from_scaled(i64::MIN, scale);
+				last.replace(num);
+				assert_eq!(last, nice);
+			}
+		}
+
+		// Overly large scales are capped.
+		assert_eq!(NiceFixed::from_scaled(5, 100), NiceFixed::from_scaled(5, MAX_SCALE));
+		assert_eq!(NiceFixed::default().as_str(), "0");
+	}
+}
@@ -2,7 +2,12 @@
 # Dactyl: Nice Float.
 */
 
-use crate::NiceWrapper;
+use crate::{
+	NicePaddedFloat,
+	NiceSeparator,
+	NiceWrapper,
+};
+use std::cmp::Ordering;
 
 
 
@@ -86,18 +91,58 @@ macro_rules! inner {
 /// * `Eq` / `PartialEq`
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<f64>` / `PartialOrd<f64>` (and vice versa)
+///
+/// Float comparisons are made against the value _as printed_, with the float
+/// rounded to eight decimal places first, just like [`NiceFloat::from`]
+/// would, so the original needn't be kept around for e.g. threshold checks:
+///
+/// ```
+/// use dactyl::NiceFloat;
+///
+/// let nice = NiceFloat::from(0.96_f64);
+/// assert!(nice > 0.95);
+/// assert!(nice == 0.96);
+/// assert!(nice == 0.960_000_001); // Same at eight places.
+/// assert!(nice < 0.960_000_01);   // Not the same.
+///
+/// // NaN is never equal to anything.
+/// assert!(NiceFloat::NAN != f64::NAN);
+///
+/// // Overflows are greater (or lesser) than anything that fits.
+/// assert!(NiceFloat::from(f64::MAX) > 1e19);
+/// assert!(NiceFloat::from(f64::MIN) < -1e19);
+/// ```
 ///
 /// You can instantiate a `NiceFloat` with:
 ///
 /// * `From<f64>`
+/// * `From<&f64>`
 /// * `From<Option<f64>>`
+/// * `From<Option<&f64>>`
 /// * `From<f32>`
+/// * `From<&f32>`
 /// * `From<Option<f32>>`
+/// * `From<Option<&f32>>`
 /// * `From<FloatKind>`
 ///
 /// When converting from a `None`, the result will be equivalent to zero.
+///
+/// ```
+/// use dactyl::NiceFloat;
+///
+/// let nums = [1.5_f64, -2.25];
+/// let nice: Vec<NiceFloat> = nums.iter().map(NiceFloat::from).collect();
+/// assert_eq!(nice[0].compact_str(), "1.5");
+/// assert_eq!(nice[1].compact_str(), "-2.25");
+///
+/// assert_eq!(NiceFloat::from(nums.first()).compact_str(), "1.5");
+/// assert_eq!(NiceFloat::from(None::<f32>), NiceFloat::ZERO);
+/// ```
 pub type NiceFloat = NiceWrapper<SIZE>;
 
+super::nice_debug!(NiceFloat);
+
 impl Default for NiceFloat {
 	#[inline]
 	fn default() -> Self { Self::ZERO }
@@ -113,6 +158,16 @@ impl From<f64> for NiceFloat {
 	fn from(num: f64) -> Self { Self::from(FloatKind::from(num)) }
 }
 
+impl From<&f32> for NiceFloat {
+	#[inline]
+	fn from(num: &f32) -> Self { Self::from(*num) }
+}
+
+impl From<&f64> for NiceFloat {
+	#[inline]
+	fn from(num: &f64) -> Self { Self::from(*num) }
+}
+
 impl From<FloatKind> for NiceFloat {
 	fn from(kind: FloatKind) -> Self {
 		match kind {
@@ -130,6 +185,30 @@ impl From<FloatKind> for NiceFloat {
 	}
 }
 
+impl PartialEq<f64> for NiceFloat {
+	#[inline]
+	fn eq(&self, other: &f64) -> bool {
+		matches!(self.cmp_f64(*other), Some(Ordering::Equal))
+	}
+}
+
+impl PartialEq<NiceFloat> for f64 {
+	#[inline]
+	fn eq(&self, other: &NiceFloat) -> bool { other == self }
+}
+
+impl PartialOrd<f64> for NiceFloat {
+	#[inline]
+	fn partial_cmp(&self, other: &f64) -> Option<Ordering> { self.cmp_f64(*other) }
+}
+
+impl PartialOrd<NiceFloat> for f64 {
+	#[inline]
+	fn partial_cmp(&self, other: &NiceFloat) -> Option<Ordering> {
+		other.cmp_f64(*self).map(Ordering::reverse)
+	}
+}
+
 impl NiceFloat {
 	/// # Infinity.
 	///
@@ -222,8 +301,9 @@ impl NiceFloat {
 	#[must_use]
 	/// # New Instance w/ Custom Separator.
 	///
-	/// Create a new instance, defining any arbitrary ASCII byte as the
-	/// thousands separator, and another for the decimal point.
+	/// Create a new instance using an alternative [`NiceSeparator`] — or
+	/// anything that converts into one — as the thousands separator, and an
+	/// arbitrary ASCII byte as the decimal point.
 	///
 	/// If you're good with American commas/periods, just use
 	/// [`NiceFloat::from`] instead; it's faster.
@@ -231,33 +311,89 @@ impl NiceFloat {
 	/// ## Examples
 	///
 	/// ```
-	/// use dactyl::NiceFloat;
+	/// use dactyl::{NiceFloat, NiceSeparator};
 	///
 	/// assert_eq!(NiceFloat::from(1234.5678_f64).as_str(), "1,234.56780000");
 	/// assert_eq!(
-	///     NiceFloat::with_separator(1234.5678_f64, b'.', b',').as_str(),
+	///     NiceFloat::with_nice_separator(1234.5678_f64, NiceSeparator::Period, b',').as_str(),
 	///     "1.234,56780000",
 	/// );
 	///
 	/// // The punctuation is also honored for "special" values:
 	/// assert_eq!(
-	///     NiceFloat::with_separator(0_f64, b'.', b',').as_str(),
+	///     NiceFloat::with_nice_separator(0_f64, NiceSeparator::Period, b',').as_str(),
 	///     "0,00000000",
 	/// );
 	/// assert_eq!(
-	///     NiceFloat::with_separator(f64::MAX, b'.', b',').as_str(),
+	///     NiceFloat::with_nice_separator(f64::MAX, NiceSeparator::Period, b',').as_str(),
 	///     "> 18.446.744.073.709.551.615",
 	/// );
 	/// ```
 	///
 	/// ## Panics
 	///
-	/// This method will panic if the separator is invalid ASCII.
+	/// This method will panic if the point is invalid ASCII.
+	pub fn with_nice_separator<S: Into<NiceSeparator>>(num: f64, sep: S, point: u8)
+	-> Self {
+		Self::with_separator_kind(FloatKind::from(num), sep.into().as_byte(), point)
+	}
+
+	#[deprecated(note = "use NiceFloat::with_nice_separator instead")]
+	#[must_use]
+	/// # New Instance w/ Custom Separator (Raw).
+	///
+	/// Create a new instance, defining any arbitrary ASCII byte as the
+	/// thousands separator, and another for the decimal point.
+	///
+	/// This is deprecated in favor of [`NiceFloat::with_nice_separator`].
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the separator or point are invalid ASCII.
 	pub fn with_separator(num: f64, sep: u8, point: u8) -> Self {
 		assert!(sep.is_ascii(), "Invalid separator.");
+		Self::with_separator_kind(FloatKind::from(num), sep, point)
+	}
+
+	#[must_use]
+	/// # New Instance w/ Custom Separator (`f32`).
+	///
+	/// This is the same as [`NiceFloat::with_nice_separator`], except it
+	/// accepts an `f32` directly, using the same parsing path as `From<f32>`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceFloat, NiceSeparator};
+	///
+	/// assert_eq!(
+	///     NiceFloat::with_nice_separator_f32(1234.5_f32, NiceSeparator::Period, b',').as_str(),
+	///     "1.234,50000000",
+	/// );
+	///
+	/// // Same as From, just with different punctuation.
+	/// assert_eq!(
+	///     NiceFloat::with_nice_separator_f32(1234.5678_f32, NiceSeparator::Comma, b'.'),
+	///     NiceFloat::from(1234.5678_f32),
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the point is invalid ASCII.
+	pub fn with_nice_separator_f32<S: Into<NiceSeparator>>(num: f32, sep: S, point: u8)
+	-> Self {
+		Self::with_separator_kind(FloatKind::from(num), sep.into().as_byte(), point)
+	}
+
+	/// # New Instance w/ Custom Separator (Parsed).
+	///
+	/// This holds the shared logic for the separator constructors, which are
+	/// responsible for validating `sep`.
+	fn with_separator_kind(kind: FloatKind, sep: u8, point: u8) -> Self {
 		assert!(point.is_ascii(), "Invalid decimal point.");
 
-		match FloatKind::from(num) {
+		match kind {
 			FloatKind::NaN => Self::NAN,
 			FloatKind::Zero => {
 				let mut out = Self::ZERO;
@@ -286,6 +422,355 @@ impl NiceFloat {
 			FloatKind::Infinity => Self::INFINITY,
 		}
 	}
+
+	#[must_use]
+	/// # New Instance w/ Style.
+	///
+	/// Create a new instance using the separator and decimal point from a
+	/// [`NiceStyle`](crate::NiceStyle).
+	///
+	/// Other options, like the grouping, are not (currently) supported by
+	/// `NiceFloat`, and are ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceFloat, NiceSeparator, NiceStyle};
+	///
+	/// let style = NiceStyle::new().with_separator(NiceSeparator::Space);
+	/// assert_eq!(
+	///     NiceFloat::with_style(1234.5_f64, style).as_str(),
+	///     "1 234.50000000",
+	/// );
+	/// ```
+	pub fn with_style(num: f64, style: crate::NiceStyle) -> Self {
+		Self::with_nice_separator(num, style.separator(), style.point())
+	}
+
+	#[must_use]
+	#[inline]
+	/// # New Instance (European).
+	///
+	/// Create a new instance using period thousands separators and a comma
+	/// decimal point, i.e. [`NiceStyle::eu`](crate::NiceStyle::eu).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::eu(1234.5678_f64).as_str(), "1.234,56780000");
+	/// assert_eq!(NiceFloat::eu(-0.5_f64).compact_str(), "-0,5");
+	/// ```
+	pub fn eu(num: f64) -> Self {
+		Self::with_nice_separator(num, NiceSeparator::Period, b',')
+	}
+
+	#[must_use]
+	/// # From Ratio.
+	///
+	/// Create a new instance from the quotient of `num / den`, performing the
+	/// decimal expansion with integer arithmetic, avoiding the precision loss
+	/// that would otherwise result from converting large values to `f64`
+	/// first.
+	///
+	/// As with the float conversions, the fractional part is rounded to
+	/// eight places using a tie-to-even strategy.
+	///
+	/// Division by zero returns [`NiceFloat::NAN`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(
+	///     NiceFloat::from_ratio(u64::MAX, 2).as_str(),
+	///     "9,223,372,036,854,775,807.50000000",
+	/// );
+	///
+	/// // Compare that with a float, which can't quite manage it:
+	/// assert_eq!(
+	///     NiceFloat::from(u64::MAX as f64 / 2.0).as_str(),
+	///     "9,223,372,036,854,775,808.00000000",
+	/// );
+	///
+	/// assert_eq!(NiceFloat::from_ratio(2, 3).as_str(), "0.66666667");
+	/// assert_eq!(NiceFloat::from_ratio(1, 0), NiceFloat::NAN);
+	/// ```
+	pub fn from_ratio(num: u64, den: u64) -> Self {
+		if den == 0 { return Self::NAN; }
+
+		let (top, bottom) = div_round(num, den, PRECISION);
+		if top == 0 && bottom == 0 { Self::ZERO }
+		else { Self::from(FloatKind::Normal(top, bottom, false)) }
+	}
+
+	#[must_use]
+	/// # From Ratio (128-bit).
+	///
+	/// This works just like [`NiceFloat::from_ratio`], but for `u128` operands,
+	/// using 128-bit integer long division to produce the (exact) decimal
+	/// digits, rounded tie-to-even at the eighth place.
+	///
+	/// As with other sources, quotients too large to fit within `u64` will
+	/// print as an overflow, and division by zero returns [`NiceFloat::NAN`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(
+	///     NiceFloat::from_ratio_u128(u128::MAX, u128::from(u64::MAX) * 2).as_str(),
+	///     "9,223,372,036,854,775,808.50000000",
+	/// );
+	///
+	/// // Compare that with a float, which can't quite manage it:
+	/// assert_eq!(
+	///     NiceFloat::from(u128::MAX as f64 / (u128::from(u64::MAX) * 2) as f64).as_str(),
+	///     "9,223,372,036,854,775,808.00000000",
+	/// );
+	///
+	/// assert_eq!(NiceFloat::from_ratio_u128(2, 3).as_str(), "0.66666667");
+	/// assert_eq!(NiceFloat::from_ratio_u128(u128::MAX, 1), NiceFloat::overflow(false));
+	/// assert_eq!(NiceFloat::from_ratio_u128(1, 0), NiceFloat::NAN);
+	/// ```
+	pub fn from_ratio_u128(num: u128, den: u128) -> Self {
+		if den == 0 { Self::NAN }
+		else { Self::ratio_u128(num, den, false) }
+	}
+
+	#[must_use]
+	/// # From Parts.
+	///
+	/// Create a new instance from a base-10 `mantissa` and `exponent`, i.e.
+	/// `mantissa × 10^exponent`, performing the scaling with integer
+	/// arithmetic so that decimal-type values can be formatted exactly,
+	/// without a detour through `f64`.
+	///
+	/// For `rust_decimal`-style values, which store a _scale_ instead, just
+	/// pass the negative of the scale as the exponent.
+	///
+	/// As with the float conversions, the fractional part is rounded to
+	/// eight places using a tie-to-even strategy, and integer parts too
+	/// large to fit within `u64` will print as an overflow.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::from_parts(12_345, -2).as_str(), "123.45000000");
+	/// assert_eq!(NiceFloat::from_parts(-5, 3).as_str(), "-5,000.00000000");
+	/// assert_eq!(NiceFloat::from_parts(123_456_789, -17).as_str(), "0.00000000");
+	/// assert_eq!(NiceFloat::from_parts(-123_456_789, -16).as_str(), "-0.00000001");
+	///
+	/// // Floats can't quite manage this one:
+	/// assert_eq!(
+	///     NiceFloat::from_parts(i64::MAX, -1).as_str(),
+	///     "922,337,203,685,477,580.70000000",
+	/// );
+	/// assert_eq!(
+	///     NiceFloat::from(i64::MAX as f64 / 10.0).as_str(),
+	///     "922,337,203,685,477,632.00000000",
+	/// );
+	///
+	/// // Too big!
+	/// assert_eq!(NiceFloat::from_parts(1, 20), NiceFloat::overflow(false));
+	/// ```
+	pub fn from_parts(mantissa: i64, exponent: i32) -> Self {
+		if mantissa == 0 { return Self::ZERO; }
+
+		let neg = mantissa < 0;
+		let num = u128::from(mantissa.unsigned_abs());
+		let scale = 10_u128.checked_pow(exponent.unsigned_abs());
+		if exponent < 0 {
+			// Anything divided by more than 10^38 rounds to zero.
+			scale.map_or(Self::ZERO, |den| Self::ratio_u128(num, den, neg))
+		}
+		else {
+			scale.and_then(|n| n.checked_mul(num)).map_or_else(
+				|| Self::overflow(neg),
+				|num| Self::ratio_u128(num, 1, neg),
+			)
+		}
+	}
+
+	/// # Ratio (128-bit, Signed).
+	///
+	/// This is the shared implementation for [`NiceFloat::from_ratio_u128`]
+	/// and [`NiceFloat::from_parts`]. The denominator must be non-zero.
+	fn ratio_u128(num: u128, den: u128, neg: bool) -> Self {
+		let (mut top, mut bottom, half) = long_div_u128(num, den, 8);
+		if matches!(half, Ordering::Greater) || (matches!(half, Ordering::Equal) && 1 == bottom & 1) {
+			bottom += 1;
+			if bottom == PRECISION {
+				bottom = 0;
+				top += 1;
+			}
+		}
+
+		match u64::try_from(top) {
+			Ok(0) if bottom == 0 => Self::ZERO,
+			Ok(top) => Self::from(FloatKind::Normal(top, bottom, neg)),
+			Err(_) => Self::overflow(neg),
+		}
+	}
+
+	#[must_use]
+	/// # Padded (Width-Stable).
+	///
+	/// Return a display-only [`NicePaddedFloat`] with the fractional part
+	/// truncated to `frac_width` places (like [`NiceFloat::precise_str`]) and
+	/// the integer part — including any sign and separators — left-padded
+	/// with spaces to at least `int_width` bytes, so that successive values
+	/// line up in a right-aligned column.
+	///
+	/// The fraction width is capped at `8`, and the integer width at whatever
+	/// room is left in the buffer. Integer parts already wider than
+	/// `int_width` are left as-is.
+	///
+	/// Special values (NaN, infinity, and overflows) have no fraction; they
+	/// are simply right-aligned within the full width.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::padded(1.5_f64, 6, 2).as_str(), "     1.50");
+	/// assert_eq!(NiceFloat::padded(-1234.567_f64, 6, 2).as_str(), "-1,234.56");
+	/// assert_eq!(NiceFloat::padded(12.0_f64, 6, 0).as_str(), "    12");
+	/// assert_eq!(NiceFloat::padded(f64::NAN, 6, 2).as_str(), "      NaN");
+	///
+	/// // Too-big integers just spill over.
+	/// assert_eq!(NiceFloat::padded(1_234_567.0_f64, 2, 1).as_str(), "1,234,567.0");
+	/// ```
+	pub fn padded(num: f64, int_width: usize, frac_width: usize) -> NicePaddedFloat {
+		let src = Self::from(num);
+		let frac_width = frac_width.min(8);
+		let tail = if frac_width == 0 { 0 } else { frac_width + 1 };
+		let int_width = int_width.min(SIZE - tail);
+
+		// Normal values get truncated and padded to the left of the dot;
+		// special values get padded to the left of everything.
+		let (bytes, int_len) =
+			if src.has_dot() {
+				let bytes = src.precise_bytes(frac_width);
+				(bytes, bytes.len() - tail)
+			}
+			else {
+				let bytes = src.as_bytes();
+				(bytes, bytes.len().saturating_sub(tail))
+			};
+
+		NicePaddedFloat::new(bytes, int_len.max(int_width) + tail)
+	}
+
+	#[must_use]
+	/// # Faithful.
+	///
+	/// This works just like [`NiceFloat::from`], except values that would
+	/// otherwise be indistinguishable from true zero are given special
+	/// markers:
+	///
+	/// * Non-zero values too small to show at eight decimal places print as `< 0.00000001` or `> -0.00000001`;
+	/// * Negative zero prints as `-0.00000000`.
+	///
+	/// This is useful for monitoring and the like, where tiny readings still
+	/// need to be told apart from nothing at all.
+	///
+	/// For `f32` values, use `f64::from` to upcast them first; the conversion
+	/// is lossless.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// // The default behavior.
+	/// assert_eq!(NiceFloat::from(0.000_000_001_f64).as_str(), "0.00000000");
+	/// assert_eq!(NiceFloat::from(-0.0_f64).as_str(), "0.00000000");
+	///
+	/// // The faithful behavior.
+	/// assert_eq!(NiceFloat::faithful(0.000_000_001).as_str(), "< 0.00000001");
+	/// assert_eq!(NiceFloat::faithful(-0.000_000_001).as_str(), "> -0.00000001");
+	/// assert_eq!(NiceFloat::faithful(-0.0).as_str(), "-0.00000000");
+	/// assert_eq!(NiceFloat::faithful(0.0).as_str(), "0.00000000");
+	///
+	/// // Everything else is the same.
+	/// assert_eq!(NiceFloat::faithful(-1.5).as_str(), "-1.50000000");
+	/// ```
+	pub fn faithful(num: f64) -> Self {
+		match FloatKind::from(num) {
+			// True zero.
+			FloatKind::Zero if num == 0.0 =>
+				if num.is_sign_negative() {
+					let mut out = Self::ZERO;
+					out.from -= 1;
+					out.inner[out.from] = b'-';
+					out
+				}
+				else { Self::ZERO },
+			// Not quite zero.
+			FloatKind::Zero => {
+				let mut out = Self::ZERO;
+				out.inner[SIZE - 1] = b'1';
+				let marker: &[u8] = if num.is_sign_negative() { b"> -" } else { b"< " };
+				out.from -= marker.len();
+				out.inner[out.from..out.from + marker.len()].copy_from_slice(marker);
+				out
+			},
+			kind => Self::from(kind),
+		}
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	#[must_use]
+	/// # From w/ Residual.
+	///
+	/// This works just like [`NiceFloat::from`], but also returns the part of
+	/// the value lost to rounding — `num` minus the value as printed — so it
+	/// can be carried forward, e.g. to keep a running total of displayed
+	/// values honest.
+	///
+	/// As with `From`, fractions are rounded to eight places, ties to even.
+	///
+	/// Values with no normal representation — `NaN`, infinity, and integer
+	/// overflows — have a residual of zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// let (nice, residual) = NiceFloat::from_with_residual(1.000_000_004);
+	/// assert_eq!(nice.as_str(), "1.00000000");
+	/// assert!((residual - 0.000_000_004).abs() < 1e-15);
+	///
+	/// let (nice, residual) = NiceFloat::from_with_residual(-0.000_000_016);
+	/// assert_eq!(nice.as_str(), "-0.00000002");
+	/// assert!((residual - 0.000_000_004).abs() < 1e-15);
+	///
+	/// // Exact values have nothing left over.
+	/// assert_eq!(NiceFloat::from_with_residual(2.5), (NiceFloat::from(2.5), 0.0));
+	/// ```
+	pub fn from_with_residual(num: f64) -> (Self, f64) {
+		let kind = FloatKind::from(num);
+		let residual = match kind {
+			// Everything was lost.
+			FloatKind::Zero => num,
+			// Subtracting the integer part is exact, leaving just the
+			// fraction to compare.
+			FloatKind::Normal(top, bottom, neg) => {
+				let diff = (num.abs() - top as f64) - f64::from(bottom) / f64::from(PRECISION);
+				if neg { -diff } else { diff }
+			},
+			_ => 0.0,
+		};
+		(Self::from(kind), residual)
+	}
 }
 
 impl NiceFloat {
@@ -438,9 +923,125 @@ impl NiceFloat {
 		// Safety: numbers are valid ASCII.
 		unsafe { std::str::from_utf8_unchecked(self.precise_bytes(precision)) }
 	}
+
+	#[inline]
+	#[must_use]
+	/// # Integer Bytes.
+	///
+	/// Return the integer half of the value — everything before the decimal
+	/// point, including any sign and thousands separators — as a byte slice.
+	///
+	/// Values without a fractional part, like NaN, infinity, and overflows,
+	/// are returned in full.
+	///
+	/// Together with [`NiceFloat::frac_bytes`], this makes it easy to line up
+	/// the decimal points of a column of numbers, regardless of which
+	/// separators are in use.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// let nice = NiceFloat::from(-12345.678_f64);
+	/// assert_eq!(nice.int_bytes(), b"-12,345");
+	/// assert_eq!(nice.frac_bytes(), b"67800000");
+	///
+	/// // Separators don't matter.
+	/// let nice = NiceFloat::eu(12345.678_f64);
+	/// assert_eq!(nice.int_bytes(), b"12.345");
+	/// assert_eq!(nice.frac_bytes(), b"67800000");
+	///
+	/// // Special values are all integer.
+	/// assert_eq!(NiceFloat::NAN.int_bytes(), b"NaN");
+	/// assert!(NiceFloat::NAN.frac_bytes().is_empty());
+	/// ```
+	pub fn int_bytes(&self) -> &[u8] {
+		if self.has_dot() { &self.inner[self.from..IDX_DOT] }
+		else { self.as_bytes() }
+	}
+
+	#[inline]
+	#[must_use]
+	/// # Fraction Bytes.
+	///
+	/// Return the fractional half of the value — the eight digits after the
+	/// decimal point — as a byte slice, or an empty slice if there isn't one.
+	///
+	/// See [`NiceFloat::int_bytes`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// let nice = NiceFloat::from(0.5_f64);
+	/// assert_eq!(nice.int_bytes(), b"0");
+	/// assert_eq!(nice.frac_bytes(), b"50000000");
+	///
+	/// assert!(NiceFloat::from(f64::MAX).frac_bytes().is_empty());
+	/// ```
+	pub fn frac_bytes(&self) -> &[u8] {
+		if self.has_dot() { &self.inner[IDX_DOT + 1..] }
+		else { &[] }
+	}
 }
 
 impl NiceFloat {
+	/// # Compare to Float.
+	///
+	/// This compares the value _as printed_ against `other`, after rounding
+	/// the latter to eight decimal places exactly as [`NiceFloat::from`]
+	/// would, so values are equal if and only if they would format the same.
+	///
+	/// NaN is unordered. Infinity — which is unsigned — is equal to either
+	/// infinity, but unordered against everything else. Bounded values, like
+	/// overflows and the tiny markers from [`NiceFloat::faithful`], are only
+	/// ordered when the answer is knowable from the bound.
+	fn cmp_f64(&self, other: f64) -> Option<Ordering> {
+		if other.is_nan() { return None; }
+		let bytes = self.as_bytes();
+		match bytes {
+			b"NaN" => return None,
+			[226, 136, 158] => return other.is_infinite().then_some(Ordering::Equal),
+			_ => {},
+		}
+
+		// Bounds, if any, come first.
+		let (bound, rest) = match bytes {
+			[b'<', b' ', rest @ ..] => (Some(Ordering::Less), rest),
+			[b'>', b' ', rest @ ..] => (Some(Ordering::Greater), rest),
+			_ => (None, bytes),
+		};
+
+		// Scale everything up by the precision to keep the math integral.
+		let mut value = rest.iter()
+			.filter(|b| b.is_ascii_digit())
+			.fold(0_i128, |acc, b| acc * 10 + i128::from(b - b'0'));
+		if ! self.has_dot() { value *= i128::from(PRECISION); }
+		if rest.first() == Some(&b'-') { value = -value; }
+
+		let other = match FloatKind::from(other) {
+			FloatKind::Normal(top, bottom, neg) => {
+				let abs = i128::from(top) * i128::from(PRECISION) + i128::from(bottom);
+				if neg { -abs } else { abs }
+			},
+			FloatKind::Zero => 0,
+			_ => if other.is_sign_negative() { i128::MIN } else { i128::MAX },
+		};
+
+		let cmp = value.cmp(&other);
+		match bound {
+			// The true value is less than the printed one.
+			Some(Ordering::Less) =>
+				if cmp.is_le() { Some(Ordering::Less) } else { None },
+			// The true value is greater than the printed one.
+			Some(Ordering::Greater) =>
+				if cmp.is_ge() { Some(Ordering::Greater) } else { None },
+			_ => Some(cmp),
+		}
+	}
+
 	/// # Has Dot?
 	///
 	/// This would be easy if we didn't allow customization, but, well, here we
@@ -502,6 +1103,11 @@ impl NiceFloat {
 				self.inner[self.from] = b'-';
 			}
 		}
+		// Negative fractions still need their sign; the zero is already there.
+		else if neg {
+			self.from = IDX_DOT - 2;
+			self.inner[self.from] = b'-';
+		}
 	}
 
 	#[expect(clippy::integer_division, reason = "We want this.")]
@@ -627,6 +1233,79 @@ impl From<f64> for FloatKind {
 	}
 }
 
+impl FloatKind {
+	#[must_use]
+	/// # Top (Integer Part).
+	///
+	/// Return the integer part of the value, if any.
+	///
+	/// Zero returns zero; `NaN`, overflows, and infinity return `None`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::FloatKind;
+	///
+	/// assert_eq!(FloatKind::from(-123.456_f64).top(), Some(123));
+	/// assert_eq!(FloatKind::from(0_f64).top(), Some(0));
+	/// assert_eq!(FloatKind::from(f64::MAX).top(), None);
+	/// ```
+	pub const fn top(&self) -> Option<u64> {
+		match self {
+			Self::Normal(top, _, _) => Some(*top),
+			Self::Zero => Some(0),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # Bottom (Fractional Part).
+	///
+	/// Return the fractional part of the value — up to eight digits, as an
+	/// integer — if any.
+	///
+	/// Zero returns zero; `NaN`, overflows, and infinity return `None`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::FloatKind;
+	///
+	/// assert_eq!(FloatKind::from(-123.456_f64).bottom(), Some(45_600_000));
+	/// assert_eq!(FloatKind::from(0_f64).bottom(), Some(0));
+	/// assert_eq!(FloatKind::from(f64::NAN).bottom(), None);
+	/// ```
+	pub const fn bottom(&self) -> Option<u32> {
+		match self {
+			Self::Normal(_, bottom, _) => Some(*bottom),
+			Self::Zero => Some(0),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	/// # Is Negative?
+	///
+	/// Returns `true` if the value is a negative normal or overflow.
+	///
+	/// Zero, `NaN`, and infinity are not differentiated by sign, so always
+	/// return `false`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::FloatKind;
+	///
+	/// assert!(FloatKind::from(-123.456_f64).is_negative());
+	/// assert!(FloatKind::from(f64::MIN).is_negative());
+	/// assert!(! FloatKind::from(123.456_f64).is_negative());
+	/// assert!(! FloatKind::from(f64::NEG_INFINITY).is_negative());
+	/// ```
+	pub const fn is_negative(&self) -> bool {
+		matches!(self, Self::Normal(_, _, true) | Self::Overflow(true))
+	}
+}
+
 
 
 #[expect(clippy::integer_division, reason = "We want this.")]
@@ -764,6 +1443,79 @@ const fn round_tie_even(offset: u128, tmp: u128) -> u32 {
 
 
 
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Divide and Round.
+///
+/// Divide `num` by `den`, returning the whole quotient and the fractional
+/// remainder expanded to `scale` (a power of ten), rounded tie-to-even.
+///
+/// The denominator must be non-zero.
+pub(super) const fn div_round(num: u64, den: u64, scale: u32) -> (u64, u32) {
+	let mut top = num / den;
+	let rem = (num % den) as u128 * scale as u128;
+	let den = den as u128;
+	let mut bottom = (rem / den) as u32;
+
+	// Round. (Without a fraction, evenness is decided by the whole part.)
+	let rem = rem % den;
+	let odd =
+		if scale == 1 { 1 == top & 1 }
+		else { 1 == bottom & 1 };
+	if den < rem * 2 || (den == rem * 2 && odd) {
+		bottom += 1;
+		if bottom == scale {
+			bottom = 0;
+			top += 1;
+		}
+	}
+
+	(top, bottom)
+}
+
+/// # Long Division (128-bit).
+///
+/// Divide `num` by `den` (which must be non-zero), returning the whole part,
+/// the first `places` decimal digits (as an integer, unrounded), and how the
+/// leftover remainder compares to _half_ of the next place, for rounding
+/// purposes.
+///
+/// The digits are worked out one at a time using modular addition, so there
+/// is no risk of overflow even with the largest operands.
+pub(super) const fn long_div_u128(num: u128, den: u128, places: u8) -> (u128, u32, Ordering) {
+	let top = num / den;
+	let mut rem = num % den;
+	let mut bottom = 0_u32;
+
+	let mut place = 0;
+	while place < places {
+		// Multiply the remainder by ten (modulo den), counting the wraps.
+		let mut digit = 0_u32;
+		let mut next = 0;
+		let mut i = 0;
+		while i < 10 {
+			if den - rem <= next {
+				next -= den - rem;
+				digit += 1;
+			}
+			else { next += rem; }
+			i += 1;
+		}
+
+		bottom = bottom * 10 + digit;
+		rem = next;
+		place += 1;
+	}
+
+	let half =
+		if rem < den - rem { Ordering::Less }
+		else if rem == den - rem { Ordering::Equal }
+		else { Ordering::Greater };
+
+	(top, bottom, half)
+}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -787,6 +1539,17 @@ mod tests {
 		assert_eq!(NiceFloat::from(1.03_f64).as_str(), "1.03000000");
 		assert_eq!(NiceFloat::from(1.020_202_020_2_f64).as_str(), "1.02020202");
 		assert_eq!(NiceFloat::from(-11_323.03_f64).as_str(), "-11,323.03000000");
+		assert_eq!(NiceFloat::from(-0.5_f64).as_str(), "-0.50000000");
+		assert_eq!(NiceFloat::with_nice_separator(-0.5_f64, NiceSeparator::Underscore, b',').as_str(), "-0,50000000");
+		assert_eq!(NiceFloat::with_nice_separator_f32(-0.5_f32, NiceSeparator::Underscore, b',').as_str(), "-0,50000000");
+		for num in [0.0, 1.1, -1.1, 0.333, 123_456.7, f32::MAX, f32::MIN, f32::NAN, f32::INFINITY] {
+			let nice = NiceFloat::from(num);
+			assert_eq!(NiceFloat::with_nice_separator_f32(num, NiceSeparator::Comma, b'.'), nice);
+			assert_eq!(
+				NiceFloat::with_nice_separator_f32(num, NiceSeparator::Underscore, b',').as_str(),
+				nice.as_str().replace(',', "_").replace('.', ","),
+			);
+		}
 
 		// Rounding.
 		assert_eq!(NiceFloat::from(0.123_456_789_f64).as_str(), "0.12345679");
@@ -803,12 +1566,166 @@ mod tests {
 		assert_eq!(NiceFloat::from(f64::INFINITY).as_str(), "∞");
 		assert_eq!(NiceFloat::from(f64::NEG_INFINITY).as_str(), "∞");
 		assert_eq!(NiceFloat::from(1.0e-308_f64).as_str(), "0.00000000");
+
+		// References and options.
+		assert_eq!(NiceFloat::from(&1.5_f32), NiceFloat::from(1.5_f32));
+		assert_eq!(NiceFloat::from(&-1.5_f64), NiceFloat::from(-1.5_f64));
+		assert_eq!(NiceFloat::from(Some(&1.5_f32)), NiceFloat::from(1.5_f32));
+		assert_eq!(NiceFloat::from(Some(2.5_f64)), NiceFloat::from(2.5_f64));
+		assert_eq!(NiceFloat::from(None::<&f64>), NiceFloat::ZERO);
+	}
+
+	#[test]
+	fn t_from_ratio() {
+		// Exact values should match their float equivalents.
+		for (num, den) in [(0_u32, 1_u32), (1, 1), (1, 2), (3, 8), (123_456, 1000), (7, 4)] {
+			assert_eq!(
+				NiceFloat::from_ratio(u64::from(num), u64::from(den)),
+				NiceFloat::from(f64::from(num) / f64::from(den)),
+				"{num}/{den}",
+			);
+		}
+
+		// Rounding.
+		assert_eq!(NiceFloat::from_ratio(1, 3).as_str(), "0.33333333");
+		assert_eq!(NiceFloat::from_ratio(5, 9).as_str(), "0.55555556");
+		assert_eq!(NiceFloat::from_ratio(1, 200_000_000).as_str(), "0.00000000"); // Tie to even (0).
+		assert_eq!(NiceFloat::from_ratio(3, 200_000_000).as_str(), "0.00000002"); // Tie to even (2).
+		assert_eq!(NiceFloat::from_ratio(999_999_999, 1_000_000_000).as_str(), "1.00000000");
+		assert_eq!(NiceFloat::from_ratio(u64::MAX - 1, u64::MAX).as_str(), "1.00000000");
+
+		// Big and weird.
+		assert_eq!(NiceFloat::from_ratio(u64::MAX, 1).as_str(), "18,446,744,073,709,551,615.00000000");
+		assert_eq!(NiceFloat::from_ratio(0, u64::MAX), NiceFloat::ZERO);
+		assert_eq!(NiceFloat::from_ratio(0, 0), NiceFloat::NAN);
+	}
+
+	#[test]
+	fn t_from_ratio_u128() {
+		// Within the u64 range, this should match the regular version.
+		let mut rng1 = fastrand::Rng::new();
+		let mut rng2 = fastrand::Rng::new();
+		for (num, den) in [(0, 1), (1, 1), (u64::MAX, 1), (u64::MAX, u64::MAX), (1, u64::MAX)].into_iter()
+			.chain(std::iter::repeat_with(|| (rng1.u64(..), rng1.u64(1..))).take(5000))
+			.chain(std::iter::repeat_with(|| (rng2.u64(..1000), rng2.u64(1..1000))).take(5000))
+		{
+			assert_eq!(
+				NiceFloat::from_ratio_u128(u128::from(num), u128::from(den)),
+				NiceFloat::from_ratio(num, den),
+				"{num}/{den}",
+			);
+		}
+
+		// Scaling both sides shouldn't change anything either, even when it
+		// pushes the operands way beyond what floats can handle.
+		for (num, den) in std::iter::repeat_with(|| (rng1.u64(..), rng1.u64(1..))).take(5000) {
+			let scale = u128::from(u64::MAX);
+			assert_eq!(
+				NiceFloat::from_ratio_u128(u128::from(num) * scale, u128::from(den) * scale),
+				NiceFloat::from_ratio(num, den),
+				"{num}/{den} * {scale}",
+			);
+		}
+
+		// Some big ones.
+		assert_eq!(NiceFloat::from_ratio_u128(u128::MAX, u128::MAX).as_str(), "1.00000000");
+		assert_eq!(NiceFloat::from_ratio_u128(u128::MAX - 1, u128::MAX).as_str(), "1.00000000");
+		assert_eq!(NiceFloat::from_ratio_u128(1, u128::MAX), NiceFloat::ZERO);
+		assert_eq!(
+			NiceFloat::from_ratio_u128(u128::from(u64::MAX) << 64, u128::from(u64::MAX) << 1).as_str(),
+			"9,223,372,036,854,775,808.00000000",
+		);
+		assert_eq!(
+			NiceFloat::from_ratio_u128(u128::from(u64::MAX) + 1, 1),
+			NiceFloat::overflow(false),
+		);
+
+		// Long division should be exact.
+		assert_eq!(long_div_u128(u128::MAX, 3, 4), (u128::MAX / 3, 0, Ordering::Less));
+		assert_eq!(long_div_u128(u128::MAX, u128::MAX - 1, 3), (1, 0, Ordering::Less));
+		assert_eq!(long_div_u128(1, 8, 2), (0, 12, Ordering::Equal));
+		assert_eq!(long_div_u128(2, 3, 2), (0, 66, Ordering::Greater));
+	}
+
+	#[test]
+	fn t_from_parts() {
+		// Should match the ratio equivalent.
+		let mut rng = fastrand::Rng::new();
+		for (m, e) in std::iter::repeat_with(|| (rng.i64(..), rng.i32(-40..=40))).take(10_000) {
+			let expected =
+				if m == 0 { NiceFloat::ZERO }
+				else if e < 0 {
+					10_u128.checked_pow(e.unsigned_abs()).map_or(
+						NiceFloat::ZERO,
+						|den| NiceFloat::ratio_u128(u128::from(m.unsigned_abs()), den, m < 0),
+					)
+				}
+				else {
+					10_u128.checked_pow(e.unsigned_abs())
+						.and_then(|n| n.checked_mul(u128::from(m.unsigned_abs())))
+						.and_then(|n| u64::try_from(n).ok())
+						.map_or_else(
+							|| NiceFloat::overflow(m < 0),
+							|n| NiceFloat::from(FloatKind::Normal(n, 0, m < 0)),
+						)
+				};
+			assert_eq!(NiceFloat::from_parts(m, e), expected, "{m}e{e}");
+		}
+
+		// Exact values should match their float equivalents.
+		for (m, e, f) in [
+			(1, 0, 1.0), (-1, 0, -1.0), (125, -3, 0.125), (-75, -2, -0.75),
+			(123_456, -3, 123.456), (5, 2, 500.0), (-123_456_789, -8, -1.234_567_89),
+		] {
+			assert_eq!(NiceFloat::from_parts(m, e), NiceFloat::from(f), "{m}e{e}");
+		}
+
+		// Rounding.
+		assert_eq!(NiceFloat::from_parts(5, -9).as_str(), "0.00000000"); // Tie to even (0).
+		assert_eq!(NiceFloat::from_parts(-15, -9).as_str(), "-0.00000002"); // Tie to even (2).
+		assert_eq!(NiceFloat::from_parts(999_999_999, -9).as_str(), "1.00000000");
+
+		// Edges.
+		assert_eq!(NiceFloat::from_parts(0, i32::MAX), NiceFloat::ZERO);
+		assert_eq!(NiceFloat::from_parts(i64::MIN, i32::MIN), NiceFloat::ZERO);
+		assert_eq!(NiceFloat::from_parts(i64::MIN, i32::MAX), NiceFloat::overflow(true));
+		assert_eq!(NiceFloat::from_parts(-1, -8).as_str(), "-0.00000001");
+		assert_eq!(NiceFloat::from_parts(-1, -9), NiceFloat::ZERO);
+		assert_eq!(
+			NiceFloat::from_parts(i64::MIN, 0).as_str(),
+			"-9,223,372,036,854,775,808.00000000",
+		);
+	}
+
+	#[test]
+	fn t_from_with_residual() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| (rng.f64() - 0.5) * f64::from(rng.u32(..))).take(10_000) {
+			let (nice, residual) = NiceFloat::from_with_residual(num);
+			assert_eq!(nice, NiceFloat::from(num));
+
+			// The residual can't be more than half of the last place (give or
+			// take the float's own precision).
+			assert!(residual.abs() <= num.abs().mul_add(f64::EPSILON, 0.000_000_005), "{num}: {residual}");
+
+			// And should add back up to the original.
+			let printed: f64 = nice.as_str().replace(',', "").parse().unwrap();
+			assert!((printed + residual - num).abs() <= (num.abs() * 4.0).max(1.0) * f64::EPSILON, "{num}");
+		}
+
+		// Special cases.
+		assert_eq!(NiceFloat::from_with_residual(0.0), (NiceFloat::ZERO, 0.0));
+		assert_eq!(NiceFloat::from_with_residual(1e-10), (NiceFloat::ZERO, 1e-10));
+		assert_eq!(NiceFloat::from_with_residual(f64::NAN), (NiceFloat::NAN, 0.0));
+		assert_eq!(NiceFloat::from_with_residual(f64::INFINITY), (NiceFloat::INFINITY, 0.0));
+		assert_eq!(NiceFloat::from_with_residual(f64::MIN), (NiceFloat::overflow(true), 0.0));
+		assert_eq!(NiceFloat::from_with_residual(1e15), (NiceFloat::from(1e15), 0.0));
 	}
 
 	#[test]
 	fn t_compact() {
 		assert_eq!(NiceFloat::from(0_f64).compact_str(), "0");
-		assert_eq!(NiceFloat::with_separator(0_f64, b'0', b'0').compact_str(), "0");
+		assert_eq!(NiceFloat::with_nice_separator(0_f64, NiceSeparator::Underscore, b'0').compact_str(), "0");
 		assert_eq!(NiceFloat::from(0.010_200_3_f64).compact_str(), "0.0102003");
 		assert_eq!(NiceFloat::from(0.000_000_01_f64).compact_str(), "0.00000001");
 		assert_eq!(NiceFloat::from(0.000_000_001_f64).compact_str(), "0");
@@ -816,12 +1733,12 @@ mod tests {
 		// A few weird ones.
 		assert_eq!(NiceFloat::from(f64::NAN).compact_str(), "NaN");
 		assert_eq!(NiceFloat::from(f64::INFINITY).compact_str(), "∞");
-		assert_eq!(NiceFloat::with_separator(f64::NAN, b'-', b'_').compact_str(), "NaN");
-		assert_eq!(NiceFloat::with_separator(f64::INFINITY, b'-', b'_').compact_str(), "∞");
+		assert_eq!(NiceFloat::with_nice_separator(f64::NAN, NiceSeparator::Underscore, b'_').compact_str(), "NaN");
+		assert_eq!(NiceFloat::with_nice_separator(f64::INFINITY, NiceSeparator::Underscore, b'_').compact_str(), "∞");
 		assert_eq!(NiceFloat::overflow(true).compact_str(), "< -18,446,744,073,709,551,615");
 		assert_eq!(NiceFloat::overflow(false).compact_str(), "> 18,446,744,073,709,551,615");
-		assert_eq!(NiceFloat::with_separator(f64::MIN, b'!', b'?').compact_str(), "< -18!446!744!073!709!551!615");
-		assert_eq!(NiceFloat::with_separator(f64::MAX, b'!', b'?').compact_str(), "> 18!446!744!073!709!551!615");
+		assert_eq!(NiceFloat::with_nice_separator(f64::MIN, NiceSeparator::Apostrophe, b'?').compact_str(), "< -18'446'744'073'709'551'615");
+		assert_eq!(NiceFloat::with_nice_separator(f64::MAX, NiceSeparator::Apostrophe, b'?').compact_str(), "> 18'446'744'073'709'551'615");
 	}
 
 	#[test]
@@ -836,8 +1753,102 @@ mod tests {
 		assert_eq!(NiceFloat::INFINITY.precise_str(3), "∞");
 		assert_eq!(NiceFloat::overflow(true).precise_str(3), "< -18,446,744,073,709,551,615");
 		assert_eq!(NiceFloat::overflow(false).precise_str(3), "> 18,446,744,073,709,551,615");
-		assert_eq!(NiceFloat::with_separator(f64::MIN, b'!', b'?').precise_str(3), "< -18!446!744!073!709!551!615");
-		assert_eq!(NiceFloat::with_separator(f64::MAX, b'!', b'?').precise_str(3), "> 18!446!744!073!709!551!615");
+		assert_eq!(NiceFloat::with_nice_separator(f64::MIN, NiceSeparator::Apostrophe, b'?').precise_str(3), "< -18'446'744'073'709'551'615");
+		assert_eq!(NiceFloat::with_nice_separator(f64::MAX, NiceSeparator::Apostrophe, b'?').precise_str(3), "> 18'446'744'073'709'551'615");
+	}
+
+	#[test]
+	fn t_int_frac() {
+		// The halves should add up to the whole.
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| (rng.f64() - 0.5) * 10_f64.powi(rng.i32(-9..20))).take(10_000) {
+			for (nice, point) in [
+				(NiceFloat::from(num), b'.'),
+				(NiceFloat::eu(num), b','),
+				(NiceFloat::with_nice_separator(num, NiceSeparator::Underscore, b'!'), b'!'),
+			] {
+				let bytes = nice.as_bytes();
+				if nice.frac_bytes().is_empty() {
+					assert_eq!(nice.int_bytes(), bytes, "{num}");
+				}
+				else {
+					assert_eq!(nice.frac_bytes().len(), 8, "{num}");
+					assert_eq!(
+						[nice.int_bytes(), &[point], nice.frac_bytes()].concat(),
+						bytes,
+						"{num}",
+					);
+					assert_eq!(nice.int_bytes(), nice.precise_bytes(0), "{num}");
+				}
+			}
+		}
+
+		// A few weird ones.
+		for nice in [
+			NiceFloat::NAN,
+			NiceFloat::INFINITY,
+			NiceFloat::overflow(true),
+			NiceFloat::overflow(false),
+			NiceFloat::with_nice_separator(f64::MIN, NiceSeparator::Apostrophe, b'?'),
+		] {
+			assert_eq!(nice.int_bytes(), nice.as_bytes());
+			assert!(nice.frac_bytes().is_empty());
+		}
+
+		assert_eq!(NiceFloat::ZERO.int_bytes(), b"0");
+		assert_eq!(NiceFloat::ZERO.frac_bytes(), b"00000000");
+		assert_eq!(NiceFloat::faithful(-0.0).int_bytes(), b"-0");
+	}
+
+	#[test]
+	fn t_padded() {
+		for (num, int_width, frac_width, expected) in [
+			(0_f64, 4, 2, "   0.00"),
+			(0_f64, 0, 0, "0"),
+			(1.5, 4, 9, "   1.50000000"),
+			(-0.25, 4, 1, "  -0.2"),
+			(-11_323.03, 4, 2, "-11,323.03"),
+			(-11_323.03, 10, 2, "   -11,323.03"),
+			(-11_323.03, 100, 8, "                    -11,323.03000000"),
+			(f64::INFINITY, 3, 2, "   ∞"), // Widths are in bytes.
+			(f64::MAX, 3, 2, "> 18,446,744,073,709,551,615"),
+		] {
+			let nice = NiceFloat::padded(num, int_width, frac_width);
+			assert_eq!(nice.as_str(), expected, "{num} ({int_width}, {frac_width})");
+		}
+
+		// Column widths should be stable.
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.f64().mul_add(200_000.0, -100_000.0)).take(1000) {
+			let nice = NiceFloat::padded(num, 8, 3);
+			assert_eq!(nice.len(), 12, "{num}");
+			assert_eq!(nice.as_str().trim_start(), NiceFloat::from(num).precise_str(3));
+		}
+	}
+
+	#[test]
+	fn t_faithful() {
+		// Normal values should be unaffected.
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.f64() * f64::from(rng.i32(..))).take(1000)
+			.chain([1.0, -1.0, 0.000_000_01, -0.000_000_01, f64::MAX, f64::MIN, f64::NAN, f64::INFINITY])
+		{
+			if num != 0.0 && NiceFloat::from(num) != NiceFloat::ZERO {
+				assert_eq!(NiceFloat::faithful(num), NiceFloat::from(num));
+			}
+		}
+
+		// Tiny values get markers.
+		for num in [0.000_000_004_f64, f64::MIN_POSITIVE, f64::from(f32::MIN_POSITIVE), 1e-320] {
+			assert_eq!(NiceFloat::from(num), NiceFloat::ZERO);
+			assert_eq!(NiceFloat::faithful(num).as_str(), "< 0.00000001");
+			assert_eq!(NiceFloat::faithful(-num).as_str(), "> -0.00000001");
+		}
+
+		// Zeroes.
+		assert_eq!(NiceFloat::faithful(0.0), NiceFloat::ZERO);
+		assert_eq!(NiceFloat::faithful(-0.0).as_str(), "-0.00000000");
+		assert_eq!(NiceFloat::faithful(-0.0).compact_str(), "-0");
 	}
 
 	#[test]
@@ -845,13 +1856,64 @@ mod tests {
 		// Basic things should have dots.
 		assert!(NiceFloat::from(0_f64).has_dot());
 		assert!(NiceFloat::from(1.234_f64).has_dot());
-		assert!(NiceFloat::with_separator(1.234_f64, b'!', b'?').has_dot());
+		assert!(NiceFloat::with_nice_separator(1.234_f64, NiceSeparator::Apostrophe, b'?').has_dot());
 
 		assert!(! NiceFloat::NAN.has_dot());
 		assert!(! NiceFloat::INFINITY.has_dot());
 		assert!(! NiceFloat::overflow(true).has_dot());
 		assert!(! NiceFloat::overflow(false).has_dot());
-		assert!(! NiceFloat::with_separator(f64::MIN, b'!', b'?').has_dot());
-		assert!(! NiceFloat::with_separator(f64::MAX, b'!', b'?').has_dot());
+		assert!(! NiceFloat::with_nice_separator(f64::MIN, NiceSeparator::Apostrophe, b'?').has_dot());
+		assert!(! NiceFloat::with_nice_separator(f64::MAX, NiceSeparator::Apostrophe, b'?').has_dot());
+	}
+
+	#[test]
+	fn t_cmp_f64() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..10_000 {
+			let a = rng.f64() * f64::from(rng.i32(..));
+			let b = rng.f64() * f64::from(rng.i32(..));
+			let nice = NiceFloat::from(a);
+
+			// A value should always equal itself.
+			assert!(nice == a, "{a}");
+			assert_eq!(a.partial_cmp(&nice), Some(Ordering::Equal));
+			assert_eq!(nice.partial_cmp(&a), Some(Ordering::Equal));
+
+			// Other values should order like their formatted equivalents.
+			let expected =
+				if NiceFloat::from(b) == nice { Ordering::Equal }
+				else { a.total_cmp(&b) };
+			assert_eq!(nice.partial_cmp(&b), Some(expected), "{a} {b}");
+			assert_eq!(b.partial_cmp(&nice), Some(expected.reverse()), "{a} {b}");
+		}
+
+		// Rounding.
+		assert!(NiceFloat::from(1.0_f64) == 1.000_000_004);
+		assert!(NiceFloat::from(1.0_f64) < 1.000_000_005_1);
+		assert!(NiceFloat::from(-1.0_f64) > -1.000_000_005_1);
+
+		// Special values.
+		assert_eq!(NiceFloat::NAN.partial_cmp(&0.0), None);
+		assert_eq!(NiceFloat::ZERO.partial_cmp(&f64::NAN), None);
+		assert!(NiceFloat::INFINITY == f64::INFINITY);
+		assert!(NiceFloat::INFINITY == f64::NEG_INFINITY);
+		assert_eq!(NiceFloat::INFINITY.partial_cmp(&0.0), None);
+		assert!(NiceFloat::ZERO < f64::INFINITY);
+		assert!(NiceFloat::ZERO > f64::NEG_INFINITY);
+		assert!(NiceFloat::ZERO == -0.0);
+
+		// Bounds.
+		let over = NiceFloat::overflow(false);
+		assert!(over > 9e18);
+		assert_eq!(over.partial_cmp(&f64::MAX), None);
+		assert!(NiceFloat::overflow(true) < 0.0);
+		assert_eq!(NiceFloat::overflow(true).partial_cmp(&f64::MIN), None);
+
+		let tiny = NiceFloat::faithful(1e-10);
+		assert!(tiny < 0.000_000_01);
+		assert_eq!(tiny.partial_cmp(&0.0), None);
+		let tiny = NiceFloat::faithful(-1e-10);
+		assert!(tiny > -0.000_000_01);
+		assert_eq!(tiny.partial_cmp(&0.0), None);
 	}
 }
@@ -0,0 +1,197 @@
+/*!
+# Dactyl: Nice Hex.
+*/
+
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// A `u128` has at most thirty-two hex digits.
+const SIZE: usize = 32;
+
+/// # Lowercase Digits.
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+
+/// # Uppercase Digits.
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Hex.
+///
+/// This struct holds the (unpadded, unprefixed) hexadecimal representation
+/// of an unsigned integer, like `ff` or `DEADBEEF`.
+///
+/// It is the formatting counterpart to [`HexToUnsigned`](crate::traits::HexToUnsigned);
+/// the output can always be decoded back to the original value by the same
+/// type's `htou`.
+///
+/// Instances are usually created using the [`UnsignedToHex`](crate::traits::UnsignedToHex)
+/// trait methods, but `From` works too, producing lowercase output.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{
+///     NiceHex,
+///     traits::{HexToUnsigned, UnsignedToHex},
+/// };
+///
+/// assert_eq!(NiceHex::from(255_u8).as_str(), "ff");
+/// assert_eq!(0xdead_beef_u32.to_hex_upper().as_str(), "DEADBEEF");
+/// assert_eq!(0_u64.to_hex_lower().as_str(), "0");
+///
+/// // Round trip!
+/// let num = 1_234_567_890_u64;
+/// assert_eq!(u64::htou(num.to_hex_lower().as_bytes()), Some(num));
+/// ```
+pub struct NiceHex {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NiceHex {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceHex {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceHex {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceHex {
+	#[inline]
+	fn default() -> Self { Self::ZERO }
+}
+
+impl Deref for NiceHex {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceHex {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceHex")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceHex {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceHex {}
+
+/// # Helper: From Unsigned.
+macro_rules! from {
+	($($ty:ty),+) => ($(
+		impl From<$ty> for NiceHex {
+			#[inline]
+			fn from(num: $ty) -> Self { Self::new(u128::from(num), false) }
+		}
+	)+);
+}
+
+from!(u8, u16, u32, u64, u128);
+
+impl From<usize> for NiceHex {
+	#[inline]
+	fn from(num: usize) -> Self { Self::new(num as u128, false) }
+}
+
+impl hash::Hash for NiceHex {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceHex {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceHex {
+	/// # Zero.
+	///
+	/// ```
+	/// use dactyl::NiceHex;
+	///
+	/// assert_eq!(NiceHex::ZERO.as_str(), "0");
+	/// assert_eq!(NiceHex::ZERO, NiceHex::from(0_u8));
+	/// ```
+	pub const ZERO: Self = Self {
+		inner: [b'0'; SIZE],
+		from: SIZE - 1,
+	};
+
+	#[must_use]
+	/// # New.
+	///
+	/// Encode `num`, using uppercase letters if `upper` is `true`.
+	pub(crate) const fn new(mut num: u128, upper: bool) -> Self {
+		let table = if upper { UPPER } else { LOWER };
+		let mut out = Self { inner: [b'0'; SIZE], from: SIZE };
+		loop {
+			out.from -= 1;
+			out.inner[out.from] = table[(num & 15) as usize];
+			num >>= 4;
+			if num == 0 { break; }
+		}
+		out
+	}
+}
+
+impl NiceHex {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceHex;
+	///
+	/// assert_eq!(NiceHex::from(4096_u16).as_bytes(), b"1000");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceHex;
+	///
+	/// assert_eq!(NiceHex::from(4096_u16).as_str(), "1000");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+}
@@ -0,0 +1,426 @@
+/*!
+# Dactyl: Nice Money.
+*/
+
+use crate::NiceFixed;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Maximum Symbol Length (in bytes).
+const MAX_SYMBOL: usize = 4;
+
+/// # Total Buffer Size.
+///
+/// `(` + four-byte symbol + `9,223,372,036,854,775,808` + `)` = 31 bytes.
+const SIZE: usize = 31;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Money.
+///
+/// This struct builds on [`NiceFixed`] to provide ledger-style currency
+/// formatting: a currency symbol — before or after the number — and,
+/// optionally, accounting-style parentheses for negative values.
+///
+/// Symbols may be any string up to four bytes in length, e.g. `$`, `€`, or
+/// `US$ `. (Include any desired spacing in the symbol itself.)
+///
+/// By default, the symbol is `$`, placed before the number, and negative
+/// values are prefixed with a minus sign.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceFixed, NiceMoney, NiceSeparator};
+///
+/// assert_eq!(NiceMoney::from_scaled(123_456, 2).as_str(), "$1,234.56");
+/// assert_eq!(NiceMoney::from_scaled(-123_456, 2).as_str(), "-$1,234.56");
+///
+/// // Accounting style.
+/// let money = NiceMoney::from_scaled(-123_456, 2).with_parentheses(true);
+/// assert_eq!(money.as_str(), "($1,234.56)");
+///
+/// // European style.
+/// let money = NiceMoney::from(NiceFixed::with_separator(-123_456, 2, NiceSeparator::Period, b','))
+///     .with_suffix(" €");
+/// assert_eq!(money.as_str(), "-1.234,56 €");
+///
+/// // Update the value in place; everything else is preserved.
+/// let mut money = NiceMoney::from_scaled(0, 2).with_parentheses(true);
+/// assert_eq!(money.as_str(), "$0.00");
+///
+/// money.replace(-5);
+/// assert_eq!(money.as_str(), "($0.05)");
+/// ```
+pub struct NiceMoney {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Length.
+	len: usize,
+
+	/// # Number.
+	fixed: NiceFixed,
+
+	/// # Symbol.
+	symbol: [u8; MAX_SYMBOL],
+
+	/// # Symbol Length.
+	symbol_len: usize,
+
+	/// # Symbol After Number?
+	suffix: bool,
+
+	/// # Parenthesize Negatives?
+	parens: bool,
+}
+
+impl AsRef<[u8]> for NiceMoney {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceMoney {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceMoney {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceMoney {
+	#[inline]
+	fn default() -> Self { Self::from_scaled(0, 2) }
+}
+
+impl Deref for NiceMoney {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+#[expect(clippy::missing_fields_in_debug, reason = "Buffers are internal.")]
+impl fmt::Debug for NiceMoney {
+	/// # Debug.
+	///
+	/// The alternate form — `{:#?}` — includes the raw (scaled) value and
+	/// scale alongside the string.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			f.debug_struct("NiceMoney")
+				.field("value", &self.fixed.value())
+				.field("scale", &self.fixed.scale())
+				.field("nice", &self.as_str())
+				.finish()
+		}
+		else {
+			f.debug_tuple("NiceMoney")
+				.field(&self.as_str())
+				.finish()
+		}
+	}
+}
+
+impl fmt::Display for NiceMoney {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceMoney {}
+
+impl From<NiceFixed> for NiceMoney {
+	#[inline]
+	fn from(fixed: NiceFixed) -> Self {
+		let mut out = Self {
+			inner: [0; SIZE],
+			len: 0,
+			fixed,
+			symbol: [b'$', 0, 0, 0],
+			symbol_len: 1,
+			suffix: false,
+			parens: false,
+		};
+		out.render();
+		out
+	}
+}
+
+impl hash::Hash for NiceMoney {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceMoney {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceMoney {
+	#[must_use]
+	/// # From Scaled.
+	///
+	/// Create a new instance from an integer `num` with `scale` implied
+	/// decimal places — see [`NiceFixed::from_scaled`] — using the default
+	/// `$` prefix.
+	///
+	/// For custom separators and decimal points, create a [`NiceFixed`]
+	/// first, and convert that instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// assert_eq!(NiceMoney::from_scaled(99, 2).as_str(), "$0.99");
+	/// assert_eq!(NiceMoney::from_scaled(1_000_000, 0).as_str(), "$1,000,000");
+	/// ```
+	pub fn from_scaled(num: i64, scale: u8) -> Self {
+		Self::from(NiceFixed::from_scaled(num, scale))
+	}
+
+	#[must_use]
+	/// # With Prefix Symbol.
+	///
+	/// Place the given currency symbol before the number.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// let money = NiceMoney::from_scaled(-1999, 2).with_prefix("£");
+	/// assert_eq!(money.as_str(), "-£19.99");
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the symbol is longer than four bytes.
+	pub fn with_prefix(mut self, symbol: &str) -> Self {
+		self.set_symbol(symbol, false);
+		self
+	}
+
+	#[must_use]
+	/// # With Suffix Symbol.
+	///
+	/// Place the given currency symbol after the number.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// let money = NiceMoney::from_scaled(1999, 2).with_suffix(" kr");
+	/// assert_eq!(money.as_str(), "19.99 kr");
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the symbol is longer than four bytes.
+	pub fn with_suffix(mut self, symbol: &str) -> Self {
+		self.set_symbol(symbol, true);
+		self
+	}
+
+	#[must_use]
+	/// # With Parentheses.
+	///
+	/// Enable or disable accounting-style parentheses for negative values.
+	/// When enabled, negative values are wrapped — symbol and all — in
+	/// parentheses instead of being prefixed with a minus sign.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// let money = NiceMoney::from_scaled(-1999, 2).with_parentheses(true);
+	/// assert_eq!(money.as_str(), "($19.99)");
+	///
+	/// let money = money.with_parentheses(false);
+	/// assert_eq!(money.as_str(), "-$19.99");
+	/// ```
+	pub fn with_parentheses(mut self, parens: bool) -> Self {
+		self.parens = parens;
+		self.render();
+		self
+	}
+
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new value, keeping
+	/// the original scale, punctuation, symbol, and negative style.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// let mut money = NiceMoney::from_scaled(1, 2).with_suffix("€");
+	/// assert_eq!(money.as_str(), "0.01€");
+	///
+	/// money.replace(-123_456_789);
+	/// assert_eq!(money.as_str(), "-1,234,567.89€");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(money.replace(250).as_str(), "2.50€");
+	/// ```
+	pub fn replace(&mut self, num: i64) -> &mut Self {
+		self.fixed.replace(num);
+		self.render();
+		self
+	}
+
+	/// # Set Symbol.
+	///
+	/// Set the symbol and its position, then re-render.
+	fn set_symbol(&mut self, symbol: &str, suffix: bool) {
+		let symbol = symbol.as_bytes();
+		assert!(symbol.len() <= MAX_SYMBOL, "Symbols are limited to four bytes.");
+		self.symbol[..symbol.len()].copy_from_slice(symbol);
+		self.symbol_len = symbol.len();
+		self.suffix = suffix;
+		self.render();
+	}
+
+	/// # Render.
+	///
+	/// (Re)build the formatted output from the parts.
+	fn render(&mut self) {
+		let fixed = self.fixed;
+		let (neg, num) = match fixed.as_bytes() {
+			[b'-', rest @ ..] => (true, rest),
+			all => (false, all),
+		};
+		let symbol = self.symbol;
+		let symbol = &symbol[..self.symbol_len];
+		let mut len = 0;
+
+		let mut push = |src: &[u8]| {
+			self.inner[len..len + src.len()].copy_from_slice(src);
+			len += src.len();
+		};
+
+		if neg { push(if self.parens { b"(" } else { b"-" }); }
+		if ! self.suffix { push(symbol); }
+		push(num);
+		if self.suffix { push(symbol); }
+		if neg && self.parens { push(b")"); }
+
+		self.len = len;
+	}
+}
+
+impl NiceMoney {
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// assert_eq!(NiceMoney::from_scaled(12_345, 2).as_bytes(), b"$123.45");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[..self.len] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceMoney;
+	///
+	/// assert_eq!(NiceMoney::from_scaled(12_345, 2).as_str(), "$123.45");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: the symbol came from a string, and everything else is
+		// ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_nice_money() {
+		let mut rng = fastrand::Rng::new();
+		for num in [0, 1, -1, i64::MIN, i64::MAX].into_iter()
+			.chain(std::iter::repeat_with(|| rng.i64(..)).take(1000))
+		{
+			let fixed = NiceFixed::from_scaled(num, 2);
+			let abs = fixed.as_str().trim_start_matches('-');
+
+			let money = NiceMoney::from(fixed);
+			let prefix = money.with_prefix("US$ ");
+			let suffix = money.with_suffix(" €");
+			let parens = money.with_parentheses(true);
+			let both = suffix.with_parentheses(true);
+
+			if num < 0 {
+				assert_eq!(money.as_str(), format!("-${abs}"));
+				assert_eq!(prefix.as_str(), format!("-US$ {abs}"));
+				assert_eq!(suffix.as_str(), format!("-{abs} €"));
+				assert_eq!(parens.as_str(), format!("(${abs})"));
+				assert_eq!(both.as_str(), format!("({abs} €)"));
+			}
+			else {
+				assert_eq!(money.as_str(), format!("${abs}"));
+				assert_eq!(prefix.as_str(), format!("US$ {abs}"));
+				assert_eq!(suffix.as_str(), format!("{abs} €"));
+				assert_eq!(parens, money);
+				assert_eq!(both, suffix);
+			}
+
+			// Replacement should preserve the settings.
+			let mut last = NiceMoney::from_scaled(i64::MIN, 2).with_suffix(" €").with_parentheses(true);
+			last.replace(num);
+			assert_eq!(last, both);
+		}
+
+		assert_eq!(NiceMoney::default().as_str(), "$0.00");
+	}
+
+	#[test]
+	#[should_panic(expected = "Symbols are limited to four bytes.")]
+	fn t_nice_money_symbol() { let _res = NiceMoney::default().with_prefix("Dollars"); }
+
+	#[test]
+	fn t_debug() {
+		let money = NiceMoney::from_scaled(-123_456, 2).with_suffix(" €");
+		assert_eq!(format!("{money:?}"), "NiceMoney(\"-1,234.56 €\")");
+		assert_eq!(
+			format!("{money:#?}"),
+			"NiceMoney {\n    value: -123456,\n    scale: 2,\n    nice: \"-1,234.56 €\",\n}",
+		);
+
+		for num in [0, -1, i64::MIN, i64::MAX] {
+			let fixed = NiceFixed::from_scaled(num, 3);
+			assert_eq!(
+				format!("{fixed:#?}"),
+				format!("NiceFixed {{\n    value: {num},\n    scale: 3,\n    nice: {:?},\n}}", fixed.as_str()),
+			);
+		}
+	}
+}
@@ -0,0 +1,357 @@
+/*!
+# Dactyl: Nice Padded.
+*/
+
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Padded.
+///
+/// This struct holds the zero-padded, separator-free digits of an unsigned
+/// integer, like `00042`, as returned by e.g.
+/// [`NiceU16::padded`](crate::NiceU16::padded).
+///
+/// `W` is the maximum number of digits for the source type.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NicePadded, NiceU32};
+///
+/// let nice: NicePadded<10> = NiceU32::from(1234_u32).padded(6);
+/// assert_eq!(nice.as_str(), "001234");
+/// ```
+pub struct NicePadded<const W: usize> {
+	/// # Buffer.
+	inner: [u8; W],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl<const W: usize> AsRef<[u8]> for NicePadded<W> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl<const W: usize> AsRef<str> for NicePadded<W> {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl<const W: usize> ::std::borrow::Borrow<str> for NicePadded<W> {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl<const W: usize> Default for NicePadded<W> {
+	#[inline]
+	fn default() -> Self { Self { inner: [b'0'; W], from: W.saturating_sub(1) } }
+}
+
+impl<const W: usize> Deref for NicePadded<W> {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl<const W: usize> fmt::Debug for NicePadded<W> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NicePadded")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl<const W: usize> fmt::Display for NicePadded<W> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl<const W: usize> Eq for NicePadded<W> {}
+
+impl<const W: usize> hash::Hash for NicePadded<W> {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl<const W: usize> PartialEq for NicePadded<W> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl<const W: usize> NicePadded<W> {
+	/// # New.
+	///
+	/// Copy the digits from `src` — skipping any separators — and left-pad
+	/// them with zeroes to (at least) `width` places, up to a maximum of `W`.
+	pub(crate) fn new(src: &[u8], width: usize) -> Self {
+		let mut out = Self { inner: [b'0'; W], from: W };
+		for &b in src.iter().rev() {
+			if b.is_ascii_digit() {
+				out.from -= 1;
+				out.inner[out.from] = b;
+			}
+		}
+
+		let width = W - width.min(W);
+		if width < out.from { out.from = width; }
+		out
+	}
+
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the padded value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU8;
+	///
+	/// assert_eq!(NiceU8::from(7_u8).padded(3).as_bytes(), b"007");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the padded value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU8;
+	///
+	/// assert_eq!(NiceU8::from(7_u8).padded(3).as_str(), "007");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: the buffer is all ASCII digits.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Length.
+	///
+	/// Return the length of the padded value, in bytes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU16;
+	///
+	/// assert_eq!(NiceU16::from(12_u16).padded(4).len(), 4);
+	/// assert_eq!(NiceU16::from(12_345_u16).padded(4).len(), 5);
+	/// ```
+	pub const fn len(&self) -> usize { W - self.from }
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	///
+	/// This is always `false`; a padded value has at least one digit.
+	pub const fn is_empty(&self) -> bool { W <= self.from }
+}
+
+
+
+/// # Float Buffer Size.
+///
+/// This matches [`NiceFloat`](crate::NiceFloat).
+const FLOAT_SIZE: usize = crate::NiceFloat::MAX_LEN;
+
+#[derive(Clone, Copy)]
+/// # Nice Padded Float.
+///
+/// This struct holds a width-stable, space-padded float, like `     1.50`, as
+/// returned by [`NiceFloat::padded`](crate::NiceFloat::padded).
+///
+/// Unlike [`NiceFloat`](crate::NiceFloat), it is for display only; there are
+/// no comparisons or float-specific helpers.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceFloat, NicePaddedFloat};
+///
+/// let nice: NicePaddedFloat = NiceFloat::padded(1.5_f64, 6, 2);
+/// assert_eq!(nice.as_str(), "     1.50");
+/// ```
+pub struct NicePaddedFloat {
+	/// # Buffer.
+	inner: [u8; FLOAT_SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NicePaddedFloat {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NicePaddedFloat {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NicePaddedFloat {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Deref for NicePaddedFloat {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NicePaddedFloat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NicePaddedFloat")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NicePaddedFloat {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NicePaddedFloat {}
+
+impl hash::Hash for NicePaddedFloat {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NicePaddedFloat {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NicePaddedFloat {
+	/// # New.
+	///
+	/// Right-align `src` within (at least) `len` bytes, padding the left with
+	/// spaces.
+	pub(crate) fn new(src: &[u8], len: usize) -> Self {
+		let mut out = Self {
+			inner: [b' '; FLOAT_SIZE],
+			from: FLOAT_SIZE - len.max(src.len()).min(FLOAT_SIZE),
+		};
+		out.inner[FLOAT_SIZE - src.len()..].copy_from_slice(src);
+		out
+	}
+
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the padded value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::padded(1.5_f64, 3, 1).as_bytes(), b"  1.5");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the padded value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::padded(1.5_f64, 3, 1).as_str(), "  1.5");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		debug_assert!(
+			std::str::from_utf8(self.as_bytes()).is_ok(),
+			"Bug: NicePaddedFloat is not UTF."
+		);
+		// Safety: the contents are copied from a (valid UTF-8) NiceFloat.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Length.
+	///
+	/// Return the length of the padded value, in bytes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceFloat;
+	///
+	/// assert_eq!(NiceFloat::padded(1.5_f64, 3, 1).len(), 5);
+	/// ```
+	pub const fn len(&self) -> usize { FLOAT_SIZE - self.from }
+
+	#[must_use]
+	#[inline]
+	/// # Is Empty?
+	///
+	/// This is always `false`; a padded float has at least one character.
+	pub const fn is_empty(&self) -> bool { FLOAT_SIZE <= self.from }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_nice_padded() {
+		assert_eq!(NicePadded::<5>::new(b"1,234", 0).as_str(), "1234");
+		assert_eq!(NicePadded::<5>::new(b"1,234", 5).as_str(), "01234");
+		assert_eq!(NicePadded::<5>::new(b"1,234", 50).as_str(), "01234");
+		assert_eq!(NicePadded::<3>::new(b"0", 0).as_str(), "0");
+		assert_eq!(NicePadded::<3>::new(b"0", 0), NicePadded::<3>::default());
+		assert!(! NicePadded::<3>::default().is_empty());
+		assert_eq!(format!("{:?}", NicePadded::<3>::new(b"7", 3)), "NicePadded(\"007\")");
+		assert_eq!(format!("{:>5}", NicePadded::<3>::new(b"7", 3)), "  007");
+
+		// Padded values should never be mistaken for the (same-sized) ratios
+		// or integers they came from.
+		let nice = crate::NiceU32::from(42_u32).padded(10);
+		assert_eq!(format!("{nice:?}"), "NicePadded(\"0000000042\")");
+		let nice = crate::NiceU8::from(7_u8).padded(3);
+		assert_eq!(format!("{nice:?}"), "NicePadded(\"007\")");
+	}
+
+	#[test]
+	fn t_nice_padded_float() {
+		assert_eq!(NicePaddedFloat::new(b"1.50", 0).as_str(), "1.50");
+		assert_eq!(NicePaddedFloat::new(b"1.50", 6).as_str(), "  1.50");
+		assert_eq!(NicePaddedFloat::new(b"1.50", 100).len(), FLOAT_SIZE);
+		assert_eq!(format!("{:?}", NicePaddedFloat::new(b"1.5", 4)), "NicePaddedFloat(\" 1.5\")");
+
+		// Padded floats are not floats.
+		let nice = crate::NiceFloat::padded(1.5, 6, 2);
+		assert_eq!(format!("{nice:?}"), "NicePaddedFloat(\"     1.50\")");
+	}
+}
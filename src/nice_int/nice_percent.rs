@@ -3,9 +3,17 @@
 */
 
 use crate::{
+	NiceDivideByZeroError,
+	NicePercentChange,
 	NiceWrapper,
-	traits::IntDivFloat,
+	ParseByteError,
+	traits::{
+		IntDivFloat,
+		SaturatingFrom,
+	},
 };
+use super::nice_float::long_div_u128;
+use std::cmp::Ordering;
 
 
 
@@ -37,6 +45,18 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b'0', b'.', b'0', b'0', b'%'];
 /// assert_eq!(NicePercent::from(0.321).as_str(), "32.10%");
 /// ```
 ///
+/// Integer ratios can be converted too, even if the numerator and denominator
+/// are different (unsigned) types:
+///
+/// ```
+/// use dactyl::NicePercent;
+///
+/// let done = 3_u32;
+/// let total = 12_u64;
+/// assert_eq!(NicePercent::try_from((done, total)).unwrap().as_str(), "25.00%");
+/// assert!(NicePercent::try_from((done, 0_u8)).is_err());
+/// ```
+///
 /// ## Traits
 ///
 /// Rustdoc doesn't do a good job at documenting type alias implementations, but
@@ -52,10 +72,32 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b'0', b'.', b'0', b'0', b'%'];
 /// * `Deref<Target=[u8]>`
 /// * `Display`
 /// * `Eq` / `PartialEq`
+/// * `FromStr` (canonical formatting only)
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<f64>` / `PartialOrd<f64>` (and vice versa)
+///
+/// Float comparisons are made against the value _as printed_, with the float
+/// rounded to the nearest basis point first, so the original needn't be kept
+/// around for e.g. threshold checks:
+///
+/// ```
+/// use dactyl::NicePercent;
+///
+/// let nice = NicePercent::from(0.96_f64);
+/// assert!(nice > 0.95);
+/// assert!(nice == 0.96);
+/// assert!(nice == 0.960_01); // Same at two places.
+/// assert!(nice < 0.961);     // Not the same.
+///
+/// // Out-of-range values are compared as-are.
+/// assert!(NicePercent::MAX < 1.5);
+/// assert!(NicePercent::MIN > -0.5);
+/// ```
 pub type NicePercent = NiceWrapper<SIZE>;
 
+super::nice_debug!(NicePercent, percent_value);
+
 impl Default for NicePercent {
 	#[inline]
 	fn default() -> Self { Self::MIN }
@@ -71,7 +113,6 @@ macro_rules! nice_from {
 			clippy::cast_sign_loss,
 			reason = "It is what it is.",
 		)]
-		#[expect(clippy::integer_division, reason = "We want this.")]
 		impl From<$float> for NicePercent {
 			fn from(num: $float) -> Self {
 				// Shortcut for overflowing values.
@@ -81,57 +122,251 @@ macro_rules! nice_from {
 				// We can maintain precision better by working from an integer.
 				// We know there is no existing integer part, so at most we'll
 				// wind up with four digits, which fits nicely in a u16.
-				let whole = (num * 10_000.0).round() as u16;
+				Self::from_whole((num * 10_000.0).round() as u16)
+			}
+		}
 
-				// Recheck the boundaries because of the rounding.
-				if whole == 0 { return Self::MIN; }
-				else if 9999 < whole { return Self::MAX; }
+		impl From<($float, $float)> for NicePercent {
+			#[inline]
+			/// # Percent From Float/Float.
+			///
+			/// This divides the first value by the second, then converts the
+			/// result into a [`NicePercent`], clamping it to `0.0..=1.0`.
+			///
+			/// As with the [`IntDivFloat`] division behind the integer pair
+			/// conversions, invalid results — `NaN` or infinity, e.g. from
+			/// division by zero — are unsupported, and fall back to `0.00%`.
+			///
+			/// ```
+			/// use dactyl::NicePercent;
+			///
+			#[doc = concat!("assert_eq!(NicePercent::from((1.5_", stringify!($float), ", 6.0)).as_str(), \"25.00%\");")]
+			#[doc = concat!("assert_eq!(NicePercent::from((9.0_", stringify!($float), ", 6.0)), NicePercent::MAX);")]
+			#[doc = concat!("assert_eq!(NicePercent::from((1.5_", stringify!($float), ", 0.0)), NicePercent::MIN);")]
+			/// ```
+			fn from(src: ($float, $float)) -> Self { Self::from(src.0 / src.1) }
+		}
+	)+);
+}
 
-				// Split the top and bottom.
-				let (top, bottom) = (whole / 100, whole % 100);
+nice_from!(f32, f64);
 
-				let [a, b] = crate::double(top as usize);
-				let from = if a == b'0' { SIZE - 5 } else { SIZE - 6 };
-				let [c, d] = crate::double(bottom as usize);
+/// # Helper: From Whole Percent.
+///
+/// This code is identical for `u8` and `u16` types.
+macro_rules! nice_from_whole {
+	($($ty:ty),+ $(,)?) => ($(
+		impl From<$ty> for NicePercent {
+			#[inline]
+			/// # Percent From Whole Percent.
+			///
+			/// Treat the value as a whole percentage — `42` is `42.00%` —
+			/// clamping it to `0..=100`.
+			///
+			/// This avoids float conversion (and rounding) altogether.
+			///
+			/// ```
+			/// use dactyl::NicePercent;
+			///
+			#[doc = concat!("assert_eq!(NicePercent::from(42_", stringify!($ty), ").as_str(), \"42.00%\");")]
+			#[doc = concat!("assert_eq!(NicePercent::from(0_", stringify!($ty), "), NicePercent::MIN);")]
+			#[doc = concat!("assert_eq!(NicePercent::from(101_", stringify!($ty), "), NicePercent::MAX);")]
+			/// ```
+			fn from(num: $ty) -> Self {
+				if 100 <= num { Self::MAX }
+				else { Self::from_whole(u16::from(num) * 100) }
+			}
+		}
+	)+);
+}
 
-				Self {
-					inner: [b'0', a, b, b'.', c, d, b'%'],
-					from,
-				}
+nice_from_whole!(u8, u16);
+
+/// # Helper: Try From Integer Pairs.
+///
+/// This code is identical for all integer types.
+macro_rules! nice_try_from {
+	($($ty:ty),+ $(,)?) => ($(
+		impl TryFrom<($ty, $ty)> for NicePercent {
+			type Error = NiceDivideByZeroError;
+
+			#[inline]
+			/// # Percent From T/T.
+			///
+			/// This method is a shorthand that performs the (decimal) division of
+			/// `T1 / T2` for you, then converts the result into a [`NicePercent`],
+			/// clamping it to `0.0..=1.0`.
+			///
+			/// ```
+			/// use dactyl::NicePercent;
+			///
+			/// assert_eq!(
+			///     NicePercent::from(0.5_f64),
+			#[doc = concat!("    NicePercent::try_from((10_", stringify!($ty), ", 20_", stringify!($ty), ")).unwrap(),")]
+			/// );
+			/// ```
+			///
+			/// ## Errors
+			///
+			/// Conversion will fail if the denominator is zero.
+			fn try_from(src: ($ty, $ty)) -> Result<Self, Self::Error> {
+				src.0.div_float(src.1)
+					.map(Self::from)
+					.ok_or(NiceDivideByZeroError)
 			}
 		}
 	)+);
 }
 
-nice_from!(f32, f64);
+// Note: these are implemented individually rather than for all IntDivFloat
+// types because the latter would conflict with the From<(float, float)>
+// implementations. (This was a breaking change in 0.10.0.)
+nice_try_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
 
-impl<T: IntDivFloat> TryFrom<(T, T)> for NicePercent {
-	type Error = ();
+impl TryFrom<(u128, u128)> for NicePercent {
+	type Error = NiceDivideByZeroError;
 
 	#[inline]
-	/// # Percent From T/T.
+	/// # Percent From u128/u128.
 	///
 	/// This method is a shorthand that performs the (decimal) division of
-	/// `T1 / T2` for you, then converts the result into a [`NicePercent`] if
-	/// it falls between `0.0..=1.0`.
+	/// `u128 / u128` for you, then converts the result into a [`NicePercent`].
+	///
+	/// Unlike the smaller integer types, the division is carried out using
+	/// exact 128-bit integer math rather than floats, so precision is never
+	/// lost, no matter how large the operands.
 	///
 	/// ```
 	/// use dactyl::NicePercent;
 	///
 	/// assert_eq!(
 	///     NicePercent::from(0.5_f64),
-	///     NicePercent::try_from((10_u8, 20_u8)).unwrap(),
+	///     NicePercent::try_from((10_u128, 20_u128)).unwrap(),
+	/// );
+	///
+	/// // Big numbers are no problem.
+	/// assert_eq!(
+	///     NicePercent::try_from((u128::MAX - 1, u128::MAX)).unwrap().as_str(),
+	///     "100.00%",
+	/// );
+	/// assert_eq!(
+	///     NicePercent::try_from((u128::MAX / 3, u128::MAX)).unwrap().as_str(),
+	///     "33.33%",
 	/// );
 	/// ```
 	///
 	/// ## Errors
 	///
-	/// Conversion will fail if the enumerator is larger than the denominator,
-	/// or if the denominator is zero.
-	fn try_from(src: (T, T)) -> Result<Self, Self::Error> {
-		src.0.div_float(src.1)
-			.map(Self::from)
-			.ok_or(())
+	/// Conversion will fail if the denominator is zero.
+	fn try_from(src: (u128, u128)) -> Result<Self, Self::Error> {
+		if src.1 == 0 { return Err(NiceDivideByZeroError); }
+
+		// Work out the basis points, rounding half up to match the float
+		// conversions.
+		let (top, mut whole, half) = long_div_u128(src.0, src.1, 4);
+		if top != 0 { return Ok(Self::MAX); }
+		if ! matches!(half, Ordering::Less) { whole += 1; }
+
+		// This fits, and is checked for range by from_whole anyway.
+		Ok(Self::from_whole(u16::saturating_from(whole)))
+	}
+}
+
+/// # Helper: Try From Mixed Unsigned Pairs.
+///
+/// Heterogeneous unsigned pairs are widened to `u128` — losslessly — then
+/// divided exactly, just like `(u128, u128)`.
+macro_rules! nice_try_from_mixed {
+	($($a:ty: $($b:ty),+;)+) => ($($(
+		impl TryFrom<($a, $b)> for NicePercent {
+			type Error = NiceDivideByZeroError;
+
+			#[inline]
+			/// # Percent From T1/T2.
+			///
+			/// This works just like the same-type integer pair conversions,
+			/// but allows the numerator and denominator to be different
+			/// (unsigned) types.
+			///
+			/// ## Errors
+			///
+			/// Conversion will fail if the denominator is zero.
+			fn try_from(src: ($a, $b)) -> Result<Self, Self::Error> {
+				Self::try_from((u128::saturating_from(src.0), u128::saturating_from(src.1)))
+			}
+		}
+	)+)+);
+}
+
+nice_try_from_mixed!(
+	u8:          u16, u32, u64, u128, usize;
+	u16:   u8,        u32, u64, u128, usize;
+	u32:   u8,   u16,      u64, u128, usize;
+	u64:   u8,   u16, u32,      u128, usize;
+	u128:  u8,   u16, u32, u64,       usize;
+	usize: u8,   u16, u32, u64, u128;
+);
+
+impl PartialEq<f64> for NicePercent {
+	#[inline]
+	fn eq(&self, other: &f64) -> bool {
+		matches!(self.cmp_f64(*other), Some(Ordering::Equal))
+	}
+}
+
+impl PartialEq<NicePercent> for f64 {
+	#[inline]
+	fn eq(&self, other: &NicePercent) -> bool { other == self }
+}
+
+impl PartialOrd<f64> for NicePercent {
+	#[inline]
+	fn partial_cmp(&self, other: &f64) -> Option<Ordering> { self.cmp_f64(*other) }
+}
+
+impl PartialOrd<NicePercent> for f64 {
+	#[inline]
+	fn partial_cmp(&self, other: &NicePercent) -> Option<Ordering> {
+		other.cmp_f64(*self).map(Ordering::reverse)
+	}
+}
+
+impl std::str::FromStr for NicePercent {
+	type Err = ParseByteError;
+
+	/// # From Str.
+	///
+	/// Parse a canonically-formatted percentage — one to three whole digits,
+	/// a dot, two decimal places, and a trailing `%` — back into an instance.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NicePercent, ParseByteError};
+	///
+	/// let nice: NicePercent = "33.33%".parse().unwrap();
+	/// assert_eq!(nice.as_str(), "33.33%");
+	/// assert_eq!("100.00%".parse::<NicePercent>(), Ok(NicePercent::MAX));
+	///
+	/// // The precision and layout must be exact.
+	/// assert!("33.3%".parse::<NicePercent>().is_err());
+	/// assert!("33.33".parse::<NicePercent>().is_err());
+	/// assert!("033.33%".parse::<NicePercent>().is_err());
+	///
+	/// // And the value must be in range.
+	/// assert_eq!("100.01%".parse::<NicePercent>(), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the string is empty, malformed, or greater than
+	/// one hundred percent.
+	fn from_str(src: &str) -> Result<Self, Self::Err> {
+		Self::parse_canonical(src.as_bytes(), b".%", |num|
+			u16::try_from(num).ok()
+				.filter(|n| *n <= 10_000)
+				.map(Self::from_whole)
+		)
 	}
 }
 
@@ -181,6 +416,71 @@ impl NicePercent {
 	};
 }
 
+impl NicePercent {
+	#[must_use]
+	#[inline]
+	/// # Percent Change.
+	///
+	/// Calculate the relative change going from `old` to `new`, returning it
+	/// as a signed, uncapped [`NicePercentChange`], like `+12.50%`.
+	///
+	/// Change from zero is undefined, and prints as
+	/// [`NicePercentChange::UNDEFINED`] (`N/A`).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NicePercent, NicePercentChange};
+	///
+	/// assert_eq!(NicePercent::change(80.0, 90.0).as_str(), "+12.50%");
+	/// assert_eq!(NicePercent::change(90.0, 87.0).as_str(), "-3.33%");
+	/// assert_eq!(NicePercent::change(0.0, 87.0), NicePercentChange::UNDEFINED);
+	/// ```
+	pub fn change(old: f64, new: f64) -> NicePercentChange {
+		NicePercentChange::new(old, new)
+	}
+}
+
+impl NicePercent {
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # Compare to Float.
+	///
+	/// This compares the value _as printed_ against `other` — a ratio, like
+	/// the `From<f64>` input — after rounding the latter to the nearest basis
+	/// point. Unlike `From<f64>`, no clamping is applied, so e.g. `100.00%`
+	/// is less than `1.5`.
+	///
+	/// NaN is unordered.
+	fn cmp_f64(&self, other: f64) -> Option<Ordering> {
+		(self.digits() as f64).partial_cmp(&(other * 10_000.0).round())
+	}
+}
+
+impl NicePercent {
+	#[expect(clippy::integer_division, reason = "We want this.")]
+	/// # From Basis Points.
+	///
+	/// Build an instance from a whole number of basis points — hundredths of
+	/// a percent — clamping to `0..=10_000`.
+	const fn from_whole(whole: u16) -> Self {
+		// Check the boundaries.
+		if whole == 0 { return Self::MIN; }
+		else if 9999 < whole { return Self::MAX; }
+
+		// Split the top and bottom.
+		let (top, bottom) = (whole / 100, whole % 100);
+
+		let [a, b] = crate::double(top as usize);
+		let from = if a == b'0' { SIZE - 5 } else { SIZE - 6 };
+		let [c, d] = crate::double(bottom as usize);
+
+		Self {
+			inner: [b'0', a, b, b'.', c, d, b'%'],
+			from,
+		}
+	}
+}
+
 impl NicePercent {
 	#[expect(
 		clippy::cast_possible_truncation,
@@ -201,8 +501,11 @@ impl NicePercent {
 	///
 	/// num.replace(0.334);
 	/// assert_eq!(num.as_str(), "33.40%");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace(2.0).as_str(), "100.00%");
 	/// ```
-	pub fn replace(&mut self, num: f32) {
+	pub fn replace(&mut self, num: f32) -> &mut Self {
 		if num <= 0.0 || ! num.is_normal() { return self.reset_min(); }
 		else if 1.0 <= num { return self.reset_max(); }
 
@@ -225,24 +528,27 @@ impl NicePercent {
 		self.inner[2] = b;
 		self.inner[4] = c;
 		self.inner[5] = d;
+		self
 	}
 
 	/// # Reset to Minimum.
-	fn reset_min(&mut self) {
+	const fn reset_min(&mut self) -> &mut Self {
 		self.inner[2] = b'0';
 		self.inner[4] = b'0';
 		self.inner[5] = b'0';
 		self.from = SIZE - 5;
+		self
 	}
 
 	/// # Reset to Maximum.
-	fn reset_max(&mut self) {
+	const fn reset_max(&mut self) -> &mut Self {
 		self.inner[0] = b'1';
 		self.inner[1] = b'0';
 		self.inner[2] = b'0';
 		self.inner[4] = b'0';
 		self.inner[5] = b'0';
 		self.from = 0;
+		self
 	}
 }
 
@@ -253,6 +559,11 @@ mod tests {
 	use super::*;
 
 	#[test]
+	#[expect(
+		clippy::cast_lossless,
+		clippy::cast_precision_loss,
+		reason = "It is what it is.",
+	)]
 	fn t_nice_percent() {
 		// There will be disagreements with a denominator of 100_000.
 		const TOTAL: u32 = 10_000_u32;
@@ -315,4 +626,122 @@ mod tests {
 		assert_eq!(NicePercent::from(1.03_f64).as_str(), "100.00%");
 		assert_eq!(NicePercent::from(10_f64).as_str(), "100.00%");
 	}
+
+	#[test]
+	fn t_nice_percent_whole() {
+		for i in 0..=u8::MAX {
+			let nice = NicePercent::from(i);
+			assert_eq!(nice, NicePercent::from(u16::from(i)));
+			if i <= 100 {
+				assert_eq!(nice.as_str(), format!("{i}.00%"));
+				assert_eq!(nice, NicePercent::from(f32::from(i) / 100.0));
+			}
+			else { assert_eq!(nice, NicePercent::MAX); }
+		}
+		assert_eq!(NicePercent::from(u16::MAX), NicePercent::MAX);
+		assert_eq!(NicePercent::from(Some(50_u8)).as_str(), "50.00%");
+	}
+
+	#[test]
+	fn t_nice_percent_pairs() {
+		for (a, b) in [(0_u8, 1_u8), (1, 3), (2, 3), (5, 7), (1, 1), (3, 2)] {
+			let expected = NicePercent::try_from((a, b)).unwrap();
+			assert_eq!(NicePercent::from((f32::from(a), f32::from(b))), expected);
+			assert_eq!(NicePercent::from((f64::from(a), f64::from(b))), expected);
+		}
+
+		// Nonsense.
+		assert_eq!(NicePercent::from((0_f64, 0_f64)), NicePercent::MIN);
+		assert_eq!(NicePercent::from((1_f64, 0_f64)), NicePercent::MIN);
+		assert_eq!(NicePercent::from((f64::NAN, 1_f64)), NicePercent::MIN);
+		assert_eq!(NicePercent::from((-1_f32, 2_f32)), NicePercent::MIN);
+	}
+
+	#[test]
+	fn t_nice_percent_u128() {
+		// Within the float-friendly range, this should match the float version.
+		let mut rng = fastrand::Rng::new();
+		for (a, b) in std::iter::repeat_with(|| (rng.u32(..), rng.u32(1..))).take(10_000) {
+			assert_eq!(
+				NicePercent::try_from((u128::from(a), u128::from(b))),
+				NicePercent::try_from((u64::from(a), u64::from(b))),
+				"{a}/{b}",
+			);
+		}
+
+		// Big numbers should be exact.
+		let big = u128::MAX / 100_000;
+		assert_eq!(NicePercent::try_from((big * 1234, big * 10_000)).unwrap().as_str(), "12.34%");
+		assert_eq!(NicePercent::try_from((big * 12_345, big * 100_000)).unwrap().as_str(), "12.35%");
+		assert_eq!(NicePercent::try_from((big * 12_345 - 1, big * 100_000)).unwrap().as_str(), "12.34%");
+		assert_eq!(NicePercent::try_from((u128::MAX, 1_u128)), Ok(NicePercent::MAX));
+		assert_eq!(NicePercent::try_from((1_u128, u128::MAX)), Ok(NicePercent::MIN));
+		assert_eq!(NicePercent::try_from((1_u128, 0_u128)), Err(NiceDivideByZeroError));
+	}
+
+	#[test]
+	fn t_nice_percent_mixed_pairs() {
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let total = rng.u16(1..);
+			let done = rng.u16(..=total);
+			let expected = NicePercent::try_from((done, total));
+			assert!(expected.is_ok());
+
+			// Every combination should match.
+			assert_eq!(NicePercent::try_from((u32::from(done), total)), expected);
+			assert_eq!(NicePercent::try_from((u64::from(done), total)), expected);
+			assert_eq!(NicePercent::try_from((done, u64::from(total))), expected);
+			assert_eq!(NicePercent::try_from((u128::from(done), u32::from(total))), expected);
+			assert_eq!(NicePercent::try_from((usize::from(done), u128::from(total))), expected);
+			if let Ok(done) = u8::try_from(done) {
+				assert_eq!(NicePercent::try_from((done, usize::from(total))), expected);
+			}
+		}
+
+		// Big values are fine.
+		assert_eq!(NicePercent::try_from((u64::MAX, u128::from(u64::MAX) * 2)).unwrap().as_str(), "50.00%");
+
+		// Zero denominators are not.
+		assert!(NicePercent::try_from((1_u8, 0_u64)).is_err());
+	}
+
+	#[test]
+	fn t_from_str() {
+		for whole in 0..=10_000_u16 {
+			let nice = NicePercent::from_whole(whole);
+			assert_eq!(nice.as_str().parse::<NicePercent>(), Ok(nice));
+		}
+
+		for (src, err) in [
+			("", ParseByteError::Empty),
+			("%", ParseByteError::InvalidDigit(0)),
+			("1.00", ParseByteError::InvalidDigit(3)),
+			("1.0%", ParseByteError::InvalidDigit(0)),
+			("1.000%", ParseByteError::InvalidDigit(1)),
+			("01.00%", ParseByteError::InvalidDigit(0)),
+			("1,00%", ParseByteError::InvalidDigit(1)),
+			("-1.00%", ParseByteError::InvalidDigit(0)),
+			("100.01%", ParseByteError::Overflow),
+		] {
+			assert_eq!(src.parse::<NicePercent>(), Err(err), "{src:?}");
+		}
+	}
+
+	#[test]
+	fn t_cmp_f64() {
+		for whole in 0..=10_000_u16 {
+			let nice = NicePercent::from_whole(whole);
+			let ratio = f64::from(whole) / 10_000.0;
+			assert!(nice == ratio, "{ratio}");
+			assert_eq!(ratio.partial_cmp(&nice), Some(Ordering::Equal));
+			assert!(nice < ratio + 0.000_051, "{ratio}");
+			assert!(nice > ratio - 0.000_051, "{ratio}");
+			assert!(ratio + 0.000_051 > nice, "{ratio}");
+		}
+
+		assert_eq!(NicePercent::MIN.partial_cmp(&f64::NAN), None);
+		assert!(NicePercent::MAX < f64::INFINITY);
+		assert!(NicePercent::MIN > f64::NEG_INFINITY);
+	}
 }
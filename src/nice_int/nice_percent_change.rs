@@ -0,0 +1,302 @@
+/*!
+# Dactyl: Nice Percent Change.
+*/
+
+use crate::NiceFixed;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `-92,233,720,368,547,758.08` + `%` = 27 bytes.
+const SIZE: usize = 27;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Percent Change.
+///
+/// This struct formats the relative change between two values — `(new - old)
+/// / |old|` — as a signed percentage with two decimal places, like `+12.50%`
+/// or `-3.33%`.
+///
+/// Unlike [`NiceSignedPercent`](crate::NiceSignedPercent), the output is not
+/// capped at one hundred percent; large changes are comma-separated, like
+/// `+1,150.00%`, saturating at the limits of `i64` basis points.
+///
+/// Positive changes are always prefixed with a `+`, negative changes with a
+/// `-`, while no change at all — including changes that round to zero — is
+/// simply `0.00%`.
+///
+/// Change from zero is undefined, as is anything involving `NaN` or
+/// infinity; such cases print as [`NicePercentChange::UNDEFINED`] (`N/A`).
+/// (Zero to zero is, however, treated as no change.)
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NicePercent, NicePercentChange};
+///
+/// assert_eq!(NicePercentChange::new(80.0, 90.0).as_str(), "+12.50%");
+/// assert_eq!(NicePercentChange::new(90.0, 87.0).as_str(), "-3.33%");
+/// assert_eq!(NicePercentChange::new(10.0, 125.0).as_str(), "+1,150.00%");
+/// assert_eq!(NicePercentChange::new(5.0, 5.0).as_str(), "0.00%");
+///
+/// // Negative starting points work too; the direction is what counts.
+/// assert_eq!(NicePercentChange::new(-10.0, -5.0).as_str(), "+50.00%");
+///
+/// // Change from zero is undefined.
+/// assert_eq!(NicePercentChange::new(0.0, 5.0), NicePercentChange::UNDEFINED);
+/// assert_eq!(NicePercentChange::UNDEFINED.as_str(), "N/A");
+///
+/// // This can also be reached via NicePercent.
+/// assert_eq!(NicePercent::change(80.0, 90.0).as_str(), "+12.50%");
+/// ```
+pub struct NicePercentChange {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NicePercentChange {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NicePercentChange {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NicePercentChange {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NicePercentChange {
+	#[inline]
+	fn default() -> Self { Self::ZERO }
+}
+
+impl Deref for NicePercentChange {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NicePercentChange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NicePercentChange")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NicePercentChange {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NicePercentChange {}
+
+impl hash::Hash for NicePercentChange {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NicePercentChange {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NicePercentChange {
+	/// # Zero.
+	///
+	/// No change.
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert_eq!(NicePercentChange::ZERO.as_str(), "0.00%");
+	/// assert_eq!(NicePercentChange::new(0.0, 0.0), NicePercentChange::ZERO);
+	/// ```
+	pub const ZERO: Self = Self {
+		inner: *b"                      0.00%",
+		from: SIZE - 5,
+	};
+
+	/// # Undefined.
+	///
+	/// Change from zero, `NaN`, or infinity.
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert_eq!(NicePercentChange::UNDEFINED.as_str(), "N/A");
+	/// assert_eq!(NicePercentChange::new(0.0, -1.0), NicePercentChange::UNDEFINED);
+	/// assert_eq!(NicePercentChange::new(f64::NAN, 1.0), NicePercentChange::UNDEFINED);
+	/// ```
+	pub const UNDEFINED: Self = Self {
+		inner: *b"                        N/A",
+		from: SIZE - 3,
+	};
+}
+
+impl NicePercentChange {
+	#[expect(clippy::cast_possible_truncation, reason = "Saturation is intended.")]
+	#[must_use]
+	/// # New.
+	///
+	/// Calculate and format the percent change going from `old` to `new`.
+	///
+	/// `f32` values can be used too; just upcast them with `f64::from` first.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert_eq!(NicePercentChange::new(3.0, 2.0).as_str(), "-33.33%");
+	/// assert_eq!(NicePercentChange::new(3.0, 0.0).as_str(), "-100.00%");
+	/// assert_eq!(NicePercentChange::new(1.0, 1.000_01).as_str(), "0.00%");
+	/// ```
+	pub fn new(old: f64, new: f64) -> Self {
+		if ! old.is_finite() || ! new.is_finite() { return Self::UNDEFINED; }
+		if old == 0.0 {
+			return if new == 0.0 { Self::ZERO } else { Self::UNDEFINED };
+		}
+
+		// Work out the change in basis points, which conveniently lets us
+		// reuse NiceFixed for the formatting.
+		let bp = ((new - old) / old.abs() * 10_000.0).round() as i64;
+		if bp == 0 { return Self::ZERO; }
+
+		let fixed = NiceFixed::from_scaled(bp, 2);
+		let fixed = fixed.as_bytes();
+
+		let mut out = Self { inner: [b' '; SIZE], from: SIZE - 1 };
+		out.inner[SIZE - 1] = b'%';
+		out.from -= fixed.len();
+		out.inner[out.from..SIZE - 1].copy_from_slice(fixed);
+
+		// Negatives are already signed; positives need a plus.
+		if 0 < bp {
+			out.from -= 1;
+			out.inner[out.from] = b'+';
+		}
+
+		out
+	}
+
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert_eq!(NicePercentChange::new(2.0, 3.0).as_bytes(), b"+50.00%");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert_eq!(NicePercentChange::new(2.0, 3.0).as_str(), "+50.00%");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Is Undefined?
+	///
+	/// Returns `true` if the change could not be calculated.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePercentChange;
+	///
+	/// assert!(NicePercentChange::new(0.0, 1.0).is_undefined());
+	/// assert!(! NicePercentChange::new(1.0, 0.0).is_undefined());
+	/// ```
+	pub fn is_undefined(&self) -> bool { self.as_bytes() == b"N/A" }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[expect(clippy::cast_possible_truncation, reason = "Saturation is intended.")]
+	#[test]
+	fn t_nice_percent_change() {
+		let mut rng = fastrand::Rng::new();
+		for (old, new) in std::iter::repeat_with(|| (
+			rng.f64() * f64::from(rng.i16(..)),
+			rng.f64() * f64::from(rng.i16(..)),
+		)).take(10_000) {
+			let change = NicePercentChange::new(old, new);
+			if old == 0.0 {
+				assert!(change.is_undefined() || new == 0.0);
+				continue;
+			}
+
+			// Rebuild the expected value the slow way.
+			let bp = ((new - old) / old.abs() * 10_000.0).round() as i64;
+			let sign = match bp.signum() { 1 => "+", -1 => "-", _ => "" };
+			let expected = format!(
+				"{sign}{}.{:02}%",
+				(bp.unsigned_abs() / 100).to_formatted_string(&Locale::en),
+				bp.unsigned_abs() % 100,
+			);
+			assert_eq!(change.as_str(), expected, "{old} -> {new}");
+
+			// The sign should reflect the direction.
+			if new < old { assert!(bp <= 0); }
+			else { assert!(0 <= bp); }
+		}
+
+		// Saturation.
+		assert_eq!(
+			NicePercentChange::new(f64::MIN_POSITIVE, f64::MAX).as_str(),
+			"+92,233,720,368,547,758.07%",
+		);
+		assert_eq!(
+			NicePercentChange::new(-f64::MIN_POSITIVE, f64::MIN).as_str(),
+			"-92,233,720,368,547,758.08%",
+		);
+
+		// Zero and undefined.
+		assert_eq!(NicePercentChange::default(), NicePercentChange::ZERO);
+		assert_eq!(NicePercentChange::new(-0.0, 0.0), NicePercentChange::ZERO);
+		assert!(NicePercentChange::new(1.0, f64::INFINITY).is_undefined());
+		assert!(! NicePercentChange::ZERO.is_undefined());
+	}
+}
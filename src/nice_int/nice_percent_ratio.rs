@@ -0,0 +1,232 @@
+/*!
+# Dactyl: Nice Percent Ratio.
+*/
+
+use crate::{
+	NiceFixed,
+	NicePercent,
+	NiceWrapper,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `99,999.99%` = ten bytes.
+const SIZE: usize = 10;
+
+/// # Maximum Basis Points.
+const MAX_BP: u32 = 9_999_999;
+
+
+
+/// `NicePercentRatio` is a variation of [`NicePercent`] for ratios that may
+/// exceed one — e.g. throughput relative to a baseline — and so shouldn't be
+/// clamped at one hundred percent.
+///
+/// The precision is fixed at two decimal places (rounded at the thousandth),
+/// with output ranging from `0.00%` to `99,999.99%`. The whole part is
+/// comma-separated.
+///
+/// Values less than zero — as well as `NaN` — are clamped to `0.00%`, while
+/// values of `1,000` or more (including infinity) are clamped to
+/// `99,999.99%`.
+///
+/// This is usually reached via [`NicePercent::ratio`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NicePercent, NicePercentRatio};
+///
+/// assert_eq!(NicePercentRatio::from(2.5).as_str(), "250.00%");
+/// assert_eq!(NicePercentRatio::from(0.321).as_str(), "32.10%");
+/// assert_eq!(NicePercentRatio::from(12.345_67).as_str(), "1,234.57%");
+/// assert_eq!(NicePercentRatio::from(5000_f32), NicePercentRatio::MAX);
+///
+/// // Compare with the default behavior:
+/// assert_eq!(NicePercent::from(2.5).as_str(), "100.00%");
+/// assert_eq!(NicePercent::ratio(2.5).as_str(), "250.00%");
+/// ```
+///
+/// ## Traits
+///
+/// Rustdoc doesn't do a good job at documenting type alias implementations, but
+/// `NicePercentRatio` has a bunch, including:
+///
+/// * `AsRef<[u8]>`
+/// * `AsRef<str>`
+/// * `Borrow<[u8]>`
+/// * `Borrow<str>`
+/// * `Clone`
+/// * `Copy`
+/// * `Default`
+/// * `Deref<Target=[u8]>`
+/// * `Display`
+/// * `Eq` / `PartialEq`
+/// * `Hash`
+/// * `Ord` / `PartialOrd`
+pub type NicePercentRatio = NiceWrapper<SIZE>;
+
+super::nice_debug!(NicePercentRatio, percent_value);
+
+impl Default for NicePercentRatio {
+	#[inline]
+	fn default() -> Self { Self::MIN }
+}
+
+impl From<f32> for NicePercentRatio {
+	#[inline]
+	fn from(num: f32) -> Self { Self::from(f64::from(num)) }
+}
+
+impl From<f64> for NicePercentRatio {
+	#[expect(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "It is what it is.",
+	)]
+	fn from(num: f64) -> Self {
+		// Shortcut for overflowing values.
+		if num <= 0.0 || num.is_nan() { return Self::MIN; }
+
+		// As with NicePercent, we'll work from basis points.
+		let bp = (num * 10_000.0).round();
+		if f64::from(MAX_BP) <= bp { return Self::MAX; }
+		let bp = bp as u32;
+		if bp == 0 { return Self::MIN; }
+
+		// NiceFixed can handle the formatting.
+		let fixed = NiceFixed::from_scaled(i64::from(bp), 2);
+		let fixed = fixed.as_bytes();
+
+		let mut out = Self::MIN;
+		out.from = SIZE - 1 - fixed.len();
+		out.inner[out.from..SIZE - 1].copy_from_slice(fixed);
+		out
+	}
+}
+
+impl From<NicePercent> for NicePercentRatio {
+	#[inline]
+	/// # From Percent.
+	///
+	/// Clamped percentages are always valid ratios.
+	///
+	/// ```
+	/// use dactyl::{NicePercent, NicePercentRatio};
+	///
+	/// let nice = NicePercent::from(0.5);
+	/// assert_eq!(NicePercentRatio::from(nice).as_str(), "50.00%");
+	/// ```
+	fn from(src: NicePercent) -> Self {
+		let src = src.as_bytes();
+		let mut out = Self::MIN;
+		out.from = SIZE - src.len();
+		out.inner[out.from..].copy_from_slice(src);
+		out
+	}
+}
+
+impl NicePercentRatio {
+	/// # Minimum Value.
+	///
+	/// Zero percent.
+	///
+	/// ```
+	/// use dactyl::NicePercentRatio;
+	///
+	/// assert_eq!(
+	///     NicePercentRatio::MIN.as_str(),
+	///     "0.00%"
+	/// );
+	/// ```
+	pub const MIN: Self = Self {
+		inner: *b"     0.00%",
+		from: SIZE - 5,
+	};
+
+	/// # Maximum Value.
+	///
+	/// Just shy of one hundred thousand percent.
+	///
+	/// ```
+	/// use dactyl::NicePercentRatio;
+	///
+	/// assert_eq!(
+	///     NicePercentRatio::MAX.as_str(),
+	///     "99,999.99%"
+	/// );
+	///
+	/// assert_eq!(
+	///     NicePercentRatio::MAX,
+	///     NicePercentRatio::from(f64::INFINITY),
+	/// );
+	/// ```
+	pub const MAX: Self = Self {
+		inner: *b"99,999.99%",
+		from: 0,
+	};
+}
+
+impl NicePercent {
+	#[must_use]
+	#[inline]
+	/// # Ratio (Unclamped).
+	///
+	/// Format `num` as a percentage _without_ clamping it to `100.00%`,
+	/// returning a [`NicePercentRatio`]. This is handy for comparisons that
+	/// can exceed the baseline, like `250.00%`.
+	///
+	/// Negative values are still treated as zero, and the output saturates
+	/// at `99,999.99%`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePercent;
+	///
+	/// assert_eq!(NicePercent::ratio(2.5).as_str(), "250.00%");
+	/// assert_eq!(NicePercent::ratio(0.5).as_str(), "50.00%");
+	/// assert_eq!(NicePercent::ratio(-0.5).as_str(), "0.00%");
+	/// ```
+	pub fn ratio(num: f64) -> NicePercentRatio { NicePercentRatio::from(num) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_percent_ratio() {
+		for bp in (0..MAX_BP).step_by(97).chain([1, 99, 100, 10_000, 99_999, 100_000, MAX_BP - 1]) {
+			let ratio = f64::from(bp) / 10_000.0;
+			let expected = format!(
+				"{}.{:02}%",
+				(bp / 100).to_formatted_string(&Locale::en),
+				bp % 100,
+			);
+			let nice = NicePercentRatio::from(ratio);
+			assert_eq!(nice.as_str(), expected, "{ratio}");
+
+			// Within 0..=1, this should match NicePercent.
+			if bp <= 10_000 {
+				let pct = NicePercent::from(ratio);
+				assert_eq!(pct.as_str(), nice.as_str());
+				assert_eq!(NicePercentRatio::from(pct), nice);
+			}
+		}
+
+		// Edge cases.
+		assert_eq!(NicePercentRatio::default(), NicePercentRatio::MIN);
+		assert_eq!(NicePercentRatio::from(f64::NAN), NicePercentRatio::MIN);
+		assert_eq!(NicePercentRatio::from(f64::NEG_INFINITY), NicePercentRatio::MIN);
+		assert_eq!(NicePercentRatio::from(0.000_04), NicePercentRatio::MIN);
+		assert_eq!(NicePercentRatio::from(999.999_9), NicePercentRatio::MAX);
+		assert_eq!(NicePercentRatio::from(f32::MAX), NicePercentRatio::MAX);
+		assert_eq!(NicePercentRatio::from(2.5_f32).as_str(), "250.00%");
+	}
+}
@@ -0,0 +1,270 @@
+/*!
+# Dactyl: Nice Power of Two.
+*/
+
+use crate::NiceU64;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `2^63 (` + `9,223,372,036,854,775,808` + `)` = 32 bytes.
+const SIZE: usize = 32;
+
+/// # Binary Units.
+const UNITS: [&[u8]; 7] = [b" B", b" KiB", b" MiB", b" GiB", b" TiB", b" PiB", b" EiB"];
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Power of Two.
+///
+/// This struct formats unsigned integers with special handling for exact
+/// powers of two, which crop up all the time in allocator, cache, and
+/// alignment reporting.
+///
+/// The [`From`] implementations give the exponent alongside the value, like
+/// `2^20 (1,048,576)`, while [`NicePow2::binary`] uses binary size units
+/// instead, like `1 MiB`.
+///
+/// Values that aren't powers of two — including zero — are formatted the
+/// same as [`NiceU64`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NicePow2;
+///
+/// assert_eq!(NicePow2::from(1_048_576_u64).as_str(), "2^20 (1,048,576)");
+/// assert_eq!(NicePow2::from(4096_u64).as_str(), "2^12 (4,096)");
+/// assert_eq!(NicePow2::binary(1_048_576).as_str(), "1 MiB");
+/// assert_eq!(NicePow2::binary(32_768).as_str(), "32 KiB");
+///
+/// // Everything else is just a number.
+/// assert_eq!(NicePow2::from(1000_u64).as_str(), "1,000");
+/// assert_eq!(NicePow2::binary(1000).as_str(), "1,000");
+/// ```
+pub struct NicePow2 {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+}
+
+impl AsRef<[u8]> for NicePow2 {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NicePow2 {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NicePow2 {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NicePow2 {
+	#[inline]
+	fn default() -> Self { Self::from(0_u64) }
+}
+
+impl Deref for NicePow2 {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NicePow2 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NicePow2")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NicePow2 {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NicePow2 {}
+
+impl From<u64> for NicePow2 {
+	fn from(num: u64) -> Self {
+		if num.is_power_of_two() {
+			// Wrap the number in parentheses, and stick the exponent in front.
+			let mut out = Self::with_suffix(num, b")");
+			out.push_front(b'(');
+			out.push_front(b' ');
+			out.push_exponent(num.trailing_zeros());
+			out
+		}
+		else { Self::with_suffix(num, b"") }
+	}
+}
+
+impl From<usize> for NicePow2 {
+	#[inline]
+	fn from(num: usize) -> Self { Self::from(num as u64) }
+}
+
+impl hash::Hash for NicePow2 {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NicePow2 {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NicePow2 {
+	#[expect(clippy::integer_division, reason = "We want this.")]
+	#[must_use]
+	/// # Binary Units.
+	///
+	/// Format exact powers of two using the largest binary size unit that
+	/// keeps the coefficient whole, from `B` through `EiB`, like `512 B`,
+	/// `64 KiB`, or `4 GiB`.
+	///
+	/// Values that aren't powers of two are formatted like [`NiceU64`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePow2;
+	///
+	/// assert_eq!(NicePow2::binary(1).as_str(), "1 B");
+	/// assert_eq!(NicePow2::binary(512).as_str(), "512 B");
+	/// assert_eq!(NicePow2::binary(1024).as_str(), "1 KiB");
+	/// assert_eq!(NicePow2::binary(1 << 32).as_str(), "4 GiB");
+	/// assert_eq!(NicePow2::binary(1 << 63).as_str(), "8 EiB");
+	///
+	/// // Not a power of two.
+	/// assert_eq!(NicePow2::binary(1536).as_str(), "1,536");
+	/// ```
+	pub fn binary(num: u64) -> Self {
+		if num.is_power_of_two() {
+			let exp = num.trailing_zeros();
+			Self::with_suffix(1 << (exp % 10), UNITS[(exp / 10) as usize])
+		}
+		else { Self::with_suffix(num, b"") }
+	}
+
+	#[must_use]
+	#[inline]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePow2;
+	///
+	/// assert_eq!(NicePow2::from(8_u64).as_bytes(), b"2^3 (8)");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	#[inline]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NicePow2;
+	///
+	/// assert_eq!(NicePow2::from(8_u64).as_str(), "2^3 (8)");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+}
+
+impl NicePow2 {
+	/// # With Suffix.
+	///
+	/// Write the number, followed by `suffix`, right-aligned.
+	fn with_suffix(num: u64, suffix: &[u8]) -> Self {
+		let nice = NiceU64::from(num);
+		let nice = nice.as_bytes();
+		let mut out = Self {
+			inner: [b' '; SIZE],
+			from: SIZE - suffix.len() - nice.len(),
+		};
+		out.inner[out.from..SIZE - suffix.len()].copy_from_slice(nice);
+		out.inner[SIZE - suffix.len()..].copy_from_slice(suffix);
+		out
+	}
+
+	/// # Push Front.
+	///
+	/// Prepend a single byte.
+	const fn push_front(&mut self, b: u8) {
+		self.from -= 1;
+		self.inner[self.from] = b;
+	}
+
+	/// # Push Exponent.
+	///
+	/// Prepend `2^N`.
+	const fn push_exponent(&mut self, exp: u32) {
+		let [a, b] = crate::double(exp as usize);
+		self.push_front(b);
+		if a != b'0' { self.push_front(a); }
+		self.push_front(b'^');
+		self.push_front(b'2');
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use num_format::{ToFormattedString, Locale};
+
+	#[test]
+	fn t_nice_pow2() {
+		for exp in 0..64 {
+			let num = 1_u64 << exp;
+			assert_eq!(
+				NicePow2::from(num).as_str(),
+				format!("2^{exp} ({})", num.to_formatted_string(&Locale::en)),
+			);
+			assert_eq!(
+				NicePow2::binary(num).as_str(),
+				format!("{} {}", 1_u64 << (exp % 10), ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"][exp / 10]),
+			);
+
+			// The neighbors aren't powers.
+			for num in [num - 1, num + 1] {
+				if num.is_power_of_two() { continue; }
+				let expected = num.to_formatted_string(&Locale::en);
+				assert_eq!(NicePow2::from(num).as_str(), expected);
+				assert_eq!(NicePow2::binary(num).as_str(), expected);
+			}
+		}
+
+		// Zero and max.
+		assert_eq!(NicePow2::default().as_str(), "0");
+		assert_eq!(NicePow2::binary(0).as_str(), "0");
+		assert_eq!(NicePow2::from(u64::MAX).as_str(), NiceU64::MAX.as_str());
+		assert_eq!(NicePow2::from(1024_usize), NicePow2::from(1024_u64));
+	}
+}
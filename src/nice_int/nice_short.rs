@@ -0,0 +1,407 @@
+/*!
+# Dactyl: Nice Short.
+*/
+
+use crate::Scale;
+use std::{
+	fmt,
+	hash,
+	ops::Deref,
+};
+
+
+
+/// # Default Decimal Places.
+const DEFAULT_DECIMALS: u8 = 2;
+
+/// # Maximum Decimal Places.
+const MAX_DECIMALS: u8 = 3;
+
+/// # Total Buffer Size.
+///
+/// `18446` + `.` + `744` + `Q` = ten bytes.
+const SIZE: usize = 10;
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Short.
+///
+/// This struct abbreviates large integers using magnitude suffixes, like
+/// `1.23M`, for places — dashboards, etc. — where the full thousands-separated
+/// value would be too wide.
+///
+/// Values under one thousand are printed as-is, without decimals or a suffix.
+/// Larger values are divided by the largest applicable power of one thousand
+/// and rounded (ties away from zero) to the desired number of decimal places.
+///
+/// The precision defaults to two decimal places, and the suffixes to the
+/// [short scale](Scale::Short), but both can be customized by constructing
+/// with [`NiceShort::with_scale`] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceShort, Scale};
+///
+/// assert_eq!(NiceShort::from(999_u64).as_str(), "999");
+/// assert_eq!(NiceShort::from(1_234_567_u64).as_str(), "1.23M");
+/// assert_eq!(NiceShort::from(5_600_000_000_u64).as_str(), "5.60B");
+///
+/// // SI suffixes, one decimal.
+/// assert_eq!(
+///     NiceShort::with_scale(5_600_000_000, Scale::Si, 1).as_str(),
+///     "5.6G",
+/// );
+///
+/// // Update the value in place.
+/// let mut num = NiceShort::from(1234_u64);
+/// assert_eq!(num.as_str(), "1.23K");
+///
+/// num.replace(999_999);
+/// assert_eq!(num.as_str(), "1.00M");
+/// ```
+pub struct NiceShort {
+	/// # Buffer.
+	inner: [u8; SIZE],
+
+	/// # Starting Index.
+	from: usize,
+
+	/// # Suffix Scale.
+	scale: Scale,
+
+	/// # Decimal Places.
+	decimals: u8,
+}
+
+impl AsRef<[u8]> for NiceShort {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceShort {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceShort {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl Default for NiceShort {
+	#[inline]
+	fn default() -> Self { Self::with_scale(0, Scale::Short, DEFAULT_DECIMALS) }
+}
+
+impl Deref for NiceShort {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Debug for NiceShort {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceShort")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl fmt::Display for NiceShort {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceShort {}
+
+/// # Helper: From Unsigned.
+macro_rules! nice_from {
+	($($uint:ty),+ $(,)?) => ($(
+		impl From<$uint> for NiceShort {
+			#[inline]
+			fn from(num: $uint) -> Self {
+				Self::with_scale(u64::from(num), Scale::Short, DEFAULT_DECIMALS)
+			}
+		}
+	)+);
+}
+
+nice_from!(u8, u16, u32, u64);
+
+impl From<usize> for NiceShort {
+	#[inline]
+	fn from(num: usize) -> Self {
+		Self::with_scale(num as u64, Scale::Short, DEFAULT_DECIMALS)
+	}
+}
+
+impl hash::Hash for NiceShort {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceShort {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceShort {
+	#[must_use]
+	/// # New Instance w/ Custom Scale and Precision.
+	///
+	/// Abbreviate `num` using the given suffix [`Scale`], with `decimals`
+	/// decimal places. The decimals are clamped to `0..=3`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceShort, Scale};
+	///
+	/// assert_eq!(NiceShort::with_scale(1_500, Scale::Short, 0).as_str(), "2K");
+	/// assert_eq!(NiceShort::with_scale(1_234_567, Scale::Short, 3).as_str(), "1.235M");
+	/// assert_eq!(NiceShort::with_scale(7_000_000_000_000, Scale::Si, 1).as_str(), "7.0T");
+	/// assert_eq!(NiceShort::with_scale(u64::MAX, Scale::Si, 2).as_str(), "18.45E");
+	/// assert_eq!(NiceShort::with_scale(u64::MAX, Scale::Short, 2).as_str(), "18446.74Q");
+	/// ```
+	pub fn with_scale(num: u64, scale: Scale, decimals: u8) -> Self {
+		let mut out = Self {
+			inner: [b'0'; SIZE],
+			from: SIZE,
+			scale,
+			decimals: decimals.min(MAX_DECIMALS),
+		};
+		out.replace(num);
+		out
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new value. The scale
+	/// and precision are preserved.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::{NiceShort, Scale};
+	///
+	/// let mut num = NiceShort::with_scale(1_000, Scale::Si, 1);
+	/// assert_eq!(num.as_str(), "1.0K");
+	///
+	/// num.replace(25_250_000);
+	/// assert_eq!(num.as_str(), "25.3M");
+	///
+	/// num.replace(12);
+	/// assert_eq!(num.as_str(), "12");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace(2_500).as_str(), "2.5K");
+	/// ```
+	pub fn replace(&mut self, num: u64) -> &mut Self {
+		// Small numbers are written as-is.
+		if num < 1000 {
+			self.from = SIZE;
+			self.write_digits(num);
+			return self;
+		}
+
+		// Find the biggest unit that fits.
+		let suffixes = self.scale.suffixes();
+		let mut idx = 0;
+		let mut unit = 1000_u128;
+		let num = u128::from(num);
+		while idx + 1 < suffixes.len() && unit * 1000 <= num {
+			idx += 1;
+			unit *= 1000;
+		}
+
+		// Scale and round. If rounding pushes us to the next unit — e.g.
+		// 999.999K — bump it.
+		let pow = 10_u128.pow(u32::from(self.decimals));
+		let mut scaled = (num * pow + unit / 2) / unit;
+		if 1000 * pow <= scaled && idx + 1 < suffixes.len() {
+			idx += 1;
+			unit *= 1000;
+			scaled = (num * pow + unit / 2) / unit;
+		}
+
+		// Write it backwards, starting with the suffix.
+		self.inner[SIZE - 1] = suffixes[idx];
+		self.from = SIZE - 1;
+
+		// The decimals, if any.
+		if self.decimals != 0 {
+			let mut bottom = scaled % pow;
+			for _ in 0..self.decimals {
+				self.from -= 1;
+				self.inner[self.from] = (bottom % 10) as u8 + b'0';
+				bottom /= 10;
+			}
+			self.from -= 1;
+			self.inner[self.from] = b'.';
+		}
+
+		// And the whole part, which is always less than a million.
+		self.write_digits((scaled / pow) as u64);
+		self
+	}
+
+	/// # Write Digits.
+	///
+	/// Write `num` (in full) to the left of `self.from`, moving the latter
+	/// accordingly.
+	const fn write_digits(&mut self, mut num: u64) {
+		loop {
+			self.from -= 1;
+			self.inner[self.from] = (num % 10) as u8 + b'0';
+			num /= 10;
+			if num == 0 { break; }
+		}
+	}
+}
+
+impl NiceShort {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the formatted value as a byte slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceShort;
+	///
+	/// assert_eq!(NiceShort::from(3_400_000_u32).as_bytes(), b"3.40M");
+	/// ```
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[self.from..] }
+
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return a copy of the full backing buffer — with the value right-aligned
+	/// and any unused leading bytes replaced with spaces — along with the
+	/// index the value starts at.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceShort;
+	///
+	/// let (arr, from) = NiceShort::from(3_400_000_u32).to_array();
+	/// assert_eq!(arr, *b"     3.40M");
+	/// assert_eq!(from, 5);
+	/// ```
+	pub const fn to_array(self) -> ([u8; SIZE], usize) {
+		let mut out = self.inner;
+		let mut idx = 0;
+		while idx < self.from {
+			out[idx] = b' ';
+			idx += 1;
+		}
+		(out, self.from)
+	}
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the formatted value as a string slice.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceShort;
+	///
+	/// assert_eq!(NiceShort::from(3_400_000_u32).as_str(), "3.40M");
+	/// ```
+	pub fn as_str(&self) -> &str {
+		debug_assert!(std::str::from_utf8(self.as_bytes()).is_ok(), "Bug: NiceShort is not UTF.");
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	/// # Decimal Places.
+	///
+	/// Return the number of decimal places used for abbreviated values.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::{NiceShort, Scale};
+	///
+	/// assert_eq!(NiceShort::default().decimals(), 2);
+	/// assert_eq!(NiceShort::with_scale(0, Scale::Si, 10).decimals(), 3);
+	/// ```
+	pub const fn decimals(&self) -> u8 { self.decimals }
+
+	#[must_use]
+	/// # Scale.
+	///
+	/// Return the suffix [`Scale`].
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::{NiceShort, Scale};
+	///
+	/// assert_eq!(NiceShort::default().scale(), Scale::Short);
+	/// assert_eq!(NiceShort::with_scale(0, Scale::Si, 2).scale(), Scale::Si);
+	/// ```
+	pub const fn scale(&self) -> Scale { self.scale }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nice_short() {
+		for (num, scale, decimals, expected) in [
+			(0, Scale::Short, 2, "0"),
+			(999, Scale::Short, 2, "999"),
+			(1000, Scale::Short, 2, "1.00K"),
+			(1000, Scale::Short, 0, "1K"),
+			(1005, Scale::Short, 2, "1.01K"),
+			(1234, Scale::Short, 1, "1.2K"),
+			(999_499, Scale::Short, 0, "999K"),
+			(999_500, Scale::Short, 0, "1M"),
+			(999_994, Scale::Short, 2, "999.99K"),
+			(999_995, Scale::Short, 2, "1.00M"),
+			(1_234_567, Scale::Short, 2, "1.23M"),
+			(3_400_000, Scale::Si, 2, "3.40M"),
+			(5_600_000_000, Scale::Short, 2, "5.60B"),
+			(5_600_000_000, Scale::Si, 2, "5.60G"),
+			(1_000_000_000_000, Scale::Short, 3, "1.000T"),
+			(2_500_000_000_000_000, Scale::Short, 1, "2.5Q"),
+			(2_500_000_000_000_000, Scale::Si, 1, "2.5P"),
+			(999_999_999_999_999_999, Scale::Short, 2, "1000.00Q"),
+			(999_999_999_999_999_999, Scale::Si, 2, "1.00E"),
+			(u64::MAX, Scale::Short, 3, "18446.744Q"),
+			(u64::MAX, Scale::Si, 3, "18.447E"),
+		] {
+			let nice = NiceShort::with_scale(num, scale, decimals);
+			assert_eq!(nice.as_str(), expected, "{num} ({scale:?}, {decimals})");
+			assert_eq!(nice.to_string(), expected);
+		}
+
+		// Replacement should match fresh instances, big or small.
+		let mut rng = fastrand::Rng::new();
+		for scale in [Scale::Short, Scale::Si] {
+			for decimals in 0..=MAX_DECIMALS {
+				let mut last = NiceShort::with_scale(u64::MAX, scale, decimals);
+				for i in std::iter::repeat_with(|| rng.u64(..)).take(1000) {
+					last.replace(i);
+					assert_eq!(last, NiceShort::with_scale(i, scale, decimals));
+					last.replace(i >> 40);
+					assert_eq!(last, NiceShort::with_scale(i >> 40, scale, decimals));
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,228 @@
+/*!
+# Dactyl: Nice Signed Percent.
+*/
+
+use crate::{
+	NicePercent,
+	NiceWrapper,
+};
+
+
+
+/// # Total Buffer Size.
+///
+/// `-` + `100.00%` = eight bytes.
+const SIZE: usize = 8;
+
+
+
+/// `NiceSignedPercent` is a variation of [`NicePercent`] for values that may
+/// be negative — a value `-1.0..=1.0` — such as progress deltas.
+///
+/// The precision is fixed at two decimal places (rounded at the thousandth),
+/// with output ranging from `-100.00%` to `100.00%`.
+///
+/// Values less than `-1` are clamped to `-100.00%`, while values greater than
+/// `1` are clamped to `100.00%`. As with [`NicePercent`], `NaN` and infinity
+/// are treated as zero, as are negative values that round to zero.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceSignedPercent;
+///
+/// assert_eq!(NiceSignedPercent::from(-0.0325).as_str(), "-3.25%");
+/// assert_eq!(NiceSignedPercent::from(0.321).as_str(), "32.10%");
+/// assert_eq!(NiceSignedPercent::from(-5_f32), NiceSignedPercent::MIN);
+/// assert_eq!(NiceSignedPercent::from(-0.00001).as_str(), "0.00%");
+/// ```
+///
+/// ## Traits
+///
+/// Rustdoc doesn't do a good job at documenting type alias implementations, but
+/// `NiceSignedPercent` has a bunch, including:
+///
+/// * `AsRef<[u8]>`
+/// * `AsRef<str>`
+/// * `Borrow<[u8]>`
+/// * `Borrow<str>`
+/// * `Clone`
+/// * `Copy`
+/// * `Default`
+/// * `Deref<Target=[u8]>`
+/// * `Display`
+/// * `Eq` / `PartialEq`
+/// * `Hash`
+/// * `Ord` / `PartialOrd`
+pub type NiceSignedPercent = NiceWrapper<SIZE>;
+
+super::nice_debug!(NiceSignedPercent, percent_value);
+
+impl Default for NiceSignedPercent {
+	#[inline]
+	fn default() -> Self { Self::ZERO }
+}
+
+/// # Helper: From
+///
+/// This code is identical for `f32` and `f64` types.
+macro_rules! nice_from {
+	($($float:ty),+ $(,)?) => ($(
+		impl From<$float> for NiceSignedPercent {
+			#[inline]
+			fn from(num: $float) -> Self {
+				let mut out = Self::ZERO;
+				out.set(NicePercent::from(num.abs()), num < 0.0);
+				out
+			}
+		}
+	)+);
+}
+
+nice_from!(f32, f64);
+
+impl NiceSignedPercent {
+	/// # Minimum Value.
+	///
+	/// Negative one hundred percent.
+	///
+	/// ```
+	/// use dactyl::NiceSignedPercent;
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::MIN.as_str(),
+	///     "-100.00%"
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::MIN,
+	///     NiceSignedPercent::from(-1_f32),
+	/// );
+	/// ```
+	pub const MIN: Self = Self {
+		inner: *b"-100.00%",
+		from: 0,
+	};
+
+	/// # Zero.
+	///
+	/// Zero percent.
+	///
+	/// ```
+	/// use dactyl::NiceSignedPercent;
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::ZERO.as_str(),
+	///     "0.00%"
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::ZERO,
+	///     NiceSignedPercent::from(-0_f32),
+	/// );
+	/// ```
+	pub const ZERO: Self = Self {
+		inner: *b"0000.00%",
+		from: SIZE - 5,
+	};
+
+	/// # Maximum Value.
+	///
+	/// One hundred percent.
+	///
+	/// ```
+	/// use dactyl::NiceSignedPercent;
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::MAX.as_str(),
+	///     "100.00%"
+	/// );
+	///
+	/// assert_eq!(
+	///     NiceSignedPercent::MAX,
+	///     NiceSignedPercent::from(1_f32),
+	/// );
+	/// ```
+	pub const MAX: Self = Self {
+		inner: *b"0100.00%",
+		from: 1,
+	};
+}
+
+impl NiceSignedPercent {
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new nice percent.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceSignedPercent;
+	///
+	/// let mut num = NiceSignedPercent::from(0.85);
+	/// assert_eq!(num.as_str(), "85.00%");
+	///
+	/// num.replace(-0.334);
+	/// assert_eq!(num.as_str(), "-33.40%");
+	/// ```
+	pub fn replace(&mut self, num: f32) {
+		self.set(NicePercent::from(num.abs()), num < 0.0);
+	}
+
+	/// # Set.
+	///
+	/// Copy the (absolute) percent into place, adding a minus sign if
+	/// negative and non-zero.
+	fn set(&mut self, pct: NicePercent, neg: bool) {
+		let src = pct.as_bytes();
+		self.from = SIZE - src.len();
+		self.inner[self.from..].copy_from_slice(src);
+		if neg && pct != NicePercent::MIN {
+			self.from -= 1;
+			self.inner[self.from] = b'-';
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[expect(clippy::cast_precision_loss, reason = "It is what it is.")]
+	fn t_nice_signed_percent() {
+		/// # Total.
+		const TOTAL: i32 = 10_000_i32;
+
+		let mut last = NiceSignedPercent::MIN;
+		for i in -TOTAL..=TOTAL {
+			let fraction = i as f32 / TOTAL as f32;
+			let nice = NiceSignedPercent::from(fraction);
+			assert_eq!(
+				nice.as_str(),
+				format!("{:0.02}%", fraction * 100_f32),
+				"{i}/{TOTAL} (f32)",
+			);
+
+			last.replace(fraction);
+			assert_eq!(last, nice);
+
+			let fraction = f64::from(i) / f64::from(TOTAL);
+			assert_eq!(
+				NiceSignedPercent::from(fraction).as_str(),
+				format!("{:0.02}%", fraction * 100_f64),
+				"{i}/{TOTAL} (f64)",
+			);
+		}
+
+		// And a few edge cases.
+		assert_eq!(NiceSignedPercent::default(), NiceSignedPercent::ZERO);
+		assert_eq!(NiceSignedPercent::from(f64::NAN), NiceSignedPercent::ZERO);
+		assert_eq!(NiceSignedPercent::from(f64::NEG_INFINITY), NiceSignedPercent::ZERO);
+		assert_eq!(NiceSignedPercent::from(-0.000_01_f64), NiceSignedPercent::ZERO);
+		assert_eq!(NiceSignedPercent::from(-1.03_f64), NiceSignedPercent::MIN);
+		assert_eq!(NiceSignedPercent::from(10_f64), NiceSignedPercent::MAX);
+	}
+}
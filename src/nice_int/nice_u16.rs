@@ -2,7 +2,11 @@
 # Dactyl: Nice u16.
 */
 
-use crate::NiceWrapper;
+use crate::{
+	Grouping,
+	NiceSeparator,
+	NiceWrapper,
+};
 use std::num::NonZeroU16;
 
 
@@ -49,6 +53,7 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b',', b'0', b'0', b'0'];
 /// * `Eq` / `PartialEq`
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<u16>` / `PartialOrd<u16>` (and vice versa)
 ///
 /// You can instantiate a `NiceU16` with:
 ///
@@ -56,12 +61,23 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b',', b'0', b'0', b'0'];
 /// * `From<Option<u16>>`
 /// * `From<NonZeroU16>`
 /// * `From<Option<NonZeroU16>>`
+/// * `FromStr` (canonical formatting only)
 ///
 /// When converting from a `None`, the result will be equivalent to zero.
 pub type NiceU16 = NiceWrapper<SIZE>;
 
+super::nice_debug!(NiceU16, value);
 super::nice_default!(NiceU16, ZERO, SIZE);
 super::nice_from_nz!(NiceU16, NonZeroU16);
+super::nice_extend!(NiceU16, u16, "65_535");
+super::nice_pad!(NiceU16, u16, 5, "1234", "01234");
+super::nice_full!(NiceU16, "1234_u16", "01,234");
+super::nice_extremes_with!(NiceU16, "u16::MAX", "65_535");
+super::nice_grouping!(NiceU16, u16, "65_535_u16", "6,5535", "65.535");
+super::nice_cmp!(NiceU16, u16);
+super::nice_get!(NiceU16, u16, "12_345_u16");
+super::nice_from_str!(NiceU16, u16, "12,345");
+super::nice_replace_from!(NiceU16, u16, "12_345", "12,345");
 
 impl From<u16> for NiceU16 {
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
@@ -153,28 +169,85 @@ impl NiceU16 {
 	#[must_use]
 	/// # New Instance w/ Custom Separator.
 	///
-	/// Create a new instance, defining any arbitrary ASCII byte as the
-	/// thousands separator.
+	/// Create a new instance using an alternative [`NiceSeparator`] — or
+	/// anything that converts into one — as the thousands separator.
 	///
 	/// If you're good with commas, just use [`NiceU16::from`] instead.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use dactyl::NiceU16;
+	/// use dactyl::{NiceSeparator, NiceU16};
 	///
 	/// let num = NiceU16::from(31415_u16);
 	/// assert_eq!(num.as_str(), "31,415");
 	///
-	/// let num = NiceU16::with_separator(31415_u16, b'_');
+	/// let num = NiceU16::with_nice_separator(31415_u16, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "31_415");
 	/// ```
+	pub fn with_nice_separator<S: Into<NiceSeparator>>(num: u16, sep: S) -> Self {
+		Self::with_byte_separator(num, sep.into().as_byte())
+	}
+
+	#[deprecated(note = "use NiceU16::with_nice_separator or NiceU16::with_const_separator instead")]
+	#[must_use]
+	/// # New Instance w/ Custom Separator (Raw).
+	///
+	/// Create a new instance, defining any arbitrary ASCII byte as the
+	/// thousands separator.
+	///
+	/// ASCII digits would be indistinguishable from the number itself, so are
+	/// replaced with the default comma.
+	///
+	/// This is deprecated in favor of the infallible
+	/// [`NiceU16::with_nice_separator`], or [`NiceU16::with_const_separator`]
+	/// for less common separators.
 	///
 	/// ## Panics
 	///
 	/// This method will panic if the separator is invalid ASCII.
 	pub fn with_separator(num: u16, sep: u8) -> Self {
-		assert!(sep.is_ascii(), "Invalid separator.");
+		Self::with_byte_separator(num, Self::sanitize_separator(sep))
+	}
+
+	#[must_use]
+	/// # New Instance w/ Const Separator.
+	///
+	/// This is a compile-time variation of [`NiceU16::with_nice_separator`]
+	/// that accepts any ASCII byte, for projects with a fixed house style:
+	/// the separator is validated when the code is built, so there's no
+	/// runtime check to pay for.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceSeparator, NiceU16};
+	///
+	/// let num = NiceU16::with_const_separator::<b'_'>(12345_u16);
+	/// assert_eq!(num.as_str(), "12_345");
+	/// assert_eq!(num, NiceU16::with_nice_separator(12345_u16, NiceSeparator::Underscore));
+	///
+	/// let num = NiceU16::with_const_separator::<b'-'>(12345_u16);
+	/// assert_eq!(num.as_str(), "12-345");
+	/// ```
+	///
+	/// Invalid separators — non-ASCII bytes and digits — won't compile.
+	///
+	/// ```compile_fail
+	/// use dactyl::NiceU16;
+	///
+	/// let num = NiceU16::with_const_separator::<b'0'>(1234_u16);
+	/// ```
+	pub fn with_const_separator<const SEP: u8>(num: u16) -> Self {
+		const { assert!(SEP.is_ascii() && ! SEP.is_ascii_digit(), "Invalid separator."); }
+		Self::with_byte_separator(num, SEP)
+	}
+
+	/// # New Instance w/ Byte Separator.
+	///
+	/// This holds the shared logic for the separator constructors, which are
+	/// responsible for validating `sep`.
+	fn with_byte_separator(num: u16, sep: u8) -> Self {
 		let mut out = Self::from(num);
 		out.inner[2] = sep;
 		out
@@ -200,15 +273,21 @@ impl NiceU16 {
 	/// Note that custom separators, if any, are preserved.
 	///
 	/// ```
-	/// use dactyl::NiceU16;
+	/// use dactyl::{NiceSeparator, NiceU16};
 	///
-	/// let mut num = NiceU16::with_separator(123_u16, b'_');
+	/// let mut num = NiceU16::with_nice_separator(123_u16, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "123");
 	///
 	/// num.replace(12345);
 	/// assert_eq!(num.as_str(), "12_345");
 	/// ```
-	pub fn replace(&mut self, num: u16) {
+	pub fn replace(&mut self, num: u16) -> &mut Self {
+		if self.is_myriad() {
+			self.replace_myriad(u64::from(num));
+			return self;
+		}
+
+		let old = self.from;
 		if 999 < num {
 			let (num, rem) = (num / 1000, num % 1000);
 			self.inner[3..].copy_from_slice(crate::triple(rem as usize).as_slice());
@@ -230,10 +309,69 @@ impl NiceU16 {
 			self.inner[4..].copy_from_slice(crate::double(num as usize).as_slice());
 			self.from = if self.inner[4] == b'0' { 5 } else { 4 };
 		}
+		if old < self.from { self.zero_stale(old); }
+		self
 	}
 }
 
 
+impl NiceU16 {
+	#[must_use]
+	/// # From Binary-Coded Decimal.
+	///
+	/// Decode a packed four-digit BCD value — one digit per nibble, most
+	/// significant first — returning `None` if any nibble is greater than
+	/// nine.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU16;
+	///
+	/// assert_eq!(NiceU16::from_bcd(0x2024).unwrap().as_str(), "2,024");
+	/// assert!(NiceU16::from_bcd(0x20F4).is_none());
+	/// ```
+	pub fn from_bcd(bcd: u16) -> Option<Self> {
+		let mut num = 0;
+		let mut shift = 16;
+		while shift != 0 {
+			shift -= 4;
+			let digit = (bcd >> shift) & 0x0F;
+			if 9 < digit { return None; }
+			num = num * 10 + digit;
+		}
+		Some(Self::from(num))
+	}
+
+	#[must_use]
+	/// # To Binary-Coded Decimal.
+	///
+	/// Encode the value as a packed four-digit BCD value, returning `None` if
+	/// it is greater than `9999` (and so won't fit).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU16;
+	///
+	/// assert_eq!(NiceU16::from(2024_u16).to_bcd(), Some(0x2024));
+	/// assert_eq!(NiceU16::from(10_000_u16).to_bcd(), None);
+	/// ```
+	pub const fn to_bcd(&self) -> Option<u16> {
+		let mut num = self.get();
+		if 9999 < num { return None; }
+
+		let mut out = 0;
+		let mut shift = 0;
+		while num != 0 {
+			out |= (num % 10) << shift;
+			num /= 10;
+			shift += 4;
+		}
+		Some(out)
+	}
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -325,4 +463,68 @@ mod tests {
 		assert_eq!(num.as_str(), String::from(num));
 		assert_eq!(num.as_bytes(), Vec::<u8>::from(num));
 	}
+
+	#[test]
+	fn t_full() {
+		let mut last = NiceU16::MAX;
+		for i in (0..=u16::MAX).rev() {
+			let expected = format!("{:02},{:03}", i / 1000, i % 1000);
+			assert_eq!(NiceU16::from(i).as_str_full(), expected);
+
+			// Replacement shouldn't leave any old digits behind.
+			last.replace(i);
+			assert_eq!(last.as_str_full(), expected);
+		}
+	}
+
+	#[test]
+	fn t_padded() {
+		for i in 0..=u16::MAX {
+			let nice = NiceU16::from(i);
+			for width in 0..=6 {
+				assert_eq!(nice.padded(width).as_str(), format!("{i:0width$}", width=width.min(5)));
+			}
+		}
+	}
+
+	#[test]
+	fn t_cmp() {
+		for i in 0..=u16::MAX {
+			for nice in [NiceU16::from(i), NiceU16::with_grouping(i, Grouping::Myriad, NiceSeparator::Underscore)] {
+				assert_eq!(nice, i);
+				assert_eq!(i, nice);
+				assert_eq!(nice.get(), i);
+				assert_eq!(nice.partial_cmp(&i), Some(std::cmp::Ordering::Equal));
+				if i != 0 {
+					assert!(i - 1 < nice);
+					assert!(nice > i - 1);
+					assert_ne!(nice, i - 1);
+				}
+				if i != u16::MAX {
+					assert!(i + 1 > nice);
+					assert!(nice < i + 1);
+					assert_ne!(nice, i + 1);
+				}
+			}
+		}
+
+		// Empty can't be compared.
+		assert_ne!(NiceU16::empty(), 0);
+		assert_eq!(NiceU16::empty().partial_cmp(&0), None);
+	}
+
+	#[test]
+	fn t_bcd() {
+		for i in 0..=u16::MAX {
+			let hex = format!("{i:04x}");
+			match NiceU16::from_bcd(i) {
+				Some(nice) => {
+					assert_eq!(nice, NiceU16::from(hex.parse::<u16>().unwrap()));
+					assert_eq!(nice.to_bcd(), Some(i));
+				},
+				None => { assert!(hex.bytes().any(|b| ! b.is_ascii_digit())); },
+			}
+			assert_eq!(NiceU16::from(i).to_bcd().is_some(), i < 10_000);
+		}
+	}
 }
@@ -2,7 +2,11 @@
 # Dactyl: Nice u32.
 */
 
-use crate::NiceWrapper;
+use crate::{
+	Grouping,
+	NiceSeparator,
+	NiceWrapper,
+};
 use std::num::NonZeroU32;
 
 
@@ -32,6 +36,10 @@ macro_rules! inner {
 ///     NiceU32::from(33231).as_str(),
 ///     "33,231"
 /// );
+///
+/// // Instances can be compared directly against u32s too.
+/// assert_eq!(NiceU32::from(33231_u32), 33231_u32);
+/// assert!(NiceU32::from(33231_u32) < 100_000_u32);
 /// ```
 ///
 /// ## Traits
@@ -51,6 +59,7 @@ macro_rules! inner {
 /// * `Eq` / `PartialEq`
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<u32>` / `PartialOrd<u32>` (and vice versa)
 ///
 /// You can instantiate a `NiceU32` with:
 ///
@@ -58,13 +67,24 @@ macro_rules! inner {
 /// * `From<Option<u32>>`
 /// * `From<NonZeroU32>`
 /// * `From<Option<NonZeroU32>>`
+/// * `FromStr` (canonical formatting only)
 ///
 /// When converting from a `None`, the result will be equivalent to zero.
 pub type NiceU32 = NiceWrapper<SIZE>;
 
+super::nice_debug!(NiceU32, value);
 super::nice_default!(NiceU32, inner!(b','), SIZE);
 super::nice_from_nz!(NiceU32, NonZeroU32);
 super::nice_parse!(NiceU32, u32);
+super::nice_extend!(NiceU32, u32, "4_294_967_295");
+super::nice_pad!(NiceU32, u32, 10, "1234", "0000001234");
+super::nice_full!(NiceU32, "1234_u32", "0,000,001,234");
+super::nice_extremes_with!(NiceU32, "u32::MAX", "4_294_967_295");
+super::nice_grouping!(NiceU32, u32, "123_456_789_u32", "1,2345,6789", "123.456.789");
+super::nice_cmp!(NiceU32, u32);
+super::nice_get!(NiceU32, u32, "123_456_789_u32");
+super::nice_from_str!(NiceU32, u32, "123,456,789");
+super::nice_replace_from!(NiceU32, u32, "3_141_592", "3,141,592");
 
 impl NiceU32 {
 	/// # Minimum Value.
@@ -116,28 +136,85 @@ impl NiceU32 {
 	#[must_use]
 	/// # New Instance w/ Custom Separator.
 	///
-	/// Create a new instance, defining any arbitrary ASCII byte as the
-	/// thousands separator.
+	/// Create a new instance using an alternative [`NiceSeparator`] — or
+	/// anything that converts into one — as the thousands separator.
 	///
 	/// If you're good with commas, just use [`NiceU32::from`] instead.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use dactyl::NiceU32;
+	/// use dactyl::{NiceSeparator, NiceU32};
 	///
 	/// let num = NiceU32::from(3141592653_u32);
 	/// assert_eq!(num.as_str(), "3,141,592,653");
 	///
-	/// let num = NiceU32::with_separator(3141592653_u32, b'_');
+	/// let num = NiceU32::with_nice_separator(3141592653_u32, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "3_141_592_653");
 	/// ```
+	pub fn with_nice_separator<S: Into<NiceSeparator>>(num: u32, sep: S) -> Self {
+		Self::with_byte_separator(num, sep.into().as_byte())
+	}
+
+	#[deprecated(note = "use NiceU32::with_nice_separator or NiceU32::with_const_separator instead")]
+	#[must_use]
+	/// # New Instance w/ Custom Separator (Raw).
+	///
+	/// Create a new instance, defining any arbitrary ASCII byte as the
+	/// thousands separator.
+	///
+	/// ASCII digits would be indistinguishable from the number itself, so are
+	/// replaced with the default comma.
+	///
+	/// This is deprecated in favor of the infallible
+	/// [`NiceU32::with_nice_separator`], or [`NiceU32::with_const_separator`]
+	/// for less common separators.
 	///
 	/// ## Panics
 	///
 	/// This method will panic if the separator is invalid ASCII.
 	pub fn with_separator(num: u32, sep: u8) -> Self {
-		assert!(sep.is_ascii(), "Invalid separator.");
+		Self::with_byte_separator(num, Self::sanitize_separator(sep))
+	}
+
+	#[must_use]
+	/// # New Instance w/ Const Separator.
+	///
+	/// This is a compile-time variation of [`NiceU32::with_nice_separator`]
+	/// that accepts any ASCII byte, for projects with a fixed house style:
+	/// the separator is validated when the code is built, so there's no
+	/// runtime check to pay for.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceSeparator, NiceU32};
+	///
+	/// let num = NiceU32::with_const_separator::<b'_'>(3141592653_u32);
+	/// assert_eq!(num.as_str(), "3_141_592_653");
+	/// assert_eq!(num, NiceU32::with_nice_separator(3141592653_u32, NiceSeparator::Underscore));
+	///
+	/// let num = NiceU32::with_const_separator::<b'-'>(3141592653_u32);
+	/// assert_eq!(num.as_str(), "3-141-592-653");
+	/// ```
+	///
+	/// Invalid separators — non-ASCII bytes and digits — won't compile.
+	///
+	/// ```compile_fail
+	/// use dactyl::NiceU32;
+	///
+	/// let num = NiceU32::with_const_separator::<b'0'>(1234_u32);
+	/// ```
+	pub fn with_const_separator<const SEP: u8>(num: u32) -> Self {
+		const { assert!(SEP.is_ascii() && ! SEP.is_ascii_digit(), "Invalid separator."); }
+		Self::with_byte_separator(num, SEP)
+	}
+
+	/// # New Instance w/ Byte Separator.
+	///
+	/// This holds the shared logic for the separator constructors, which are
+	/// responsible for validating `sep`.
+	fn with_byte_separator(num: u32, sep: u8) -> Self {
 		let mut out = Self {
 			inner: inner!(sep),
 			from: SIZE,
@@ -160,22 +237,32 @@ impl NiceU32 {
 	///
 	/// num.replace(12345);
 	/// assert_eq!(num.as_str(), "12,345");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace(678).as_str(), "678");
 	/// ```
 	///
 	/// Note that custom separators, if any, are preserved.
 	///
 	/// ```
-	/// use dactyl::NiceU32;
+	/// use dactyl::{NiceSeparator, NiceU32};
 	///
-	/// let mut num = NiceU32::with_separator(3141592653_u32, b'_');
+	/// let mut num = NiceU32::with_nice_separator(3141592653_u32, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "3_141_592_653");
 	///
 	/// num.replace(12345);
 	/// assert_eq!(num.as_str(), "12_345");
 	/// ```
-	pub fn replace(&mut self, num: u32) {
-		self.from = SIZE;
-		self.parse(num);
+	#[inline]
+	pub fn replace(&mut self, num: u32) -> &mut Self {
+		if self.is_myriad() { self.replace_myriad(u64::from(num)); }
+		else {
+			let old = self.from;
+			self.from = SIZE;
+			self.parse(num);
+			if old < self.from { self.zero_stale(old); }
+		}
+		self
 	}
 }
 
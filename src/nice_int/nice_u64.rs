@@ -2,10 +2,16 @@
 # Dactyl: Nice u64.
 */
 
-use crate::NiceWrapper;
+use crate::{
+	Grouping,
+	NiceSeparator,
+	NiceWrapper,
+	traits::SaturatingFrom,
+};
 use std::num::{
 	NonZeroU64,
 	NonZeroUsize,
+	TryFromIntError,
 };
 
 
@@ -54,6 +60,7 @@ macro_rules! inner {
 /// * `Eq` / `PartialEq`
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<u64>` / `PartialOrd<u64>` (and vice versa)
 ///
 /// You can instantiate a `NiceU64` with:
 ///
@@ -61,13 +68,38 @@ macro_rules! inner {
 /// * `From<Option<u64>>`
 /// * `From<NonZeroU64>`
 /// * `From<Option<NonZeroU64>>`
+/// * `FromStr` (canonical formatting only)
 /// * `From<usize>`
 /// * `From<Option<usize>>`
 /// * `From<NonZeroUsize>`
 /// * `From<Option<NonZeroUsize>>`
+/// * `From<u128>` (saturating; see below)
+/// * `TryFrom<i32>`
+/// * `TryFrom<i64>`
 ///
 /// When converting from a `None`, the result will be equivalent to zero.
 ///
+/// The signed conversions are fallible, returning a [`TryFromIntError`] for
+/// negative values. (For signed output, see [`NiceU64::delta`].)
+///
+/// ## Saturation
+///
+/// **`From<u128>` silently clamps** values larger than [`u64::MAX`] to
+/// [`u64::MAX`], so an out-of-range value will _look_ like `u64::MAX` rather
+/// than fail. If that distinction matters, convert with `u64::try_from`
+/// first.
+///
+/// ```
+/// use dactyl::NiceU64;
+///
+/// assert!(NiceU64::try_from(-5_i32).is_err());
+/// assert_eq!(NiceU64::try_from(12_345_i64).unwrap().as_str(), "12,345");
+///
+/// // Too big!
+/// assert_eq!(NiceU64::from(u128::MAX), NiceU64::MAX);
+/// assert!(u64::try_from(u128::MAX).is_err());
+/// ```
+///
 /// For targets with 128-bit pointers, `usize` values cannot exceed [`u64::MAX`]
 /// or a panic will ensue.
 pub type NiceU64 = NiceWrapper<SIZE>;
@@ -76,9 +108,53 @@ impl From<usize> for NiceU64 {
 	fn from(num: usize) -> Self { Self::from(num as u64) }
 }
 
+/// # Helper: Saturating From.
+macro_rules! nice_from_saturating {
+	($($ty:ty),+) => ($(
+		impl From<$ty> for NiceU64 {
+			#[inline]
+			/// # Saturating From.
+			///
+			/// This will never fail, however out-of-range values will be
+			/// silently clamped to `0..=u64::MAX` before parsing.
+			fn from(num: $ty) -> Self { Self::from(u64::saturating_from(num)) }
+		}
+	)+);
+}
+
+nice_from_saturating!(u128);
+
+/// # Helper: Try From Signed.
+macro_rules! nice_try_from_signed {
+	($($ty:ty),+) => ($(
+		impl TryFrom<$ty> for NiceU64 {
+			type Error = TryFromIntError;
+
+			#[inline]
+			/// # Try From Signed.
+			///
+			/// Negative values are not supported and will return an error.
+			fn try_from(num: $ty) -> Result<Self, Self::Error> {
+				u64::try_from(num).map(Self::from)
+			}
+		}
+	)+);
+}
+
+nice_try_from_signed!(i32, i64);
+
+super::nice_debug!(NiceU64, value);
 super::nice_default!(NiceU64, inner!(b','), SIZE);
 super::nice_from_nz!(NiceU64, NonZeroU64, NonZeroUsize);
 super::nice_parse!(NiceU64, u64);
+super::nice_extend!(NiceU64, u64, "18_446_744_073_709_551_615");
+super::nice_pad!(NiceU64, u64, 20, "1234", "00000000000000001234");
+super::nice_full!(NiceU64, "1234_u64", "00,000,000,000,000,001,234");
+super::nice_extremes_with!(NiceU64, "u64::MAX", "18_446_744_073_709_551_615");
+super::nice_grouping!(NiceU64, u64, "123_456_789_u64", "1,2345,6789", "123.456.789");
+super::nice_cmp!(NiceU64, u64);
+super::nice_from_str!(NiceU64, u64, "18,446,744,073,709,551,615");
+super::nice_replace_from!(NiceU64, u64, "3_141_592_653", "3,141,592,653");
 
 impl NiceU64 {
 	/// # Minimum Value.
@@ -130,28 +206,91 @@ impl NiceU64 {
 	#[must_use]
 	/// # New Instance w/ Custom Separator.
 	///
-	/// Create a new instance, defining any arbitrary ASCII byte as the
-	/// thousands separator.
+	/// Create a new instance using an alternative [`NiceSeparator`] — or
+	/// anything that converts into one — as the thousands separator.
 	///
 	/// If you're good with commas, just use [`NiceU64::from`] instead.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use dactyl::NiceU64;
+	/// use dactyl::{NiceSeparator, NiceU64};
 	///
 	/// let num = NiceU64::from(3141592653589793238_u64);
 	/// assert_eq!(num.as_str(), "3,141,592,653,589,793,238");
 	///
-	/// let num = NiceU64::with_separator(3141592653589793238_u64, b'_');
+	/// let num = NiceU64::with_nice_separator(3141592653589793238_u64, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "3_141_592_653_589_793_238");
 	/// ```
+	pub fn with_nice_separator<S: Into<NiceSeparator>>(num: u64, sep: S) -> Self {
+		Self::with_byte_separator(num, sep.into().as_byte())
+	}
+
+	#[deprecated(note = "use NiceU64::with_nice_separator or NiceU64::with_const_separator instead")]
+	#[must_use]
+	/// # New Instance w/ Custom Separator (Raw).
+	///
+	/// Create a new instance, defining any arbitrary ASCII byte as the
+	/// thousands separator.
+	///
+	/// ASCII digits would be indistinguishable from the number itself, so are
+	/// replaced with the default comma.
+	///
+	/// This is deprecated in favor of the infallible
+	/// [`NiceU64::with_nice_separator`], or [`NiceU64::with_const_separator`]
+	/// for less common separators.
 	///
 	/// ## Panics
 	///
 	/// This method will panic if the separator is invalid ASCII.
 	pub fn with_separator(num: u64, sep: u8) -> Self {
-		assert!(sep.is_ascii(), "Invalid separator.");
+		Self::with_byte_separator(num, Self::sanitize_separator(sep))
+	}
+
+	#[must_use]
+	/// # New Instance w/ Const Separator.
+	///
+	/// This is a compile-time variation of [`NiceU64::with_nice_separator`]
+	/// that accepts any ASCII byte, for projects with a fixed house style:
+	/// the separator is validated when the code is built, so there's no
+	/// runtime check to pay for.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceSeparator, NiceU64};
+	///
+	/// let num = NiceU64::with_const_separator::<b'_'>(3141592653589793238_u64);
+	/// assert_eq!(num.as_str(), "3_141_592_653_589_793_238");
+	/// assert_eq!(num, NiceU64::with_nice_separator(3141592653589793238_u64, NiceSeparator::Underscore));
+	///
+	/// let num = NiceU64::with_const_separator::<b'-'>(3141592653589793238_u64);
+	/// assert_eq!(num.as_str(), "3-141-592-653-589-793-238");
+	/// ```
+	///
+	/// Invalid separators — non-ASCII bytes and digits — won't compile.
+	///
+	/// ```compile_fail
+	/// use dactyl::NiceU64;
+	///
+	/// let num = NiceU64::with_const_separator::<255>(1234_u64);
+	/// ```
+	///
+	/// ```compile_fail
+	/// use dactyl::NiceU64;
+	///
+	/// let num = NiceU64::with_const_separator::<b'7'>(1234_u64);
+	/// ```
+	pub fn with_const_separator<const SEP: u8>(num: u64) -> Self {
+		const { assert!(SEP.is_ascii() && ! SEP.is_ascii_digit(), "Invalid separator."); }
+		Self::with_byte_separator(num, SEP)
+	}
+
+	/// # New Instance w/ Byte Separator.
+	///
+	/// This holds the shared logic for the separator constructors, which are
+	/// responsible for validating `sep`.
+	fn with_byte_separator(num: u64, sep: u8) -> Self {
 		let mut out = Self {
 			inner: inner!(sep),
 			from: SIZE,
@@ -179,17 +318,201 @@ impl NiceU64 {
 	/// Note that custom separators, if any, are preserved.
 	///
 	/// ```
-	/// use dactyl::NiceU64;
+	/// use dactyl::{NiceSeparator, NiceU64};
 	///
-	/// let mut num = NiceU64::with_separator(3141592653_u64, b'_');
+	/// let mut num = NiceU64::with_nice_separator(3141592653_u64, NiceSeparator::Underscore);
 	/// assert_eq!(num.as_str(), "3_141_592_653");
 	///
 	/// num.replace(12345);
 	/// assert_eq!(num.as_str(), "12_345");
 	/// ```
-	pub fn replace(&mut self, num: u64) {
-		self.from = SIZE;
-		self.parse(num);
+	#[inline]
+	pub fn replace(&mut self, num: u64) -> &mut Self {
+		// Signed deltas and myriads need some extra care.
+		if self.is_myriad() || self.is_signed() { self.replace_slow(num); }
+		else {
+			let old = self.from;
+			self.from = SIZE;
+			self.parse(num);
+			if old < self.from { self.zero_stale(old); }
+		}
+		self
+	}
+
+	#[cold]
+	#[inline(never)]
+	/// # Replace (Slow Path).
+	///
+	/// This is [`NiceU64::replace`] for buffers holding a signed
+	/// [delta](NiceU64::delta) or using myriad grouping.
+	fn replace_slow(&mut self, num: u64) {
+		self.unsign();
+		if self.is_myriad() { self.replace_myriad(num); }
+		else {
+			let old = self.from;
+			self.from = SIZE;
+			self.parse(num);
+			self.zero_stale(old);
+		}
+	}
+
+	#[must_use]
+	/// # Get (`u64`).
+	///
+	/// Return the numeric value, recomputed from the formatted buffer (so the
+	/// number and string forms can travel together).
+	///
+	/// For signed [deltas](NiceU64::delta), the absolute value is returned.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU64;
+	///
+	/// let nice = NiceU64::from(12_345_678_901_u64);
+	/// assert_eq!(nice.get(), 12_345_678_901_u64);
+	///
+	/// assert_eq!(NiceU64::delta(-5).get(), 5);
+	/// ```
+	pub const fn get(&self) -> u64 { self.digits() }
+}
+
+impl NiceU64 {
+	#[doc(hidden)]
+	#[must_use]
+	/// # Const Length.
+	///
+	/// Return the formatted length of `num`. This is used by the
+	/// [`nice`](crate::nice) macro and is not otherwise useful.
+	pub const fn __const_len(num: u64) -> usize { crate::nice_width_u64(num) }
+
+	#[doc(hidden)]
+	#[must_use]
+	/// # Const Bytes.
+	///
+	/// Format `num` at compile time. This is used by the [`nice`](crate::nice)
+	/// macro and is not otherwise useful.
+	///
+	/// ## Panics
+	///
+	/// This will panic if `N` does not match [`NiceU64::__const_len`].
+	pub const fn __const_bytes<const N: usize>(mut num: u64) -> [u8; N] {
+		assert!(N == Self::__const_len(num), "Invalid length.");
+		let mut out = [b','; N];
+		let mut idx = N;
+		let mut digits = 0;
+		loop {
+			if digits != 0 && digits % 3 == 0 { idx -= 1; }
+			idx -= 1;
+			out[idx] = (num % 10) as u8 + b'0';
+			num /= 10;
+			digits += 1;
+			if num == 0 { break; }
+		}
+		out
+	}
+}
+
+impl NiceU64 {
+	#[must_use]
+	/// # New Signed Delta.
+	///
+	/// Create a new instance from a signed value, _always_ prefixing it with
+	/// a `+` or `-`, as is common for diff-type output.
+	///
+	/// Like the standard library's `{:+}` formatting, zero is treated as
+	/// positive.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU64;
+	///
+	/// assert_eq!(NiceU64::delta(1234_i64).as_str(), "+1,234");
+	/// assert_eq!(NiceU64::delta(-567_i64).as_str(), "-567");
+	/// assert_eq!(NiceU64::delta(0_i64).as_str(), "+0");
+	/// assert_eq!(
+	///     NiceU64::delta(i64::MIN).as_str(),
+	///     "-9,223,372,036,854,775,808",
+	/// );
+	/// ```
+	pub fn delta(num: i64) -> Self {
+		let mut out = Self::empty();
+		out.parse_delta(num);
+		out
+	}
+
+	/// # Replace (Signed Delta).
+	///
+	/// Reuse the backing storage behind `self` to hold a new signed delta.
+	/// See [`NiceU64::delta`] for more details.
+	///
+	/// As with [`NiceU64::replace`], custom separators, if any, are
+	/// preserved.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::{NiceSeparator, NiceU64};
+	///
+	/// let mut num = NiceU64::with_nice_separator(3141592653_u64, NiceSeparator::Underscore);
+	/// assert_eq!(num.as_str(), "3_141_592_653");
+	///
+	/// num.replace_delta(-123_456);
+	/// assert_eq!(num.as_str(), "-123_456");
+	///
+	/// num.replace_delta(1_234_567);
+	/// assert_eq!(num.as_str(), "+1_234_567");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace_delta(-5).as_str(), "-5");
+	/// ```
+	pub fn replace_delta(&mut self, num: i64) -> &mut Self {
+		self.unsign();
+		let old = self.from;
+		self.parse_delta(num);
+		self.zero_stale(old);
+		self
+	}
+
+	/// # Parse Delta.
+	///
+	/// Parse the absolute value, then prepend the sign.
+	fn parse_delta(&mut self, num: i64) {
+		if self.is_myriad() { self.parse_myriad(num.unsigned_abs()); }
+		else {
+			self.from = SIZE;
+			self.parse(num.unsigned_abs());
+		}
+		self.from -= 1;
+		self.inner[self.from] = if num < 0 { b'-' } else { b'+' };
+	}
+
+	#[must_use]
+	/// # Is Signed?
+	///
+	/// Unsigned values always begin with a digit, so anything else in the
+	/// leading position must be a [delta](NiceU64::delta) sign.
+	const fn is_signed(&self) -> bool {
+		self.from < SIZE && ! self.inner[self.from].is_ascii_digit()
+	}
+
+	/// # Remove Sign.
+	///
+	/// Signs are written directly in front of the leading digit, which might
+	/// be a slot otherwise reserved for a separator. If that is the case, the
+	/// separator is restored (by copying one of its neighbors) so the next
+	/// parse isn't corrupted.
+	///
+	/// Unsigned values never begin with a separator, so this is a no-op for
+	/// them.
+	const fn unsign(&mut self) {
+		let step = if self.is_myriad() { 5 } else { 4 };
+		if self.from < SIZE && (SIZE - self.from) % step == 0 {
+			self.inner[self.from] =
+				if self.from == SIZE - step { self.inner[SIZE - step * 2] }
+				else { self.inner[SIZE - step] };
+		}
 	}
 }
 
@@ -199,6 +522,7 @@ impl NiceU64 {
 mod tests {
 	use super::*;
 	use num_format::{ToFormattedString, Locale};
+	use crate::ParseByteError;
 
 	#[test]
 	fn t_nice_u64() {
@@ -233,6 +557,22 @@ mod tests {
 		let foo = Some(13_usize);
 		assert_eq!(NiceU64::from(13_usize), NiceU64::from(foo));
 
+		// Test the saturating conversions.
+		assert_eq!(NiceU64::from(13_u128), NiceU64::from(13_u64));
+		assert_eq!(NiceU64::from(u128::from(u64::MAX) + 1), NiceU64::MAX);
+		assert_eq!(NiceU64::from(u128::MAX), NiceU64::MAX);
+
+		// And the fallible signed ones.
+		assert_eq!(NiceU64::try_from(13_i32), Ok(NiceU64::from(13_u64)));
+		assert_eq!(NiceU64::try_from(0_i32), Ok(NiceU64::MIN));
+		assert_eq!(NiceU64::try_from(i32::MAX).unwrap().as_str(), "2,147,483,647");
+		assert_eq!(NiceU64::try_from(-1_i32), Err(u64::try_from(-1_i32).unwrap_err()));
+		assert!(NiceU64::try_from(i32::MIN).is_err());
+		assert_eq!(NiceU64::try_from(13_i64), Ok(NiceU64::from(13_u64)));
+		assert_eq!(NiceU64::try_from(i64::MAX).unwrap().as_str(), "9,223,372,036,854,775,807");
+		assert_eq!(NiceU64::try_from(-1_i64), Err(u64::try_from(-1_i64).unwrap_err()));
+		assert!(NiceU64::try_from(i64::MIN).is_err());
+
 		// Check ordering too.
 		let one = NiceU64::from(10_u64);
 		let two = NiceU64::from(90_u64);
@@ -264,6 +604,197 @@ mod tests {
 		assert_eq!(last.as_str(), "0");
 	}
 
+	#[test]
+	fn t_delta() {
+		assert_eq!(NiceU64::delta(0).as_str(), "+0");
+		assert_eq!(NiceU64::delta(i64::MAX).as_str(), "+9,223,372,036,854,775,807");
+		assert_eq!(NiceU64::delta(i64::MIN).as_str(), "-9,223,372,036,854,775,808");
+
+		let mut last = NiceU64::with_nice_separator(0, NiceSeparator::Underscore);
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.i64(..) >> rng.u32(0..64)).take(10_000) {
+			let nice = NiceU64::delta(i);
+			let expected = format!(
+				"{}{}",
+				if i < 0 { '-' } else { '+' },
+				i.unsigned_abs().to_formatted_string(&Locale::en),
+			);
+			assert_eq!(nice.as_str(), expected);
+
+			// Replacement should yield the same thing, separators and all.
+			last.replace_delta(i);
+			assert_eq!(last.as_str(), expected.replace(',', "_"));
+
+			// Switching back to unsigned shouldn't leave any signs behind.
+			let u = i.unsigned_abs();
+			last.replace(u);
+			assert_eq!(last, NiceU64::with_nice_separator(u, NiceSeparator::Underscore));
+			assert_eq!(last.as_bytes_full(), NiceU64::with_nice_separator(u, NiceSeparator::Underscore).as_bytes_full());
+		}
+	}
+
+	#[test]
+	fn t_extremes_with() {
+		static MAX: NiceU64 = NiceU64::max_with(NiceSeparator::Apostrophe);
+		assert_eq!(MAX.as_str(), "18'446'744'073'709'551'615");
+
+		for sep in [
+			NiceSeparator::Comma,
+			NiceSeparator::Period,
+			NiceSeparator::Apostrophe,
+			NiceSeparator::Space,
+			NiceSeparator::Underscore,
+		] {
+			assert_eq!(NiceU64::min_with(sep), NiceU64::with_nice_separator(0, sep));
+			assert_eq!(NiceU64::max_with(sep), NiceU64::with_nice_separator(u64::MAX, sep));
+			assert_eq!(
+				NiceU64::max_with(sep).as_bytes_full(),
+				NiceU64::with_nice_separator(u64::MAX, sep).as_bytes_full(),
+			);
+		}
+	}
+
+	#[test]
+	fn t_const_separator() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			let nice = NiceU64::with_const_separator::<b' '>(i);
+			assert_eq!(nice, NiceU64::with_nice_separator(i, NiceSeparator::Space));
+			assert_eq!(nice.as_str(), i.to_formatted_string(&Locale::en).replace(',', " "));
+		}
+	}
+
+	#[test]
+	fn t_nice_macro() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			assert_eq!(NiceU64::__const_len(i), NiceU64::from(i).len());
+		}
+
+		assert_eq!(crate::nice!(0), "0");
+		assert_eq!(crate::nice!(999), "999");
+		assert_eq!(crate::nice!(1000), "1,000");
+		assert_eq!(crate::nice!(123_456), "123,456");
+		assert_eq!(crate::nice!(1_234_567), "1,234,567");
+		assert_eq!(crate::nice!(u64::MAX), NiceU64::MAX.as_str());
+	}
+
+	#[test]
+	fn t_full() {
+		let mut rng = fastrand::Rng::new();
+		let mut last = NiceU64::MAX;
+		let mut myriad = NiceU64::with_grouping(u64::MAX, Grouping::Myriad, NiceSeparator::Comma);
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			let expected = format!("{i:020}")
+				.as_bytes()
+				.rchunks(3)
+				.rev()
+				.map(|c| std::str::from_utf8(c).unwrap())
+				.collect::<Vec<_>>()
+				.join(",");
+			assert_eq!(NiceU64::from(i).as_str_full(), expected);
+
+			// Replacement shouldn't leave any old digits behind.
+			last.replace(i);
+			assert_eq!(last.as_str_full(), expected);
+			myriad.replace(i);
+			assert_eq!(
+				myriad.as_bytes_full(),
+				NiceU64::with_grouping(i, Grouping::Myriad, NiceSeparator::Comma).as_bytes_full(),
+			);
+		}
+	}
+
+	#[test]
+	fn t_myriad() {
+		/// # Myriad-Grouped String.
+		fn myriad(num: u64) -> String {
+			let digits = num.to_string();
+			let mut out = String::new();
+			for (i, c) in digits.chars().enumerate() {
+				if i != 0 && (digits.len() - i) % 4 == 0 { out.push(' '); }
+				out.push(c);
+			}
+			out
+		}
+
+		assert_eq!(NiceU64::with_grouping(0, Grouping::Myriad, NiceSeparator::Space).as_str(), "0");
+		assert_eq!(
+			NiceU64::with_grouping(u64::MAX, Grouping::Myriad, NiceSeparator::Space).as_str(),
+			"1844 6744 0737 0955 1615",
+		);
+		assert_eq!(
+			NiceU64::with_grouping(u64::MAX, Grouping::Thousands, NiceSeparator::Space),
+			NiceU64::with_nice_separator(u64::MAX, NiceSeparator::Space),
+		);
+
+		let mut last = NiceU64::with_grouping(0, Grouping::Myriad, NiceSeparator::Space);
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			let expected = myriad(i);
+			assert_eq!(NiceU64::with_grouping(i, Grouping::Myriad, NiceSeparator::Space).as_str(), expected);
+
+			// Replacements should stick with the grouping, and not leave any
+			// signs behind.
+			last.replace(i);
+			assert_eq!(last.as_str(), expected);
+			assert_eq!(
+				last.as_bytes_full(),
+				NiceU64::with_grouping(i, Grouping::Myriad, NiceSeparator::Space).as_bytes_full(),
+			);
+
+			// Deltas too.
+			let signed = i64::try_from(i).unwrap_or(i64::MAX);
+			last.replace_delta(-signed);
+			assert_eq!(
+				last.as_str(),
+				format!("{}{}", if signed == 0 { '+' } else { '-' }, myriad(signed.unsigned_abs())),
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid separator.")]
+	fn t_myriad_separator() { let _res = NiceU64::myriad(0, b'1'); }
+
+	#[expect(deprecated, reason = "This covers the raw-byte constructors.")]
+	#[test]
+	fn t_digit_separator() {
+		use crate::{NiceU16, NiceU32};
+
+		// Digits can't be told apart from the number, so should fall back
+		// to commas.
+		let nice = NiceU64::with_separator(1234, b'7');
+		assert_eq!(nice.as_str(), "1,234");
+		assert_eq!(nice.get(), 1234);
+		assert_eq!(nice, 1234_u64);
+		assert_eq!(NiceU32::with_separator(1234, b'7').as_str(), "1,234");
+		assert_eq!(NiceU16::with_separator(1234, b'7').as_str(), "1,234");
+
+		// Other bytes are fine.
+		assert_eq!(NiceU64::with_separator(1234, b'-').as_str(), "1-234");
+	}
+
+	#[test]
+	fn t_extend_vec() {
+		let mut rng = fastrand::Rng::new();
+		let values: Vec<u64> = std::iter::repeat_with(|| rng.u64(..)).take(1000).collect();
+
+		let mut out = b"Numbers: ".to_vec();
+		NiceU64::extend_vec(&values, &mut out, NiceSeparator::Comma, b' ');
+
+		let expected = values.iter()
+			.map(|n| n.to_formatted_string(&Locale::en))
+			.collect::<Vec<_>>()
+			.join(" ");
+		assert_eq!(out, format!("Numbers: {expected}").into_bytes());
+
+		// Nothing should write nothing.
+		out.truncate(0);
+		NiceU64::extend_vec(&[], &mut out, NiceSeparator::Comma, b' ');
+		assert!(out.is_empty());
+	}
+
 	#[test]
 	fn t_nice_nonzero_u64() {
 		assert_eq!(NiceU64::default(), NiceU64::from(NonZeroU64::new(0)));
@@ -292,4 +823,157 @@ mod tests {
 		assert_eq!(num.as_str(), String::from(num));
 		assert_eq!(num.as_bytes(), Vec::<u8>::from(num));
 	}
+
+	#[test]
+	fn t_cmp() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(1..u64::MAX)).take(1000) {
+			let nice = NiceU64::from(i);
+			assert_eq!(nice, i);
+			assert_eq!(i, nice);
+			assert!(nice < i + 1);
+			assert!(i - 1 < nice);
+		}
+
+		// Signed deltas should compare as signed.
+		assert_eq!(NiceU64::delta(5), 5_u64);
+		assert_ne!(NiceU64::delta(-5), 5_u64);
+		assert!(NiceU64::delta(-5) < 0_u64);
+		assert!(0_u64 > NiceU64::delta(i64::MIN));
+		assert_eq!(NiceU64::MAX, u64::MAX);
+	}
+
+	#[test]
+	fn t_get() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..)).take(1000).chain([0, u64::MAX]) {
+			assert_eq!(NiceU64::from(i).get(), i);
+			assert_eq!(NiceU64::with_nice_separator(i, NiceSeparator::Period).get(), i);
+			assert_eq!(NiceU64::with_grouping(i, Grouping::Myriad, NiceSeparator::Underscore).get(), i);
+		}
+		assert_eq!(NiceU64::delta(i64::MIN).get(), i64::MIN.unsigned_abs());
+		assert_eq!(NiceU64::empty().get(), 0);
+	}
+
+	#[test]
+	fn t_to_array() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..)).take(1000).chain([0, u64::MAX]) {
+			for num in [NiceU64::from(i), NiceU64::with_grouping(i, Grouping::Myriad, NiceSeparator::Underscore)] {
+				let (arr, from) = num.to_array();
+				assert_eq!(&arr[from..], num.as_bytes());
+				assert!(arr[..from].iter().all(|&b| b == b' '));
+			}
+		}
+	}
+
+	#[test]
+	fn t_iter() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(1000).chain([0, u64::MAX]) {
+			let num = NiceU64::from(i);
+			assert_eq!(num.bytes().len(), num.len());
+			assert!(num.bytes().eq(num.as_bytes().iter().copied()));
+			assert!(num.bytes().rev().eq(num.as_bytes().iter().rev().copied()));
+			assert_eq!(num.chars().len(), num.len());
+			assert!(num.chars().eq(num.as_str().chars()));
+			assert!(num.chars().rev().eq(num.as_str().chars().rev()));
+		}
+
+		assert_eq!(NiceU64::empty().bytes().len(), 0);
+		assert_eq!(NiceU64::empty().chars().next(), None);
+	}
+
+	#[test]
+	fn t_debug() {
+		// The normal form is unchanged.
+		assert_eq!(format!("{:?}", NiceU64::from(1234_u64)), "NiceWrapper<26>(\"1,234\")");
+
+		// The alternate form includes the (real) name and value.
+		assert_eq!(
+			format!("{:#?}", NiceU64::from(1234_u64)),
+			"NiceU64 {\n    value: 1234,\n    nice: \"1,234\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NiceU8::from(123_u8)),
+			"NiceU8 {\n    value: 123,\n    nice: \"123\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NiceU16::from(12_345_u16)),
+			"NiceU16 {\n    value: 12345,\n    nice: \"12,345\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NiceU32::from(1_234_567_u32)),
+			"NiceU32 {\n    value: 1234567,\n    nice: \"1,234,567\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NicePercent::from(0.125_f32)),
+			"NicePercent {\n    value: 0.125,\n    nice: \"12.50%\",\n}",
+		);
+		assert_eq!(
+			format!("{:#?}", crate::NiceSignedPercent::from(-0.5_f32)),
+			"NiceSignedPercent {\n    value: -0.5,\n    nice: \"-50.00%\",\n}",
+		);
+
+		assert_eq!(
+			format!("{:#?}", crate::NicePercentRatio::from(2.5_f64)),
+			"NicePercentRatio {\n    value: 2.5,\n    nice: \"250.00%\",\n}",
+		);
+
+		// Deltas keep their sign.
+		assert_eq!(
+			format!("{:#?}", NiceU64::delta(-5)),
+			"NiceU64 {\n    value: -5,\n    nice: \"-5\",\n}",
+		);
+
+		// Floats are string-only.
+		assert_eq!(
+			format!("{:#?}", crate::NiceFloat::from(1.5_f64)),
+			"NiceFloat {\n    nice: \"1.50000000\",\n}",
+		);
+	}
+
+	#[test]
+	fn t_from_str() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u64(..)).take(10_000) {
+			let nice = NiceU64::from(num);
+			assert_eq!(nice.as_str().parse::<NiceU64>(), Ok(nice));
+
+			// The smaller types too, if they fit.
+			if let Ok(num) = u32::try_from(num) {
+				let nice = crate::NiceU32::from(num);
+				assert_eq!(nice.as_str().parse::<crate::NiceU32>(), Ok(nice));
+			}
+		}
+
+		// The edges.
+		for num in [0, u64::from(u8::MAX), u64::from(u16::MAX), u64::from(u32::MAX), u64::MAX] {
+			let nice = NiceU64::from(num);
+			assert_eq!(nice.as_str().parse::<NiceU64>(), Ok(nice));
+		}
+		assert_eq!("255".parse::<crate::NiceU8>(), Ok(crate::NiceU8::from(255_u8)));
+		assert_eq!("65,535".parse::<crate::NiceU16>(), Ok(crate::NiceU16::from(65_535_u16)));
+
+		// Bad stuff.
+		for (src, err) in [
+			("", ParseByteError::Empty),
+			("-1", ParseByteError::InvalidDigit(0)),
+			("1.0", ParseByteError::InvalidDigit(1)),
+			(" 1", ParseByteError::InvalidDigit(0)),
+			("00", ParseByteError::InvalidDigit(1)),
+			("01,234", ParseByteError::InvalidDigit(0)),
+			("1234", ParseByteError::InvalidDigit(1)),
+			("12,34", ParseByteError::InvalidDigit(1)),
+			("1,234,", ParseByteError::InvalidDigit(5)),
+			(",1,234", ParseByteError::InvalidDigit(0)),
+			("1,2345,6789", ParseByteError::InvalidDigit(1)),
+			("18,446,744,073,709,551,616", ParseByteError::Overflow),
+			("99,999,999,999,999,999,999", ParseByteError::Overflow),
+		] {
+			assert_eq!(src.parse::<NiceU64>(), Err(err), "{src:?}");
+		}
+		assert_eq!("256".parse::<crate::NiceU8>(), Err(ParseByteError::Overflow));
+		assert_eq!("65,536".parse::<crate::NiceU16>(), Err(ParseByteError::Overflow));
+	}
 }
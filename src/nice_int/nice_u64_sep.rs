@@ -0,0 +1,146 @@
+/*!
+# Dactyl: Nice u64 (Const Separator).
+*/
+
+use crate::NiceU64;
+use std::{
+	fmt,
+	ops::Deref,
+};
+
+
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+/// # Nice u64 (Const Separator).
+///
+/// This is a [`NiceU64`] with its thousands separator baked into the type,
+/// for projects with a fixed house style. The separator is validated when
+/// the code is built, and never has to be passed around (or checked) at
+/// runtime.
+///
+/// It dereferences to [`NiceU64`], so all the usual read-only methods —
+/// [`as_str`](NiceU64::as_str), [`padded`](NiceU64::padded), etc. — are
+/// available.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceSeparator, NiceU64, NiceU64Sep};
+///
+/// type NiceUnderscore = NiceU64Sep<b'_'>;
+///
+/// let num = NiceUnderscore::from(3141592653_u64);
+/// assert_eq!(num.as_str(), "3_141_592_653");
+/// assert_eq!(*num, NiceU64::with_nice_separator(3141592653_u64, NiceSeparator::Underscore));
+/// ```
+///
+/// Invalid separators — non-ASCII bytes and digits — won't compile.
+///
+/// ```compile_fail
+/// use dactyl::NiceU64Sep;
+///
+/// let num = NiceU64Sep::<b'0'>::from(1234_u64);
+/// ```
+pub struct NiceU64Sep<const SEP: u8>(NiceU64);
+
+impl<const SEP: u8> AsRef<[u8]> for NiceU64Sep<SEP> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.0.as_bytes() }
+}
+
+impl<const SEP: u8> AsRef<str> for NiceU64Sep<SEP> {
+	#[inline]
+	fn as_ref(&self) -> &str { self.0.as_str() }
+}
+
+impl<const SEP: u8> ::std::borrow::Borrow<str> for NiceU64Sep<SEP> {
+	#[inline]
+	fn borrow(&self) -> &str { self.0.as_str() }
+}
+
+impl<const SEP: u8> Default for NiceU64Sep<SEP> {
+	#[inline]
+	fn default() -> Self { Self::from(0) }
+}
+
+impl<const SEP: u8> Deref for NiceU64Sep<SEP> {
+	type Target = NiceU64;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<const SEP: u8> fmt::Debug for NiceU64Sep<SEP> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceU64Sep")
+			.field(&self.0.as_str())
+			.finish()
+	}
+}
+
+impl<const SEP: u8> fmt::Display for NiceU64Sep<SEP> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.0.as_str()) }
+}
+
+impl<const SEP: u8> From<u64> for NiceU64Sep<SEP> {
+	#[inline]
+	fn from(num: u64) -> Self { Self(NiceU64::with_const_separator::<SEP>(num)) }
+}
+
+impl<const SEP: u8> From<NiceU64Sep<SEP>> for NiceU64 {
+	#[inline]
+	fn from(src: NiceU64Sep<SEP>) -> Self { src.0 }
+}
+
+impl<const SEP: u8> NiceU64Sep<SEP> {
+	/// # Replace.
+	///
+	/// Reuse the backing storage behind `self` to hold a new nice number,
+	/// keeping the separator.
+	///
+	/// ## Examples.
+	///
+	/// ```
+	/// use dactyl::NiceU64Sep;
+	///
+	/// let mut num = NiceU64Sep::<b' '>::from(1234_u64);
+	/// assert_eq!(num.as_str(), "1 234");
+	///
+	/// // Calls can be chained.
+	/// assert_eq!(num.replace(5_678_901).as_str(), "5 678 901");
+	/// ```
+	pub fn replace(&mut self, num: u64) -> &mut Self {
+		self.0.replace(num);
+		self
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NiceSeparator;
+
+	#[test]
+	fn t_nice_u64_sep() {
+		for num in [0_u64, 1, 999, 1000, 123_456_789, u64::MAX] {
+			let nice = NiceU64Sep::<b'\''>::from(num);
+			assert_eq!(*nice, NiceU64::with_nice_separator(num, NiceSeparator::Apostrophe));
+			assert_eq!(nice.to_string(), nice.as_str());
+			assert_eq!(NiceU64::from(nice), NiceU64::with_nice_separator(num, NiceSeparator::Apostrophe));
+		}
+
+		assert_eq!(NiceU64Sep::<b'_'>::default().as_str(), "0");
+		assert_eq!(
+			format!("{:?}", NiceU64Sep::<b'_'>::from(1234_u64)),
+			"NiceU64Sep(\"1_234\")",
+		);
+
+		// Replacement should keep the separator.
+		let mut nice = NiceU64Sep::<b'_'>::from(u64::MAX);
+		assert_eq!(nice.replace(1234).as_str(), "1_234");
+		assert_eq!(nice, NiceU64Sep::<b'_'>::from(1234_u64));
+	}
+}
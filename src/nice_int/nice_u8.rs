@@ -49,6 +49,7 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b'0'];
 /// * `Eq` / `PartialEq`
 /// * `Hash`
 /// * `Ord` / `PartialOrd`
+/// * `PartialEq<u8>` / `PartialOrd<u8>` (and vice versa)
 ///
 /// You can instantiate a `NiceU8` with:
 ///
@@ -56,6 +57,7 @@ const ZERO: [u8; SIZE] = [b'0', b'0', b'0'];
 /// * `From<Option<u8>>`
 /// * `From<NonZeroU8>`
 /// * `From<Option<NonZeroU8>>`
+/// * `FromStr` (canonical formatting only)
 ///
 /// When converting from a `None`, the result will be equivalent to zero.
 pub type NiceU8 = NiceWrapper<SIZE>;
@@ -78,8 +80,15 @@ impl From<u8> for NiceU8 {
 	}
 }
 
+super::nice_debug!(NiceU8, value);
 super::nice_default!(NiceU8, ZERO, SIZE);
 super::nice_from_nz!(NiceU8, NonZeroU8);
+super::nice_pad!(NiceU8, u8, 3, "7", "007");
+super::nice_full!(NiceU8, "7_u8", "007");
+super::nice_cmp!(NiceU8, u8);
+super::nice_get!(NiceU8, u8, "123_u8");
+super::nice_from_str!(NiceU8, u8, "123");
+super::nice_replace_from!(NiceU8, u8, "123", "123");
 
 impl NiceU8 {
 	/// # Minimum Value.
@@ -143,7 +152,7 @@ impl NiceU8 {
 	/// num.replace(1);
 	/// assert_eq!(num.as_str(), "1");
 	/// ```
-	pub fn replace(&mut self, num: u8) {
+	pub const fn replace(&mut self, num: u8) -> &mut Self {
 		if 99 < num {
 			self.inner = crate::triple(num as usize);
 			self.from = 0;
@@ -155,6 +164,7 @@ impl NiceU8 {
 			self.inner[2] = c;
 			self.from = if b == b'0' { 2 } else { 1 };
 		}
+		self
 	}
 }
 
@@ -255,6 +265,55 @@ impl NiceU8 {
 }
 
 
+impl NiceU8 {
+	#[must_use]
+	/// # From Binary-Coded Decimal.
+	///
+	/// Decode a packed BCD byte — tens in the high nibble, ones in the low —
+	/// like those used by RTC registers, returning `None` if either nibble is
+	/// greater than nine.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU8;
+	///
+	/// assert_eq!(NiceU8::from_bcd(0x59).unwrap().as_str2(), "59");
+	/// assert_eq!(NiceU8::from_bcd(0x07).unwrap().as_str2(), "07");
+	/// assert!(NiceU8::from_bcd(0x5A).is_none());
+	/// ```
+	pub const fn from_bcd(bcd: u8) -> Option<Self> {
+		let (hi, lo) = (bcd >> 4, bcd & 0x0F);
+		if hi < 10 && lo < 10 {
+			let mut out = Self::MIN;
+			out.replace(hi * 10 + lo);
+			Some(out)
+		}
+		else { None }
+	}
+
+	#[expect(clippy::integer_division, reason = "We want this.")]
+	#[must_use]
+	/// # To Binary-Coded Decimal.
+	///
+	/// Encode the value as a packed BCD byte, returning `None` if it is
+	/// greater than `99` (and so won't fit).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceU8;
+	///
+	/// assert_eq!(NiceU8::from(59_u8).to_bcd(), Some(0x59));
+	/// assert_eq!(NiceU8::from(100_u8).to_bcd(), None);
+	/// ```
+	pub const fn to_bcd(&self) -> Option<u8> {
+		let num = self.get();
+		if num < 100 { Some(((num / 10) << 4) | (num % 10)) }
+		else { None }
+	}
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -352,4 +411,20 @@ mod tests {
 		assert_eq!(num.as_str(), String::from(num));
 		assert_eq!(num.as_bytes(), Vec::<u8>::from(num));
 	}
+
+	#[test]
+	fn t_bcd() {
+		for i in 0..=u8::MAX {
+			let (hi, lo) = (i >> 4, i & 0x0F);
+			match NiceU8::from_bcd(i) {
+				Some(nice) => {
+					assert!(hi < 10 && lo < 10);
+					assert_eq!(nice, NiceU8::from(hi * 10 + lo));
+					assert_eq!(nice.to_bcd(), Some(i));
+				},
+				None => { assert!(9 < hi || 9 < lo); },
+			}
+			assert_eq!(NiceU8::from(i).to_bcd().is_some(), i < 100);
+		}
+	}
 }
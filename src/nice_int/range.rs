@@ -0,0 +1,159 @@
+/*!
+# Dactyl: Nice Ranges.
+*/
+
+use crate::{
+	NiceU16,
+	NiceU32,
+	NiceU64,
+	NiceU8,
+};
+use std::iter::FusedIterator;
+
+
+
+#[derive(Debug, Clone)]
+/// # Nice Range (Inclusive).
+///
+/// This iterator walks an inclusive range of integers by some step, yielding
+/// each as its "nice" equivalent. A single buffer is maintained and advanced
+/// via `replace` between iterations, so each item is just a cheap copy.
+///
+/// Instances are created with the `nice_range_inclusive` constructors on
+/// [`NiceU8`], [`NiceU16`], [`NiceU32`], and [`NiceU64`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::NiceU64;
+///
+/// let mut iter = NiceU64::nice_range_inclusive(0, 1_000_000, 250_000);
+/// assert_eq!(iter.next().unwrap().as_str(), "0");
+/// assert_eq!(iter.next().unwrap().as_str(), "250,000");
+/// assert_eq!(iter.next().unwrap().as_str(), "500,000");
+/// assert_eq!(iter.next().unwrap().as_str(), "750,000");
+/// assert_eq!(iter.next().unwrap().as_str(), "1,000,000");
+/// assert!(iter.next().is_none());
+/// ```
+pub struct NiceRangeInclusive<N, T> {
+	/// # Buffer.
+	nice: N,
+
+	/// # Next Value.
+	next: Option<T>,
+
+	/// # Last Value (Inclusive).
+	end: T,
+
+	/// # Step.
+	step: T,
+}
+
+/// # Helper: Range Implementations.
+macro_rules! range {
+	($($nice:ty, $uint:ty),+ $(,)?) => ($(
+		impl Iterator for NiceRangeInclusive<$nice, $uint> {
+			type Item = $nice;
+
+			fn next(&mut self) -> Option<Self::Item> {
+				let next = self.next?;
+				self.nice.replace(next);
+				self.next = next.checked_add(self.step).filter(|n| *n <= self.end);
+				Some(self.nice)
+			}
+
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				let len = self.next.map_or(Some(0), |next|
+					usize::try_from((self.end - next) / self.step).ok()
+						.and_then(|n| n.checked_add(1))
+				);
+				(len.unwrap_or(usize::MAX), len)
+			}
+		}
+
+		impl FusedIterator for NiceRangeInclusive<$nice, $uint> {}
+
+		impl $nice {
+			#[must_use]
+			/// # Nice Range (Inclusive).
+			///
+			/// Return an iterator yielding nice values from `start` to `end`
+			/// (inclusive), counting by `step`.
+			///
+			/// The range will be empty if `start` is greater than `end`.
+			///
+			/// ## Examples
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("let all: Vec<String> = ", stringify!($nice), "::nice_range_inclusive(0, 20, 10)")]
+			///     .map(|n| n.to_string())
+			///     .collect();
+			/// assert_eq!(all, ["0", "10", "20"]);
+			/// ```
+			///
+			/// ## Panics
+			///
+			/// This will panic if `step` is zero.
+			pub fn nice_range_inclusive(start: $uint, end: $uint, step: $uint)
+			-> NiceRangeInclusive<Self, $uint> {
+				assert!(step != 0, "The step must be non-zero.");
+				NiceRangeInclusive {
+					nice: Self::MIN,
+					next: if start <= end { Some(start) } else { None },
+					end,
+					step,
+				}
+			}
+		}
+	)+);
+}
+
+range!(
+	NiceU8, u8,
+	NiceU16, u16,
+	NiceU32, u32,
+	NiceU64, u64,
+);
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Helper: Compare against std.
+	macro_rules! t_range {
+		($nice:ty, $uint:ty, $start:expr, $end:expr, $step:expr) => (
+			let iter = <$nice>::nice_range_inclusive($start, $end, $step);
+			let expected: Vec<$nice> = ($start..=$end).step_by(usize::try_from($step).unwrap())
+				.map(<$nice>::from)
+				.collect();
+			assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+			assert_eq!(iter.collect::<Vec<_>>(), expected);
+		);
+	}
+
+	#[test]
+	fn t_range() {
+		t_range!(NiceU8, u8, 0_u8, u8::MAX, 1_u8);
+		t_range!(NiceU8, u8, 3_u8, 250_u8, 7_u8);
+		t_range!(NiceU16, u16, 0_u16, u16::MAX, 255_u16);
+		t_range!(NiceU32, u32, 1000_u32, 1_000_000_u32, 10_000_u32);
+		t_range!(NiceU64, u64, 0_u64, 1_000_000_u64, 10_000_u64);
+		t_range!(NiceU64, u64, u64::MAX - 100, u64::MAX, 30_u64);
+
+		// Single and empty.
+		t_range!(NiceU32, u32, 5_u32, 5_u32, 1_u32);
+		let mut iter = NiceU32::nice_range_inclusive(6, 5, 1);
+		assert_eq!(iter.size_hint(), (0, Some(0)));
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "The step must be non-zero.")]
+	fn t_range_zero() {
+		let _res = NiceU32::nice_range_inclusive(0, 10, 0);
+	}
+}
@@ -0,0 +1,269 @@
+/*!
+# Dactyl: Progress Helpers.
+
+This module contains helpers for reporting the progress of long-running
+loops.
+*/
+
+use crate::{
+	NicePercent,
+	Rounding,
+};
+use std::iter::FusedIterator;
+
+
+
+#[must_use]
+/// # Percent Every.
+///
+/// Wrap an iterator, pairing each item with an `Option<NicePercent>` that is
+/// only `Some` when the running count — relative to `total` — crosses a
+/// `step`-sized whole-percent boundary, e.g. every `5%`.
+///
+/// The reported percentages are the boundaries themselves (`5.00%`,
+/// `10.00%`, etc.), so will never be reported twice. `0.00%` is never
+/// reported.
+///
+/// The `step` is clamped to `1..=100`. Counts exceeding `total` are treated
+/// as `100%`, as is a `total` of zero.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::progress::percent_every;
+///
+/// let reported: Vec<String> = percent_every(0..40, 40, 25)
+///     .filter_map(|(_, pct)| pct.map(|p| p.to_string()))
+///     .collect();
+///
+/// assert_eq!(reported, ["25.00%", "50.00%", "75.00%", "100.00%"]);
+/// ```
+pub fn percent_every<I: IntoIterator>(iter: I, total: u64, step: u8)
+-> PercentEvery<I::IntoIter> {
+	PercentEvery {
+		iter: iter.into_iter(),
+		done: 0,
+		total,
+		step: step.clamp(1, 100),
+		last: NicePercent::MIN,
+	}
+}
+
+
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Percent Of.
+///
+/// Return `done` as a whole percentage of `total` — `0..=100` — using the
+/// given [`Rounding`] strategy to resolve any remainder.
+///
+/// The math is carried out in `u128` space, so cannot overflow. Counts
+/// exceeding `total` are treated as `100`, as is a `total` of zero.
+///
+/// Note that with [`Rounding::Ceil`] and the nearest strategies, counts just
+/// shy of `total` may round up to `100`; use [`Rounding::Floor`] if `100`
+/// should be reserved for completion.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{percent_of, Rounding};
+///
+/// assert_eq!(percent_of(1, 3, Rounding::Floor), 33);
+/// assert_eq!(percent_of(1, 3, Rounding::Ceil), 34);
+/// assert_eq!(percent_of(2, 3, Rounding::Nearest), 67);
+///
+/// // Ties.
+/// assert_eq!(percent_of(1, 200, Rounding::Nearest), 1);
+/// assert_eq!(percent_of(1, 200, Rounding::NearestEven), 0);
+/// assert_eq!(percent_of(3, 200, Rounding::NearestEven), 2);
+///
+/// // Near the end.
+/// assert_eq!(percent_of(999, 1000, Rounding::Floor), 99);
+/// assert_eq!(percent_of(999, 1000, Rounding::Nearest), 100);
+///
+/// // Edge cases.
+/// assert_eq!(percent_of(5, 0, Rounding::Floor), 100);
+/// assert_eq!(percent_of(usize::MAX, usize::MAX, Rounding::Floor), 100);
+/// ```
+pub const fn percent_of(done: usize, total: usize, rounding: Rounding) -> u8 {
+	if total == 0 || total <= done { return 100; }
+
+	let num = done as u128 * 100;
+	let total = total as u128;
+	let (whole, rem) = (num / total, num % total);
+	let up = match rounding {
+		Rounding::Floor => false,
+		Rounding::Ceil => rem != 0,
+		Rounding::Nearest => total <= rem * 2,
+		Rounding::NearestEven => total < rem * 2 || (total == rem * 2 && whole & 1 == 1),
+	};
+
+	// The whole part is less than one hundred, so this fits.
+	(if up { whole + 1 } else { whole }) as u8
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Percent Every (Iterator).
+///
+/// This is the iterator returned by [`percent_every`]. See that function for
+/// details.
+pub struct PercentEvery<I> {
+	/// # Inner Iterator.
+	iter: I,
+
+	/// # Items Seen.
+	done: u64,
+
+	/// # Total Items.
+	total: u64,
+
+	/// # Step (Whole Percent).
+	step: u8,
+
+	/// # Last Reported Value.
+	last: NicePercent,
+}
+
+impl<I: Iterator> Iterator for PercentEvery<I> {
+	type Item = (I::Item, Option<NicePercent>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let next = self.iter.next()?;
+		self.done = self.done.saturating_add(1);
+
+		// Only report when the (formatted) boundary changes.
+		let pct = self.boundary();
+		if pct == self.last { Some((next, None)) }
+		else {
+			self.last = pct;
+			Some((next, Some(pct)))
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for PercentEvery<I> {
+	#[inline]
+	fn len(&self) -> usize { self.iter.len() }
+}
+
+impl<I: FusedIterator> FusedIterator for PercentEvery<I> {}
+
+impl<I> PercentEvery<I> {
+	#[must_use]
+	/// # Done.
+	///
+	/// Return the number of items seen so far.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::progress::percent_every;
+	///
+	/// let mut iter = percent_every(0..10, 10, 1);
+	/// iter.next();
+	/// iter.next();
+	/// assert_eq!(iter.done(), 2);
+	/// ```
+	pub const fn done(&self) -> u64 { self.done }
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Current Boundary.
+	///
+	/// Return the most recently crossed boundary as a percent.
+	fn boundary(&self) -> NicePercent {
+		let whole =
+			if self.total == 0 || self.total <= self.done { 100 }
+			else {
+				// Do the math in u128 space to avoid overflow.
+				(u128::from(self.done) * 100 / u128::from(self.total)) as u8
+			};
+
+		// Round down to the nearest step, unless we're done.
+		let whole =
+			if whole == 100 { whole }
+			else { whole - whole % self.step };
+
+		NicePercent::from(f32::from(whole) / 100.0)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[expect(clippy::float_cmp, reason = "The values are whole.")]
+	fn t_percent_of() {
+		for total in 1..=250_u32 {
+			for done in 0..=total + 1 {
+				let exact = f64::from(done.min(total)) * 100.0 / f64::from(total);
+				let (done, total) = (done as usize, total as usize);
+				let floor = percent_of(done, total, Rounding::Floor);
+				let ceil = percent_of(done, total, Rounding::Ceil);
+				let nearest = percent_of(done, total, Rounding::Nearest);
+				let even = percent_of(done, total, Rounding::NearestEven);
+
+				assert_eq!(f64::from(floor), exact.floor(), "{done}/{total}");
+				assert_eq!(f64::from(ceil), exact.ceil(), "{done}/{total}");
+				assert_eq!(f64::from(nearest), exact.round(), "{done}/{total}");
+				assert_eq!(f64::from(even), exact.round_ties_even(), "{done}/{total}");
+			}
+		}
+
+		// Zero and huge totals.
+		for rounding in [Rounding::Floor, Rounding::Ceil, Rounding::Nearest, Rounding::NearestEven] {
+			assert_eq!(percent_of(0, 0, rounding), 100);
+			assert_eq!(percent_of(usize::MAX - 1, usize::MAX, rounding), if matches!(rounding, Rounding::Floor) { 99 } else { 100 });
+			assert_eq!(percent_of(usize::MAX / 2, usize::MAX, Rounding::Floor), 49);
+		}
+	}
+
+	#[test]
+	fn t_percent_every() {
+		// Every single percent should be reported once, in order.
+		let reported: Vec<(u32, NicePercent)> = percent_every(1..=1234_u32, 1234, 1)
+			.filter_map(|(i, pct)| pct.map(|p| (i, p)))
+			.collect();
+		assert_eq!(reported.len(), 100);
+		for (whole, (i, pct)) in (1..).zip(reported) {
+			assert_eq!(pct.as_str(), format!("{whole}.00%"));
+			assert_eq!(i * 100 / 1234, whole, "Reported late.");
+			assert_eq!((i - 1) * 100 / 1234, whole - 1, "Reported early.");
+		}
+
+		// Uneven steps can't reach 100% by themselves, but the end is always
+		// reported.
+		let reported: Vec<String> = percent_every(0..1000, 1000, 30)
+			.filter_map(|(_, pct)| pct.map(|p| p.to_string()))
+			.collect();
+		assert_eq!(reported, ["30.00%", "60.00%", "90.00%", "100.00%"]);
+
+		// Bad steps are clamped.
+		assert_eq!(percent_every(0..200, 200, 0).filter(|(_, p)| p.is_some()).count(), 100);
+		assert_eq!(percent_every(0..200, 200, 255).filter(|(_, p)| p.is_some()).count(), 1);
+
+		// Overflowing totals are capped.
+		let reported: Vec<(u8, NicePercent)> = percent_every(0..10_u8, 5, 50)
+			.filter_map(|(i, pct)| pct.map(|p| (i, p)))
+			.collect();
+		assert_eq!(reported, [(2, NicePercent::from(0.5_f32)), (4, NicePercent::MAX)]);
+
+		// As are zero totals.
+		let mut iter = percent_every(0..3, 0, 10);
+		assert_eq!(iter.len(), 3);
+		assert_eq!(iter.next(), Some((0, Some(NicePercent::MAX))));
+		assert_eq!(iter.next(), Some((1, None)));
+		assert_eq!(iter.next(), Some((2, None)));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.done(), 3);
+	}
+}
@@ -0,0 +1,490 @@
+/*!
+# Dactyl: Style/Config.
+*/
+
+use crate::NiceSeparatorError;
+
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Rounding Strategy.
+///
+/// This enum describes how a value should be rounded when it cannot be
+/// represented exactly at the desired precision, e.g. by
+/// [`percent_of`](crate::percent_of).
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum Rounding {
+	/// # Round Down (Toward Zero).
+	Floor,
+
+	/// # Round Up (Away From Zero).
+	Ceil,
+
+	/// # Round to Nearest (Ties Away From Zero).
+	Nearest,
+
+	#[default]
+	/// # Round to Nearest (Ties to Even).
+	///
+	/// This is also known as banker's rounding.
+	NearestEven,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Digit Grouping.
+///
+/// This enum describes how the digits of an integer should be grouped (i.e.
+/// how often a separator should be inserted).
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum Grouping {
+	#[default]
+	/// # Thousands (e.g. `123,456,789`).
+	Thousands,
+
+	/// # Myriads (e.g. `1,2345,6789`).
+	///
+	/// This is the traditional Chinese/Japanese grouping, with separators
+	/// every four digits.
+	Myriad,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # NaN Policy.
+///
+/// This enum describes how a not-a-number float value should be displayed.
+///
+/// It can be set on a [`NiceStyle`], but is reserved for now; no formatter
+/// reads it yet.
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum NanPolicy {
+	#[default]
+	/// # Print `NaN`.
+	Nan,
+
+	/// # Treat as Zero.
+	Zero,
+
+	/// # Print Nothing.
+	Empty,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Unit Labels.
+///
+/// This enum describes how units — e.g. of time — should be labelled.
+///
+/// It can be set on a [`NiceStyle`], but is reserved for now; no formatter
+/// reads it yet.
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum Units {
+	#[default]
+	/// # Long (e.g. `3 hours`).
+	Long,
+
+	/// # Short (e.g. `3h`).
+	Short,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Magnitude Scale.
+///
+/// This enum describes which suffixes should be used when abbreviating large
+/// numbers, e.g. with [`NiceShort`](crate::NiceShort).
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum Scale {
+	#[default]
+	/// # Short Scale (`K`, `M`, `B`, `T`, `Q`).
+	Short,
+
+	/// # SI (`K`, `M`, `G`, `T`, `P`, `E`).
+	Si,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Thousands Separator.
+///
+/// This enum names the supported thousands separators, and is accepted by
+/// all of the `Nice*` separator constructors, like
+/// [`NiceU64::with_nice_separator`](crate::NiceU64::with_nice_separator).
+///
+/// Separators can also be converted from their byte or `char` equivalents
+/// using `TryFrom`.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceSeparator, NiceU64};
+///
+/// let sep = NiceSeparator::try_from(b'_').unwrap();
+/// assert_eq!(sep, NiceSeparator::Underscore);
+/// assert_eq!(
+///     NiceU64::with_nice_separator(1234_u64, sep).as_str(),
+///     "1_234",
+/// );
+///
+/// // Digits (among other things) are not separators.
+/// assert!(NiceSeparator::try_from('7').is_err());
+/// ```
+///
+/// New variants may be added in future releases, so matches should include a
+/// wildcard arm.
+pub enum NiceSeparator {
+	#[default]
+	/// # Comma (e.g. `1,234`).
+	Comma,
+
+	/// # Period (e.g. `1.234`).
+	Period,
+
+	/// # Apostrophe (e.g. `1'234`).
+	Apostrophe,
+
+	/// # Space (e.g. `1 234`).
+	Space,
+
+	/// # Underscore (e.g. `1_234`).
+	Underscore,
+}
+
+impl NiceSeparator {
+	#[must_use]
+	/// # As Byte.
+	///
+	/// Return the separator as an ASCII byte.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceSeparator;
+	///
+	/// assert_eq!(NiceSeparator::Comma.as_byte(), b',');
+	/// assert_eq!(NiceSeparator::Underscore.as_byte(), b'_');
+	/// ```
+	pub const fn as_byte(self) -> u8 {
+		match self {
+			Self::Comma => b',',
+			Self::Period => b'.',
+			Self::Apostrophe => b'\'',
+			Self::Space => b' ',
+			Self::Underscore => b'_',
+		}
+	}
+}
+
+impl From<NiceSeparator> for u8 {
+	#[inline]
+	fn from(src: NiceSeparator) -> Self { src.as_byte() }
+}
+
+impl TryFrom<u8> for NiceSeparator {
+	type Error = NiceSeparatorError;
+
+	#[inline]
+	fn try_from(src: u8) -> Result<Self, Self::Error> {
+		match src {
+			b',' => Ok(Self::Comma),
+			b'.' => Ok(Self::Period),
+			b'\'' => Ok(Self::Apostrophe),
+			b' ' => Ok(Self::Space),
+			b'_' => Ok(Self::Underscore),
+			_ => Err(NiceSeparatorError),
+		}
+	}
+}
+
+impl TryFrom<char> for NiceSeparator {
+	type Error = NiceSeparatorError;
+
+	#[inline]
+	fn try_from(src: char) -> Result<Self, Self::Error> {
+		u8::try_from(src).map_err(|_| NiceSeparatorError).and_then(Self::try_from)
+	}
+}
+
+impl Scale {
+	#[must_use]
+	/// # Suffixes.
+	///
+	/// Return the suffixes for each successive power of one thousand.
+	pub(crate) const fn suffixes(self) -> &'static [u8] {
+		match self {
+			Self::Short => b"KMBTQ",
+			Self::Si => b"KMGTPE",
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Nice Style.
+///
+/// This struct bundles together the punctuation and grouping options supported
+/// by the `Nice*` types' `with_style` constructors.
+///
+/// Instances are created with [`NiceStyle::new`] or [`Default`], and
+/// customized using the builder-style `with_*` methods.
+///
+/// The [`Rounding`], [`NanPolicy`], and [`Units`] options are reserved for
+/// future formatters; they can be set and read back, but nothing in the crate
+/// consults them yet.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{Grouping, NiceSeparator, NiceStyle};
+///
+/// let style = NiceStyle::new()
+///     .with_separator(NiceSeparator::Period)
+///     .with_point(b',')
+///     .with_grouping(Grouping::Myriad);
+///
+/// assert_eq!(style.separator(), NiceSeparator::Period);
+/// assert_eq!(style.point(), b',');
+/// assert_eq!(style.grouping(), Grouping::Myriad);
+/// ```
+pub struct NiceStyle {
+	/// # Thousands Separator.
+	separator: NiceSeparator,
+
+	/// # Digit Grouping.
+	grouping: Grouping,
+
+	/// # Decimal Point.
+	point: u8,
+
+	/// # Rounding (Reserved).
+	rounding: Rounding,
+
+	/// # NaN Policy (Reserved).
+	nan: NanPolicy,
+
+	/// # Units (Reserved).
+	units: Units,
+}
+
+impl Default for NiceStyle {
+	#[inline]
+	fn default() -> Self { Self::new() }
+}
+
+impl NiceStyle {
+	#[must_use]
+	/// # New.
+	///
+	/// Return a new instance with the default (American) options: comma
+	/// separators every three digits and period decimal points.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::NiceStyle;
+	///
+	/// assert_eq!(NiceStyle::new(), NiceStyle::default());
+	/// ```
+	pub const fn new() -> Self {
+		Self {
+			separator: NiceSeparator::Comma,
+			grouping: Grouping::Thousands,
+			point: b'.',
+			rounding: Rounding::NearestEven,
+			nan: NanPolicy::Nan,
+			units: Units::Long,
+		}
+	}
+
+	#[must_use]
+	/// # New (European).
+	///
+	/// Return a new instance with European-style punctuation — period
+	/// separators and comma decimal points — but otherwise the same defaults
+	/// as [`NiceStyle::new`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceFloat, NiceSeparator, NiceStyle, NiceU64};
+	///
+	/// let style = NiceStyle::eu();
+	/// assert_eq!(style.separator(), NiceSeparator::Period);
+	/// assert_eq!(style.point(), b',');
+	///
+	/// assert_eq!(
+	///     NiceU64::with_style(1_234_567_u64, style).as_str(),
+	///     "1.234.567",
+	/// );
+	/// assert_eq!(
+	///     NiceFloat::with_style(1234.5_f64, style).as_str(),
+	///     "1.234,50000000",
+	/// );
+	/// ```
+	pub const fn eu() -> Self {
+		Self {
+			separator: NiceSeparator::Period,
+			point: b',',
+			..Self::new()
+		}
+	}
+
+	#[must_use]
+	/// # With Separator.
+	///
+	/// Set the thousands separator.
+	pub const fn with_separator(self, separator: NiceSeparator) -> Self {
+		Self { separator, ..self }
+	}
+
+	#[must_use]
+	/// # With Grouping.
+	///
+	/// Set the digit grouping.
+	pub const fn with_grouping(self, grouping: Grouping) -> Self {
+		Self { grouping, ..self }
+	}
+
+	#[must_use]
+	/// # With Decimal Point.
+	///
+	/// Set the decimal point.
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the point is invalid ASCII.
+	pub const fn with_point(self, point: u8) -> Self {
+		assert!(point.is_ascii(), "Invalid decimal point.");
+		Self { point, ..self }
+	}
+
+	#[must_use]
+	/// # With Rounding.
+	///
+	/// Set the rounding strategy. This is reserved for future use.
+	pub const fn with_rounding(self, rounding: Rounding) -> Self {
+		Self { rounding, ..self }
+	}
+
+	#[must_use]
+	/// # With NaN Policy.
+	///
+	/// Set the not-a-number policy. This is reserved for future use.
+	pub const fn with_nan(self, nan: NanPolicy) -> Self {
+		Self { nan, ..self }
+	}
+
+	#[must_use]
+	/// # With Units.
+	///
+	/// Set the unit label style. This is reserved for future use.
+	pub const fn with_units(self, units: Units) -> Self {
+		Self { units, ..self }
+	}
+}
+
+impl NiceStyle {
+	#[must_use]
+	/// # Thousands Separator.
+	pub const fn separator(&self) -> NiceSeparator { self.separator }
+
+	#[must_use]
+	/// # Digit Grouping.
+	pub const fn grouping(&self) -> Grouping { self.grouping }
+
+	#[must_use]
+	/// # Decimal Point.
+	pub const fn point(&self) -> u8 { self.point }
+
+	#[must_use]
+	/// # Rounding (Reserved).
+	pub const fn rounding(&self) -> Rounding { self.rounding }
+
+	#[must_use]
+	/// # NaN Policy (Reserved).
+	pub const fn nan(&self) -> NanPolicy { self.nan }
+
+	#[must_use]
+	/// # Units (Reserved).
+	pub const fn units(&self) -> Units { self.units }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_style() {
+		let style = NiceStyle::default();
+		assert_eq!(style.separator(), NiceSeparator::Comma);
+		assert_eq!(style.grouping(), Grouping::Thousands);
+		assert_eq!(style.point(), b'.');
+		assert_eq!(style.rounding(), Rounding::NearestEven);
+		assert_eq!(style.nan(), NanPolicy::Nan);
+		assert_eq!(style.units(), Units::Long);
+
+		// Setters should only change the one thing.
+		let style2 = style.with_rounding(Rounding::Floor);
+		assert_eq!(style2.rounding(), Rounding::Floor);
+		assert_eq!(style2.with_rounding(Rounding::NearestEven), style);
+
+		let style2 = style.with_nan(NanPolicy::Empty);
+		assert_eq!(style2.nan(), NanPolicy::Empty);
+		assert_eq!(style2.with_nan(NanPolicy::Nan), style);
+
+		let style2 = style.with_grouping(Grouping::Myriad);
+		assert_eq!(style2.grouping(), Grouping::Myriad);
+		assert_eq!(style2.with_grouping(Grouping::Thousands), style);
+
+		let style2 = style.with_point(b',');
+		assert_eq!(style2.point(), b',');
+		assert_eq!(style2.with_point(b'.'), style);
+
+		let style2 = style.with_units(Units::Short);
+		assert_eq!(style2.units(), Units::Short);
+		assert_eq!(style2.with_units(Units::Long), style);
+	}
+
+	#[test]
+	fn t_style_eu() {
+		let style = NiceStyle::eu();
+		assert_eq!(
+			style.with_separator(NiceSeparator::Comma).with_point(b'.'),
+			NiceStyle::new(),
+		);
+	}
+
+	#[test]
+	fn t_separator() {
+		for sep in [
+			NiceSeparator::Comma,
+			NiceSeparator::Period,
+			NiceSeparator::Apostrophe,
+			NiceSeparator::Space,
+			NiceSeparator::Underscore,
+		] {
+			let byte = sep.as_byte();
+			assert_eq!(NiceSeparator::try_from(byte), Ok(sep));
+			assert_eq!(NiceSeparator::try_from(char::from(byte)), Ok(sep));
+		}
+
+		for bad in [b'0', b'7', b'-', b'a', 0xFF] {
+			assert_eq!(NiceSeparator::try_from(bad), Err(NiceSeparatorError));
+		}
+		assert_eq!(NiceSeparator::try_from('\u{2009}'), Err(NiceSeparatorError));
+	}
+}
@@ -0,0 +1,296 @@
+/*!
+# Dactyl: Time Helpers.
+
+This module contains a handful of small helpers for working with
+[`Duration`]s, and for splitting seconds into days, hours, minutes, and
+seconds.
+*/
+
+use crate::NiceOverflowError;
+use std::time::Duration;
+
+
+
+/// # Max Seconds.
+const MAX_SECS: u128 = u64::MAX as u128;
+
+/// # Max Milliseconds.
+const MAX_MILLIS: u128 = MAX_SECS * 1000 + 999;
+
+/// # Seconds Per Day.
+const DAY: u32 = 86_400;
+
+
+
+#[must_use]
+/// # Duration From Seconds (Saturating).
+///
+/// Convert an arbitrary number of seconds into a [`Duration`], saturating to
+/// [`Duration::MAX`] (sans nanoseconds) if too big.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::duration_from_secs;
+/// use std::time::Duration;
+///
+/// assert_eq!(duration_from_secs(60), Duration::from_secs(60));
+/// assert_eq!(duration_from_secs(u128::MAX), Duration::from_secs(u64::MAX));
+/// ```
+pub const fn duration_from_secs(secs: u128) -> Duration {
+	match duration_from_secs_checked(secs) {
+		Ok(d) => d,
+		Err(_) => Duration::from_secs(u64::MAX),
+	}
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Duration From Seconds (Checked).
+///
+/// Convert an arbitrary number of seconds into a [`Duration`], returning an
+/// error if too big.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::duration_from_secs_checked;
+/// use std::time::Duration;
+///
+/// assert_eq!(duration_from_secs_checked(60), Ok(Duration::from_secs(60)));
+/// assert!(duration_from_secs_checked(u128::MAX).is_err());
+/// ```
+///
+/// ## Errors
+///
+/// If the value exceeds `u64::MAX`, a [`NiceOverflowError`] is returned.
+pub const fn duration_from_secs_checked(secs: u128)
+-> Result<Duration, NiceOverflowError> {
+	if secs <= MAX_SECS { Ok(Duration::from_secs(secs as u64)) }
+	else { Err(NiceOverflowError::new(secs, MAX_SECS)) }
+}
+
+#[must_use]
+/// # Duration From Milliseconds (Saturating).
+///
+/// Convert an arbitrary number of milliseconds into a [`Duration`],
+/// saturating to the maximum millisecond-representable value if too big.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::duration_from_millis;
+/// use std::time::Duration;
+///
+/// assert_eq!(duration_from_millis(1500), Duration::from_millis(1500));
+/// assert_eq!(
+///     duration_from_millis(u128::MAX),
+///     Duration::new(u64::MAX, 999_000_000),
+/// );
+/// ```
+pub const fn duration_from_millis(ms: u128) -> Duration {
+	match duration_from_millis_checked(ms) {
+		Ok(d) => d,
+		Err(_) => Duration::new(u64::MAX, 999_000_000),
+	}
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+/// # Duration From Milliseconds (Checked).
+///
+/// Convert an arbitrary number of milliseconds into a [`Duration`],
+/// returning an error if too big.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::duration_from_millis_checked;
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     duration_from_millis_checked(1500),
+///     Ok(Duration::from_millis(1500)),
+/// );
+///
+/// // The error message is nice too!
+/// assert_eq!(
+///     duration_from_millis_checked(18_446_744_073_709_551_616_000)
+///         .unwrap_err()
+///         .to_string(),
+///     "18,446,744,073,709,551,616,000 exceeds the maximum of 18,446,744,073,709,551,615,999.",
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// If the value is too big to fit, a [`NiceOverflowError`] is returned.
+pub const fn duration_from_millis_checked(ms: u128)
+-> Result<Duration, NiceOverflowError> {
+	if ms <= MAX_MILLIS {
+		Ok(Duration::new((ms / 1000) as u64, (ms % 1000) as u32 * 1_000_000))
+	}
+	else { Err(NiceOverflowError::new(ms, MAX_MILLIS)) }
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Time Chunks (with Days).
+///
+/// This works just like [`hms`], but counts up days too.
+///
+/// Note that unlike the time units, which have really small caps, days can
+/// reach up to `49,710`, so are returned as a `u16`. For bigger inputs, use
+/// [`dhms_u64`] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::dhms;
+///
+/// assert_eq!(dhms(1_123_321), (13_u16, 0_u8, 2_u8, 1_u8));
+/// assert_eq!(dhms(3661), (0_u16, 1_u8, 1_u8, 1_u8));
+/// ```
+pub const fn dhms(num: u32) -> (u16, u8, u8, u8) {
+	let (d, [h, m, s]) =
+		if num < DAY { (0, hms(num)) }
+		else { (num.wrapping_div(DAY) as u16, hms(num % DAY)) };
+
+	(d, h, m, s)
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Time Chunks (with Days, `u64`).
+///
+/// This is the same as [`dhms`], but for `u64` inputs, returning the days as
+/// a `u64` too.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::dhms_u64;
+///
+/// assert_eq!(dhms_u64(1_123_321), (13, 0, 2, 1));
+/// assert_eq!(dhms_u64(u64::MAX), (213_503_982_334_601, 7, 0, 15));
+/// ```
+pub const fn dhms_u64(num: u64) -> (u64, u8, u8, u8) {
+	let [h, m, s] = hms((num % DAY as u64) as u32);
+	(num / DAY as u64, h, m, s)
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Time Chunks.
+///
+/// Split seconds into hours, minutes, and seconds. Days are not supported;
+/// the maximum return value is `[23, 59, 59]`.
+///
+/// Given the limited range of digits involved, we're able to use some data
+/// rounding trickery to achieve conversion, bypassing the need for
+/// (relatively) expensive division and remainder calculations.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::hms;
+///
+/// assert_eq!(hms(121), [0_u8, 2_u8, 1_u8]);
+/// assert_eq!(hms(86_400), [23_u8, 59_u8, 59_u8]); // Saturated.
+/// ```
+pub const fn hms(mut num: u32) -> [u8; 3] {
+	if num < 60 { [0, 0, num as u8] }
+	else if num < 86399 {
+		let mut buf = [0_u8; 3];
+
+		// There are hours.
+		if num >= 3600 {
+			buf[0] = ((num * 0x91A3) >> 27) as u8;
+			num -= buf[0] as u32 * 3600;
+		}
+
+		// There are minutes.
+		if num >= 60 {
+			buf[1] = ((num * 0x889) >> 17) as u8;
+			buf[2] = (num - buf[1] as u32 * 60) as u8;
+		}
+		// There are seconds.
+		else if num > 0 { buf[2] = num as u8; }
+
+		buf
+	}
+	else { [23, 59, 59] }
+}
+
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+#[must_use]
+/// # Time Chunks (`u64`).
+///
+/// This is the same as [`hms`], but for `u64` inputs. Values of a day or
+/// more are saturated to `[23, 59, 59]`.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::time::hms_u64;
+///
+/// assert_eq!(hms_u64(3661), [1_u8, 1_u8, 1_u8]);
+/// assert_eq!(hms_u64(u64::MAX), [23_u8, 59_u8, 59_u8]);
+/// ```
+pub const fn hms_u64(num: u64) -> [u8; 3] {
+	if num < DAY as u64 { hms(num as u32) }
+	else { [23, 59, 59] }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_duration_from() {
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.u64(..)).take(1000) {
+			assert_eq!(duration_from_secs(u128::from(i)), Duration::from_secs(i));
+			assert_eq!(duration_from_millis(u128::from(i)), Duration::from_millis(i));
+		}
+
+		// The edges.
+		assert_eq!(duration_from_secs_checked(MAX_SECS), Ok(Duration::from_secs(u64::MAX)));
+		assert_eq!(
+			duration_from_secs_checked(MAX_SECS + 1),
+			Err(NiceOverflowError::new(MAX_SECS + 1, MAX_SECS)),
+		);
+		assert_eq!(
+			duration_from_millis_checked(MAX_MILLIS),
+			Ok(Duration::new(u64::MAX, 999_000_000)),
+		);
+		assert_eq!(
+			duration_from_millis_checked(MAX_MILLIS + 1),
+			Err(NiceOverflowError::new(MAX_MILLIS + 1, MAX_MILLIS)),
+		);
+		assert_eq!(duration_from_millis(MAX_MILLIS + 1), duration_from_millis(MAX_MILLIS));
+	}
+
+	#[test]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	fn t_hms() {
+		// The magic-number math should match plain old division for every
+		// second of the day.
+		for num in 0..DAY {
+			let expected = [num / 3600, num % 3600 / 60, num % 60].map(|n| n as u8);
+			assert_eq!(hms(num), expected);
+			assert_eq!(hms_u64(u64::from(num)), expected);
+		}
+
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			let (d, h, m, s) = dhms_u64(num);
+			assert_eq!(d * 86_400 + u64::from(h) * 3600 + u64::from(m) * 60 + u64::from(s), num);
+
+			if let Ok(num32) = u32::try_from(num) {
+				assert_eq!(dhms(num32), (d as u16, h, m, s));
+			}
+			else { assert_eq!(hms_u64(num), [23, 59, 59]); }
+		}
+	}
+}
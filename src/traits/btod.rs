@@ -0,0 +1,342 @@
+/*!
+# Dactyl — Bytes to Duration.
+*/
+
+use std::time::Duration;
+
+
+
+/// # Nanoseconds per Millisecond.
+const MS: u128 = 1_000_000;
+
+/// # Nanoseconds per Second.
+const SEC: u128 = 1_000_000_000;
+
+/// # Nanoseconds per Minute.
+const MIN: u128 = SEC * 60;
+
+/// # Nanoseconds per Hour.
+const HOUR: u128 = MIN * 60;
+
+/// # Nanoseconds per Day.
+const DAY: u128 = HOUR * 24;
+
+
+
+/// # Bytes to Duration.
+///
+/// This trait exposes the method `btod` which converts human-entered (UTF-8)
+/// byte slices like `1h 30m` or `1:30:00` into a [`Duration`], closing the
+/// loop on [`NiceClock`](crate::NiceClock),
+/// [`NiceElapsed`](crate::NiceElapsed), and
+/// [`NiceIso8601`](crate::NiceIso8601), the output of which can all be parsed
+/// back.
+///
+/// Two general formats are supported:
+///
+/// **Clock**: two or three colon-separated parts — `M:SS` or `H:MM:SS` —
+/// with optional fractional seconds, e.g. `1:30:00` or `2:05.5`.
+///
+/// **Units**: one or more numbers — with optional thousands separators and/or
+/// fractions — each followed by a (case-insensitive) unit, optionally
+/// separated by whitespace, commas, and/or the word "and". (As a special
+/// case, a single number without any unit is treated as seconds.) The
+/// supported units are:
+///
+/// | Unit | Labels |
+/// | ---- | ------ |
+/// | Days | `d`, `day`, `days` |
+/// | Hours | `h`, `hr`, `hrs`, `hour`, `hours` |
+/// | Minutes | `m`, `min`, `mins`, `minute`, `minutes` |
+/// | Seconds | `s`, `sec`, `secs`, `second`, `seconds` |
+/// | Milliseconds | `ms`, `msec`, `msecs`, `millisecond`, `milliseconds` |
+///
+/// ISO-8601 durations of the `PT…` variety are handled as a special case of
+/// the latter.
+///
+/// The method will return `None` if the slice is empty, malformed, or too
+/// large for a `Duration`.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::BytesToDuration;
+/// use std::time::Duration;
+///
+/// assert_eq!(Duration::btod(b"90"), Some(Duration::from_secs(90)));
+/// assert_eq!(Duration::btod(b"90s"), Some(Duration::from_secs(90)));
+/// assert_eq!(Duration::btod(b"1h 30m"), Some(Duration::from_secs(5400)));
+/// assert_eq!(Duration::btod(b"1:30:00"), Some(Duration::from_secs(5400)));
+/// assert_eq!(Duration::btod(b"1.5 hours"), Some(Duration::from_secs(5400)));
+/// assert_eq!(Duration::btod(b"PT1H30M"), Some(Duration::from_secs(5400)));
+/// assert_eq!(
+///     Duration::btod(b"1 hour, 30 minutes, and 0.5 seconds"),
+///     Some(Duration::from_millis(5_400_500)),
+/// );
+///
+/// // Nonsense is nonsense.
+/// assert_eq!(Duration::btod(b"1 fortnight"), None);
+/// assert_eq!(Duration::btod(b"1:75"), None);
+/// ```
+///
+/// And round-tripping:
+///
+/// ```
+/// use dactyl::{NiceElapsed, traits::BytesToDuration};
+/// use std::time::Duration;
+///
+/// let nice = NiceElapsed::from(1_234_567_u32);
+/// assert_eq!(nice.as_str(), "14 days, 6 hours, 56 minutes, and 7 seconds");
+/// assert_eq!(
+///     Duration::btod(nice.as_bytes()),
+///     Some(Duration::from_secs(1_234_567)),
+/// );
+/// ```
+pub trait BytesToDuration: Sized {
+	/// # Bytes to Duration.
+	fn btod(src: &[u8]) -> Option<Self>;
+}
+
+impl BytesToDuration for Duration {
+	fn btod(src: &[u8]) -> Option<Self> {
+		let src = src.trim_ascii();
+		let nanos =
+			if src.is_empty() { return None; }
+			else if src.contains(&b':') { parse_clock(src)? }
+			else if let [b'p' | b'P', b't' | b'T', rest @ ..] = src { parse_units(rest)? }
+			else { parse_units(src)? };
+
+		let secs = u64::try_from(nanos / SEC).ok()?;
+		let nanos = u32::try_from(nanos % SEC).ok()?;
+		Some(Self::new(secs, nanos))
+	}
+}
+
+
+
+/// # Parse Clock.
+///
+/// Parse an `M:SS` or `H:MM:SS` value, returning the total nanoseconds.
+fn parse_clock(src: &[u8]) -> Option<u128> {
+	let mut parts = src.split(|b| b':' == *b);
+	let (h, m, s) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+		(Some(m), Some(s), None, _) => (None, m, s),
+		(Some(h), Some(m), Some(s), None) => (Some(h), m, s),
+		_ => return None,
+	};
+
+	// Only the seconds may have a fraction.
+	let (s, s_frac) = parse_exact(s)?;
+	let (m, m_frac) = parse_exact(m)?;
+	if 60 <= s || m_frac != 0 { return None; }
+
+	let mut total = (s * SEC + s_frac).checked_add(m.checked_mul(MIN)?)?;
+	if let Some(h) = h {
+		let (h, h_frac) = parse_exact(h)?;
+		if 60 <= m || h_frac != 0 { return None; }
+		total = total.checked_add(h.checked_mul(HOUR)?)?;
+	}
+	Some(total)
+}
+
+/// # Parse Exact.
+///
+/// Parse a number that makes up the entirety of the slice, returning the
+/// whole and fractional (billionths) parts.
+fn parse_exact(src: &[u8]) -> Option<(u128, u128)> {
+	let (whole, frac, rest) = parse_num(src)?;
+	if rest.is_empty() { Some((whole, frac)) }
+	else { None }
+}
+
+/// # Parse Units.
+///
+/// Parse a list of numbers and units, returning the total nanoseconds.
+fn parse_units(mut src: &[u8]) -> Option<u128> {
+	let mut total = 0_u128;
+	let mut any = false;
+	loop {
+		src = skip_joiners(src);
+		if src.is_empty() { break; }
+
+		let (whole, frac, rest) = parse_num(src)?;
+		let rest = rest.trim_ascii_start();
+		let len = rest.iter().take_while(|b| b.is_ascii_alphabetic()).count();
+		let (label, rest) = rest.split_at(len);
+
+		// Unitless numbers are only allowed on their own.
+		let unit =
+			if label.is_empty() {
+				if any || ! rest.is_empty() { return None; }
+				SEC
+			}
+			else { unit(label)? };
+
+		total = total.checked_add(whole.checked_mul(unit)?)?
+			.checked_add(frac * unit / SEC)?;
+		src = rest;
+		any = true;
+	}
+
+	if any { Some(total) }
+	else { None }
+}
+
+/// # Parse Number.
+///
+/// Parse a leading number — with optional thousands separators and/or
+/// decimal — returning the whole part, the fractional part (in billionths),
+/// and whatever remains of the slice.
+fn parse_num(src: &[u8]) -> Option<(u128, u128, &[u8])> {
+	let mut whole = 0_u128;
+	let mut digits = 0;
+	let mut idx = 0;
+	while let Some(&b) = src.get(idx) {
+		if b.is_ascii_digit() {
+			whole = whole.checked_mul(10)?.checked_add(u128::from(b - b'0'))?;
+			digits += 1;
+		}
+		// Thousands separators must be followed by exactly three digits.
+		else if
+			b == b',' &&
+			digits != 0 &&
+			src.get(idx + 1..idx + 4).is_some_and(|s| s.iter().all(u8::is_ascii_digit)) &&
+			! src.get(idx + 4).is_some_and(u8::is_ascii_digit)
+		{}
+		else { break; }
+		idx += 1;
+	}
+	if digits == 0 { return None; }
+
+	// Fractions.
+	let mut frac = 0_u128;
+	if src.get(idx) == Some(&b'.') {
+		idx += 1;
+		let start = idx;
+		let mut scale = SEC;
+		while let Some(&b) = src.get(idx) {
+			if ! b.is_ascii_digit() { break; }
+			scale /= 10;
+			frac += u128::from(b - b'0') * scale;
+			idx += 1;
+		}
+		if idx == start { return None; }
+	}
+
+	Some((whole, frac, &src[idx..]))
+}
+
+/// # Skip Joiners.
+///
+/// Strip leading whitespace, commas, and the word "and".
+fn skip_joiners(mut src: &[u8]) -> &[u8] {
+	loop {
+		match src {
+			[b' ' | b'\t' | b'\n' | b'\r' | b',', rest @ ..] => { src = rest; },
+			[a, n, d, rest @ ..]
+				if [*a, *n, *d].eq_ignore_ascii_case(b"and") &&
+				rest.first().is_none_or(u8::is_ascii_whitespace) => { src = rest; },
+			_ => return src,
+		}
+	}
+}
+
+/// # Unit.
+///
+/// Return the number of nanoseconds corresponding to the unit label, or
+/// `None` if unrecognized.
+fn unit(src: &[u8]) -> Option<u128> {
+	let mut buf = [0_u8; 12];
+	let buf = buf.get_mut(..src.len())?;
+	buf.copy_from_slice(src);
+	buf.make_ascii_lowercase();
+
+	match &*buf {
+		b"d" | b"day" | b"days" => Some(DAY),
+		b"h" | b"hr" | b"hrs" | b"hour" | b"hours" => Some(HOUR),
+		b"m" | b"min" | b"mins" | b"minute" | b"minutes" => Some(MIN),
+		b"s" | b"sec" | b"secs" | b"second" | b"seconds" => Some(SEC),
+		b"ms" | b"msec" | b"msecs" | b"millisecond" | b"milliseconds" => Some(MS),
+		_ => None,
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		NiceClock,
+		NiceElapsed,
+		NiceIso8601,
+	};
+
+	#[test]
+	fn t_btod() {
+		for (src, expected) in [
+			("0", Some(Duration::ZERO)),
+			("0 seconds", Some(Duration::ZERO)),
+			(" 90 ", Some(Duration::from_secs(90))),
+			("1h30m", Some(Duration::from_secs(5400))),
+			("1H 30M", Some(Duration::from_secs(5400))),
+			("1 hr, 30 mins", Some(Duration::from_secs(5400))),
+			("2 days and 1 sec", Some(Duration::from_secs(172_801))),
+			("1,000 ms", Some(Duration::from_secs(1))),
+			("250ms", Some(Duration::from_millis(250))),
+			("0.001s", Some(Duration::from_millis(1))),
+			("1:30", Some(Duration::from_secs(90))),
+			("01:30:00", Some(Duration::from_secs(5400))),
+			("100:00:00.25", Some(Duration::from_millis(360_000_250))),
+			("PT0S", Some(Duration::ZERO)),
+			("pt1m1.5s", Some(Duration::from_millis(61_500))),
+			("", None),
+			("  ", None),
+			("and", None),
+			("h", None),
+			("1 h h", None),
+			("1,00 s", None),
+			("1h 30", None),
+			("30 1h", None),
+			("1.s", None),
+			("-1s", None),
+			("1 andy", None),
+			("1::00", None),
+			("1:2:3:4", None),
+			("1:60", None),
+			("1:60:00", None),
+			("1.5:00", None),
+			("5124095576030432h", None),
+		] {
+			assert_eq!(Duration::btod(src.as_bytes()), expected, "{src}");
+		}
+	}
+
+	#[test]
+	fn t_btod_roundtrip() {
+		let mut rng = fastrand::Rng::new();
+
+		// The edges.
+		for i in [0, 1, 59, 60, 3599, 3600, 86_399, 86_400, u32::MAX] {
+			let d = Duration::from_secs(u64::from(i));
+			assert_eq!(Duration::btod(NiceElapsed::from(i).as_bytes()), Some(d));
+			assert_eq!(Duration::btod(NiceIso8601::from(d).as_bytes()), Some(d));
+		}
+		assert_eq!(Duration::btod(NiceIso8601::from(Duration::MAX).as_bytes()), Some(Duration::new(u64::MAX, 999_000_000)));
+
+		for i in std::iter::repeat_with(|| rng.u32(..)).take(10_000) {
+			let d = Duration::from_secs(u64::from(i));
+			assert_eq!(Duration::btod(NiceElapsed::from(i).as_bytes()), Some(d));
+			assert_eq!(Duration::btod(NiceIso8601::from(d).as_bytes()), Some(d));
+
+			let d = Duration::from_millis(u64::from(i));
+			assert_eq!(Duration::btod(NiceIso8601::from(d).as_bytes()), Some(d));
+
+			let i = i % 86_400;
+			assert_eq!(
+				Duration::btod(NiceClock::from(i).as_bytes()),
+				Some(Duration::from_secs(u64::from(i))),
+			);
+		}
+	}
+}
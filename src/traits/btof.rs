@@ -0,0 +1,229 @@
+/*!
+# Dactyl — Bytes to Float.
+*/
+
+use crate::ParseByteError;
+
+
+
+/// # Bytes to Float.
+///
+/// This is the floating-point counterpart to [`BytesToUnsigned`](crate::traits::BytesToUnsigned)
+/// and [`BytesToSigned`](crate::traits::BytesToSigned), parsing `f32` and
+/// `f64` values directly from ASCII byte slices, without the need for an
+/// intermediate UTF-8 check.
+///
+/// The following format is supported:
+/// * An optional leading `+` or `-`;
+/// * Digits, with an optional decimal point (`.`) somewhere among them;
+/// * An optional exponent: `e` or `E`, an optional sign, and more digits.
+///
+/// There must be at least one digit before the exponent, if any. Named
+/// values like `inf` and `NaN` are not supported, and values too large to
+/// represent — i.e. that would parse as infinity — are treated as failures.
+///
+/// If you need to know _why_ a slice couldn't be parsed, use
+/// [`BytesToFloat::btof_checked`] instead.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::BytesToFloat;
+///
+/// assert_eq!(f64::btof(b"123.45"), Some(123.45));
+/// assert_eq!(f64::btof(b"-.5"), Some(-0.5));
+/// assert_eq!(f64::btof(b"1.5e3"), Some(1500.0));
+/// assert_eq!(f32::btof(b"+2E-2"), Some(0.02));
+///
+/// // Bad stuff.
+/// assert_eq!(f64::btof(b""), None);
+/// assert_eq!(f64::btof(b"."), None);
+/// assert_eq!(f64::btof(b"1.2.3"), None);
+/// assert_eq!(f64::btof(b"inf"), None);
+/// assert_eq!(f64::btof(b"1e999"), None); // Too big.
+/// ```
+pub trait BytesToFloat: Sized {
+	/// # Bytes to Float.
+	fn btof(src: &[u8]) -> Option<Self>;
+
+	/// # Bytes to Float (Checked).
+	///
+	/// This works just like [`BytesToFloat::btof`], but returns a
+	/// [`ParseByteError`] explaining the failure, if any.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{
+	///     ParseByteError,
+	///     traits::BytesToFloat,
+	/// };
+	///
+	/// assert_eq!(f64::btof_checked(b"-1.25"), Ok(-1.25));
+	/// assert_eq!(f64::btof_checked(b""), Err(ParseByteError::Empty));
+	/// assert_eq!(f64::btof_checked(b"-"), Err(ParseByteError::InvalidDigit(0)));
+	/// assert_eq!(f64::btof_checked(b"1.2x"), Err(ParseByteError::InvalidDigit(3)));
+	/// assert_eq!(f64::btof_checked(b"1e"), Err(ParseByteError::InvalidDigit(1)));
+	/// assert_eq!(f32::btof_checked(b"1e39"), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty, malformed, or its value is too
+	/// large to represent.
+	fn btof_checked(src: &[u8]) -> Result<Self, ParseByteError>;
+}
+
+
+
+/// # Helper: Generate Impls.
+macro_rules! float {
+	($($ty:ty),+) => ($(
+		impl BytesToFloat for $ty {
+			#[inline]
+			/// # Bytes to Float.
+			fn btof(src: &[u8]) -> Option<Self> { Self::btof_checked(src).ok() }
+
+			#[expect(unsafe_code, reason = "Content is ASCII.")]
+			/// # Bytes to Float (Checked).
+			fn btof_checked(src: &[u8]) -> Result<Self, ParseByteError> {
+				validate(src)?;
+
+				// Safety: the slice was just validated as ASCII.
+				let src = unsafe { std::str::from_utf8_unchecked(src) };
+
+				// The standard library takes it from here; our syntax is a
+				// subset of its own.
+				match src.parse::<Self>() {
+					Ok(n) if n.is_finite() => Ok(n),
+					_ => Err(ParseByteError::Overflow),
+				}
+			}
+		}
+	)+);
+}
+
+float!(f32, f64);
+
+
+
+/// # Validate.
+///
+/// Make sure the slice is a properly-formatted decimal float, returning the
+/// reason if not.
+fn validate(src: &[u8]) -> Result<(), ParseByteError> {
+	/// # Skip Digits.
+	///
+	/// Advance `idx` past any digits, returning `true` if there were some.
+	fn digits(src: &[u8], idx: &mut usize) -> bool {
+		let start = *idx;
+		while *idx < src.len() && src[*idx].is_ascii_digit() { *idx += 1; }
+		start < *idx
+	}
+
+	/// # Optional Sign.
+	///
+	/// Advance `idx` past a `+` or `-`, if present.
+	fn sign(src: &[u8], idx: &mut usize) {
+		if *idx < src.len() && matches!(src[*idx], b'+' | b'-') { *idx += 1; }
+	}
+
+	if src.is_empty() { return Err(ParseByteError::Empty); }
+
+	// If a section comes up empty, the last byte read is to blame.
+	let blame = |idx: usize| ParseByteError::InvalidDigit(idx.min(src.len() - 1));
+
+	// The sign and number.
+	let mut idx = 0;
+	sign(src, &mut idx);
+	let mut any = digits(src, &mut idx);
+	if idx < src.len() && src[idx] == b'.' {
+		idx += 1;
+		any |= digits(src, &mut idx);
+	}
+	if ! any { return Err(blame(idx)); }
+
+	// The exponent.
+	if idx < src.len() && matches!(src[idx], b'e' | b'E') {
+		idx += 1;
+		sign(src, &mut idx);
+		if ! digits(src, &mut idx) { return Err(blame(idx)); }
+	}
+
+	// There shouldn't be anything left over.
+	if idx < src.len() { Err(ParseByteError::InvalidDigit(idx)) }
+	else { Ok(()) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_btof() {
+		// These should match the standard library, except for infinity.
+		for src in [
+			"0", "-0", "+0", "1", "-1", "0.5", ".5", "5.", "-.5", "+5.",
+			"123.456", "1e3", "1E3", "1e+3", "1e-3", "-1.5e-3", ".5e1",
+			"00001.00001", "3.141592653589793", "1e-999",
+			"340282346638528859811704183484516925440",
+			"179769313486231570000000000000000000000e269",
+		] {
+			assert_eq!(
+				f64::btof(src.as_bytes()),
+				src.parse::<f64>().ok().filter(|n| n.is_finite()),
+				"{src}",
+			);
+			assert_eq!(
+				f32::btof(src.as_bytes()),
+				src.parse::<f32>().ok().filter(|n| n.is_finite()),
+				"{src}",
+			);
+		}
+
+		// As should some random values.
+		let mut rng = fastrand::Rng::new();
+		for _ in 0..1000 {
+			let num = rng.f64() * f64::from(rng.i32(..));
+			for src in [num.to_string(), format!("{num:e}"), format!("{num:.3}")] {
+				assert_eq!(f64::btof(src.as_bytes()), Some(src.parse::<f64>().unwrap()), "{src}");
+			}
+		}
+	}
+
+	#[test]
+	fn t_btof_checked() {
+		for (src, expected) in [
+			(&b""[..], ParseByteError::Empty),
+			(b"-", ParseByteError::InvalidDigit(0)),
+			(b".", ParseByteError::InvalidDigit(0)),
+			(b"-.", ParseByteError::InvalidDigit(1)),
+			(b"x", ParseByteError::InvalidDigit(0)),
+			(b"1x", ParseByteError::InvalidDigit(1)),
+			(b"1.2.3", ParseByteError::InvalidDigit(3)),
+			(b"1e", ParseByteError::InvalidDigit(1)),
+			(b"1e+", ParseByteError::InvalidDigit(2)),
+			(b"1e5.5", ParseByteError::InvalidDigit(3)),
+			(b"e5", ParseByteError::InvalidDigit(0)),
+			(b"--1", ParseByteError::InvalidDigit(1)),
+			(b" 1", ParseByteError::InvalidDigit(0)),
+			(b"1 ", ParseByteError::InvalidDigit(1)),
+			(b"inf", ParseByteError::InvalidDigit(0)),
+			(b"NaN", ParseByteError::InvalidDigit(0)),
+			(b"1e999", ParseByteError::Overflow),
+			(b"-1e999", ParseByteError::Overflow),
+		] {
+			assert_eq!(
+				f64::btof_checked(src),
+				Err(expected),
+				"{:?}", String::from_utf8_lossy(src),
+			);
+		}
+
+		// f32 overflows sooner.
+		assert_eq!(f32::btof_checked(b"1e39"), Err(ParseByteError::Overflow));
+		assert_eq!(f64::btof_checked(b"1e39"), Ok(1e39));
+	}
+}
@@ -4,7 +4,10 @@
 
 #![expect(clippy::cast_possible_truncation, reason = "False positive.")]
 
-use crate::traits::BytesToUnsigned;
+use crate::{
+	ParseByteError,
+	traits::BytesToUnsigned,
+};
 use std::{
 	cmp::Ordering,
 	num::{
@@ -25,6 +28,9 @@ use std::{
 /// It works exactly the same way and for the same reason, except the first
 /// byte can optionally be a `+` or `-`.
 ///
+/// If you need to know _why_ a slice couldn't be parsed, use
+/// [`BytesToSigned::btoi_checked`] instead.
+///
 /// ## Examples
 ///
 /// ```
@@ -38,6 +44,56 @@ use std::{
 pub trait BytesToSigned: Sized {
 	/// # Bytes to Signed.
 	fn btoi(src: &[u8]) -> Option<Self>;
+
+	/// # Bytes to Signed (Checked).
+	///
+	/// This works just like [`BytesToSigned::btoi`], but returns a
+	/// [`ParseByteError`] explaining the failure, if any.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{
+	///     ParseByteError,
+	///     traits::BytesToSigned,
+	/// };
+	///
+	/// assert_eq!(i8::btoi_checked(b"-120"), Ok(-120));
+	/// assert_eq!(i8::btoi_checked(b"-"), Err(ParseByteError::InvalidDigit(0)));
+	/// assert_eq!(i8::btoi_checked(b"-1-"), Err(ParseByteError::InvalidDigit(2)));
+	/// assert_eq!(i8::btoi_checked(b"-129"), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty, contains non-digits (other
+	/// than a leading sign), or its value is out of range for the type.
+	fn btoi_checked(src: &[u8]) -> Result<Self, ParseByteError> {
+		Self::btoi(src).ok_or_else(|| ParseByteError::from_decimal(src, true))
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Bytes to Signed (Trimmed).
+	///
+	/// This works just like [`BytesToSigned::btoi`], but ignores leading and
+	/// trailing ASCII whitespace.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::BytesToSigned;
+	///
+	/// assert_eq!(i32::btoi_trim(b"  -1234\n"), Some(-1234));
+	/// assert_eq!(i32::btoi_trim(b"\t+1234 "), Some(1234));
+	///
+	/// // The strict version is still strict.
+	/// assert_eq!(i32::btoi(b" -1234"), None);
+	///
+	/// // Whitespace is only trimmed from the ends.
+	/// assert_eq!(i32::btoi_trim(b"- 1234"), None);
+	/// ```
+	fn btoi_trim(src: &[u8]) -> Option<Self> { Self::btoi(src.trim_ascii()) }
 }
 
 
@@ -137,6 +193,12 @@ mod tests {
 			assert_eq!(<$ty>::btoi(b"-0123"), Some(-123));
 			assert_eq!(<$ty>::btoi(b"apples"), None);
 
+			assert_eq!(<$ty>::btoi_trim(b" 1"), Some(1));
+			assert_eq!(<$ty>::btoi_trim(b"\t+123\n"), Some(123));
+			assert_eq!(<$ty>::btoi_trim(b" -0123 "), Some(-123));
+			assert_eq!(<$ty>::btoi_trim(b"  "), None);
+			assert_eq!(<$ty>::btoi_trim(b"- 1"), None);
+
 			assert_eq!(<$ty>::btoi(b"-0"), Some(0));
 			assert_eq!(<$ty>::btoi(b"+0"), Some(0));
 			assert_eq!(<$ty>::btoi(b"0"), Some(0));
@@ -145,6 +207,12 @@ mod tests {
 			assert_eq!(<$ty>::btoi(b"00000000"), Some(0));
 			assert_eq!(<$ty>::btoi(b"0000000000000000"), Some(0));
 			assert_eq!(<$ty>::btoi(b"000000000000000000000000000000000000000000000000"), Some(0));
+
+			assert_eq!(<$ty>::btoi_checked(b""), Err(ParseByteError::Empty));
+			assert_eq!(<$ty>::btoi_checked(b"+"), Err(ParseByteError::InvalidDigit(0)));
+			assert_eq!(<$ty>::btoi_checked(b"1.0"), Err(ParseByteError::InvalidDigit(1)));
+			assert_eq!(<$ty>::btoi_checked(b"--1"), Err(ParseByteError::InvalidDigit(1)));
+			assert_eq!(<$ty>::btoi_checked(b"-0123"), Ok(-123));
 		);
 	}
 
@@ -153,6 +221,8 @@ mod tests {
 		sanity_check!(i8);
 		assert_eq!(i8::btoi(b"0127"), Some(i8::MAX));
 		assert_eq!(i8::btoi(b"128"), None);
+		assert_eq!(i8::btoi_checked(b"128"), Err(ParseByteError::Overflow));
+		assert_eq!(NonZeroI8::btoi_checked(b"-00"), Err(ParseByteError::Zero));
 
 		// This is small enough we can check every value.
 		for i in i8::MIN..=i8::MAX {
@@ -167,6 +237,7 @@ mod tests {
 		sanity_check!(i16);
 		assert_eq!(i16::btoi(b"032767"), Some(i16::MAX));
 		assert_eq!(i16::btoi(b"32768"), None);
+		assert_eq!(i16::btoi_checked(b"32768"), Err(ParseByteError::Overflow));
 
 		// This is small enough we can check every value.
 		#[cfg(not(miri))]
@@ -194,6 +265,7 @@ mod tests {
 		assert_eq!(i32::btoi(b"2147483647"), Some(i32::MAX));
 		assert_eq!(i32::btoi(b"02147483647"), Some(i32::MAX));
 		assert_eq!(i32::btoi(b"2147483648"), None);
+		assert_eq!(i32::btoi_checked(b"2147483648"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -211,6 +283,7 @@ mod tests {
 		assert_eq!(i64::btoi(b"9223372036854775807"), Some(i64::MAX));
 		assert_eq!(i64::btoi(b"09223372036854775807"), Some(i64::MAX));
 		assert_eq!(i64::btoi(b"9223372036854775808"), None);
+		assert_eq!(i64::btoi_checked(b"9223372036854775808"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -228,6 +301,7 @@ mod tests {
 		assert_eq!(i128::btoi(b"170141183460469231731687303715884105727"), Some(i128::MAX));
 		assert_eq!(i128::btoi(b"0170141183460469231731687303715884105727"), Some(i128::MAX));
 		assert_eq!(i128::btoi(b"170141183460469231731687303715884105728"), None);
+		assert_eq!(i128::btoi_checked(b"170141183460469231731687303715884105728"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -2,6 +2,7 @@
 # Dactyl — Bytes to Unsigned.
 */
 
+use crate::ParseByteError;
 use std::num::{
 	NonZeroU8,
 	NonZeroU16,
@@ -26,6 +27,9 @@ use std::num::{
 /// Only little endian architectures are optimized; for big endian machines,
 /// this trait just passes through the results of [`str::parse`].
 ///
+/// If you need to know _why_ a slice couldn't be parsed, use
+/// [`BytesToUnsigned::btou_checked`] instead.
+///
 /// For signed integer parsing, see [`BytesToSigned`](crate::traits::BytesToSigned);
 ///
 /// ## Examples
@@ -41,15 +45,142 @@ use std::num::{
 pub trait BytesToUnsigned: Sized {
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self>;
+
+	/// # Bytes to Unsigned (Checked).
+	///
+	/// This works just like [`BytesToUnsigned::btou`], but returns a
+	/// [`ParseByteError`] explaining the failure, if any.
+	///
+	/// The happy path is exactly as fast as `btou`; the diagnosis only runs
+	/// after a failure.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{
+	///     ParseByteError,
+	///     traits::BytesToUnsigned,
+	/// };
+	/// use std::num::NonZeroU32;
+	///
+	/// assert_eq!(u32::btou_checked(b"1234"), Ok(1234));
+	/// assert_eq!(u32::btou_checked(b""), Err(ParseByteError::Empty));
+	/// assert_eq!(u32::btou_checked(b"+1234"), Err(ParseByteError::InvalidDigit(0)));
+	/// assert_eq!(u32::btou_checked(b"99999999999"), Err(ParseByteError::Overflow));
+	/// assert_eq!(NonZeroU32::btou_checked(b"0"), Err(ParseByteError::Zero));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty, contains non-digits, or its
+	/// value is out of range for the type.
+	fn btou_checked(src: &[u8]) -> Result<Self, ParseByteError> {
+		Self::btou(src).ok_or_else(|| ParseByteError::from_decimal(src, false))
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Bytes to Unsigned (Trimmed).
+	///
+	/// This works just like [`BytesToUnsigned::btou`], but is a little more
+	/// forgiving of human-entered input, ignoring leading and trailing ASCII
+	/// whitespace, as well as a single leading `+`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::BytesToUnsigned;
+	///
+	/// assert_eq!(u32::btou_trim(b"  1234\n"), Some(1234));
+	/// assert_eq!(u32::btou_trim(b"\t+1234 "), Some(1234));
+	///
+	/// // The strict version is still strict.
+	/// assert_eq!(u32::btou(b" 1234"), None);
+	/// assert_eq!(u32::btou(b"+1234"), None);
+	///
+	/// // Whitespace is only trimmed from the ends.
+	/// assert_eq!(u32::btou_trim(b"+ 1234"), None);
+	/// assert_eq!(u32::btou_trim(b"12 34"), None);
+	/// assert_eq!(u32::btou_trim(b"-1234"), None);
+	/// ```
+	fn btou_trim(src: &[u8]) -> Option<Self> {
+		let src = src.trim_ascii();
+		match src {
+			[b'+', rest @ ..] => Self::btou(rest),
+			_ => Self::btou(src),
+		}
+	}
 }
 
 
 
+/// # Bytes to Unsigned (Fixed Width).
+///
+/// This trait exposes the method `btou_fixed`, a specialized variant of
+/// [`BytesToUnsigned::btou`] for inputs of a known, fixed length — e.g.
+/// exactly-eight-digit record IDs — that skips all the length branching and
+/// overflow checks.
+///
+/// It is implemented for the following type/width combinations, each of which
+/// maps directly to one of the internal SWAR parsers:
+///
+/// | Type | Width |
+/// | ---- | ----- |
+/// | `u16` | 2, 4 |
+/// | `u32` | 8 |
+/// | `u64` | 16 |
+///
+/// As with `btou`, leading zeroes are fine, but the method will return `None`
+/// if the array contains anything other than ASCII digits.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::BytesToUnsignedFixed;
+///
+/// assert_eq!(u32::btou_fixed(b"00123456"), Some(123_456));
+/// assert_eq!(u64::btou_fixed(b"1234567890123456"), Some(1_234_567_890_123_456));
+/// assert_eq!(u32::btou_fixed(b"0012345x"), None);
+///
+/// // The width can also be given explicitly.
+/// let src = [b'9'; 4];
+/// assert_eq!(<u16 as BytesToUnsignedFixed<4>>::btou_fixed(&src), Some(9999));
+/// ```
+pub trait BytesToUnsignedFixed<const N: usize>: Sized {
+	/// # Bytes to Unsigned (Fixed Width).
+	fn btou_fixed(src: &[u8; N]) -> Option<Self>;
+}
+
+/// # Helper: Fixed-Width Implementations.
+macro_rules! fixed {
+	($($ty:ty, $n:literal, $parse:ident;)+) => ($(
+		impl BytesToUnsignedFixed<$n> for $ty {
+			#[cfg(target_endian = "little")]
+			#[inline]
+			/// # Bytes to Unsigned (Fixed Width).
+			fn btou_fixed(src: &[u8; $n]) -> Option<Self> { $parse(src) }
+
+			#[cfg(target_endian = "big")]
+			#[inline]
+			/// # Bytes to Unsigned (Fixed Width).
+			fn btou_fixed(src: &[u8; $n]) -> Option<Self> { Self::btou(src) }
+		}
+	)+);
+}
+
+fixed!(
+	u16, 2, parse2;
+	u16, 4, parse4;
+	u32, 8, parse8;
+	u64, 16, parse16;
+);
+
+
+
 /// # Helper: Generate Fallbacks.
 macro_rules! big {
 	() => (
 		#[cfg(target_endian = "big")]
-		#[must_use]
 		/// # Bytes to Unsigned.
 		fn btou(src: &[u8]) -> Option<Self> {
 			if src.is_empty() || src[0] == b'+' { None }
@@ -94,7 +225,6 @@ impl BytesToUnsigned for u8 {
 
 impl BytesToUnsigned for u16 {
 	#[cfg(target_endian = "little")]
-	#[must_use]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> {
 		match src.len() {
@@ -121,7 +251,6 @@ impl BytesToUnsigned for u16 {
 
 impl BytesToUnsigned for u32 {
 	#[cfg(target_endian = "little")]
-	#[must_use]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> {
 		match src.len() {
@@ -163,7 +292,6 @@ impl BytesToUnsigned for u32 {
 
 impl BytesToUnsigned for u64 {
 	#[cfg(target_endian = "little")]
-	#[must_use]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> {
 		match src.len() {
@@ -232,7 +360,6 @@ impl BytesToUnsigned for u128 {
 	#[cfg(target_endian = "little")]
 	#[expect(clippy::cognitive_complexity, reason = "Readability.")]
 	#[expect(clippy::too_many_lines, reason = "These numbers are huge.")]
-	#[must_use]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> {
 		match src.len() {
@@ -363,19 +490,16 @@ impl BytesToUnsigned for u128 {
 #[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 impl BytesToUnsigned for usize {
 	#[cfg(target_pointer_width = "16")]
-	#[must_use]
 	#[inline]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> { u16::btou(src).map(Self::from) }
 
 	#[cfg(target_pointer_width = "32")]
-	#[must_use]
 	#[inline]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> { Some(u32::btou(src)? as Self) }
 
 	#[cfg(target_pointer_width = "64")]
-	#[must_use]
 	#[inline]
 	/// # Bytes to Unsigned.
 	fn btou(src: &[u8]) -> Option<Self> { Some(u64::btou(src)? as Self) }
@@ -571,6 +695,22 @@ mod tests {
 			assert_eq!(<$ty>::btou(b"00000000"), Some(0));
 			assert_eq!(<$ty>::btou(b"0000000000000000"), Some(0));
 			assert_eq!(<$ty>::btou(b"000000000000000000000000000000000000000000000000"), Some(0));
+
+			assert_eq!(<$ty>::btou_checked(b""), Err(ParseByteError::Empty));
+			assert_eq!(<$ty>::btou_checked(b" 1"), Err(ParseByteError::InvalidDigit(0)));
+			assert_eq!(<$ty>::btou_checked(b"1.0"), Err(ParseByteError::InvalidDigit(1)));
+			assert_eq!(<$ty>::btou_checked(b"+123"), Err(ParseByteError::InvalidDigit(0)));
+			assert_eq!(<$ty>::btou_checked(b"0000"), Ok(0));
+			assert_eq!(<$ty>::btou_checked(b"123"), Ok(123));
+
+			assert_eq!(<$ty>::btou_trim(b" 1"), Some(1));
+			assert_eq!(<$ty>::btou_trim(b"+123"), Some(123));
+			assert_eq!(<$ty>::btou_trim(b"\r\n +0123 \t"), Some(123));
+			assert_eq!(<$ty>::btou_trim(b"  "), None);
+			assert_eq!(<$ty>::btou_trim(b" + "), None);
+			assert_eq!(<$ty>::btou_trim(b"++1"), None);
+			assert_eq!(<$ty>::btou_trim(b"-1"), None);
+			assert_eq!(<$ty>::btou_trim(b"1 2"), None);
 		);
 	}
 
@@ -579,6 +719,8 @@ mod tests {
 		sanity_check!(u8);
 		assert_eq!(u8::btou(b"0255"), Some(u8::MAX));
 		assert_eq!(u8::btou(b"256"), None);
+		assert_eq!(u8::btou_checked(b"256"), Err(ParseByteError::Overflow));
+		assert_eq!(NonZeroU8::btou_checked(b"000"), Err(ParseByteError::Zero));
 
 		// This is small enough we can check every value.
 		for i in 0..=u8::MAX {
@@ -593,6 +735,7 @@ mod tests {
 		sanity_check!(u16);
 		assert_eq!(u16::btou(b"065535"), Some(u16::MAX));
 		assert_eq!(u16::btou(b"65536"), None);
+		assert_eq!(u16::btou_checked(b"65536"), Err(ParseByteError::Overflow));
 
 		// This is small enough we can check every value.
 		#[cfg(not(miri))]
@@ -619,6 +762,7 @@ mod tests {
 		assert_eq!(u32::btou(b"4294967295"), Some(u32::MAX));
 		assert_eq!(u32::btou(b"04294967295"), Some(u32::MAX));
 		assert_eq!(u32::btou(b"4294967296"), None);
+		assert_eq!(u32::btou_checked(b"4294967296"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -635,6 +779,7 @@ mod tests {
 		assert_eq!(u64::btou(b"18446744073709551615"), Some(u64::MAX));
 		assert_eq!(u64::btou(b"018446744073709551615"), Some(u64::MAX));
 		assert_eq!(u64::btou(b"18446744073709551616"), None);
+		assert_eq!(u64::btou_checked(b"18446744073709551616"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -651,6 +796,7 @@ mod tests {
 		assert_eq!(u128::btou(b"340282366920938463463374607431768211455"), Some(u128::MAX));
 		assert_eq!(u128::btou(b"0340282366920938463463374607431768211455"), Some(u128::MAX));
 		assert_eq!(u128::btou(b"340282366920938463463374607431768211456"), None);
+		assert_eq!(u128::btou_checked(b"340282366920938463463374607431768211456"), Err(ParseByteError::Overflow));
 
 		// Now let's check ten million random values and hope they all hit.
 		let mut rng = fastrand::Rng::new();
@@ -661,6 +807,33 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn t_fixed() {
+		macro_rules! fixed {
+			($ty:ty, $n:literal) => (
+				assert_eq!(<$ty>::btou_fixed(&[b'0'; $n]), Some(0));
+				assert_eq!(<$ty>::btou_fixed(&[b'9'; $n]), <$ty>::btou(&[b'9'; $n]));
+				assert_eq!(<$ty>::btou_fixed(&[b'a'; $n]), None);
+
+				let mut rng = fastrand::Rng::new();
+				for _ in 0..SAMPLE_SIZE.min(50_000) {
+					let mut src = [0_u8; $n];
+					for b in &mut src { *b = rng.u8(b'0'..=b'9'); }
+					assert_eq!(<$ty>::btou_fixed(&src), <$ty>::btou(&src));
+
+					// Non-digits anywhere should fail.
+					src[rng.usize(0..$n)] = b'+';
+					assert_eq!(<$ty>::btou_fixed(&src), None);
+				}
+			);
+		}
+
+		fixed!(u16, 2);
+		fixed!(u16, 4);
+		fixed!(u32, 8);
+		fixed!(u64, 16);
+	}
+
 	#[test]
 	fn t_usize() {
 		sanity_check!(usize);
@@ -0,0 +1,245 @@
+/*!
+# Dactyl: Digits
+*/
+
+use super::SaturatingFrom;
+
+
+
+/// # Unsigned Digits.
+///
+/// This trait adds a few small digit-analysis helpers to the unsigned integer
+/// primitives — counts, sums, and histograms — handy for data-validation
+/// routines that need to sanity-check numbers before or after formatting
+/// them.
+///
+/// All methods consider the _decimal_ representation of the value. Zero has
+/// one digit: `0`.
+///
+/// See also [`luhn_checksum`], for checksum calculations on digit strings.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::UnsignedDigits;
+///
+/// assert_eq!(12_345_u32.digit_count(), 5);
+/// assert_eq!(12_345_u32.digit_sum(), 15);
+/// assert_eq!(
+///     1_220_u16.digit_histogram(),
+///     [1, 1, 2, 0, 0, 0, 0, 0, 0, 0],
+/// );
+/// ```
+pub trait UnsignedDigits: Copy {
+	/// # Digit Count.
+	///
+	/// Return the number of decimal digits in the value.
+	fn digit_count(self) -> u32;
+
+	/// # Digit Sum.
+	///
+	/// Return the sum of the decimal digits in the value.
+	fn digit_sum(self) -> u32;
+
+	/// # Digit Histogram.
+	///
+	/// Return the number of times each decimal digit — `0` through `9` —
+	/// appears in the value, indexed by digit.
+	fn digit_histogram(self) -> [u8; 10];
+}
+
+/// # Helper: Implement Trait.
+macro_rules! digits {
+	($($ty:ty, $ex:literal, $count:literal, $sum:literal);+ $(;)?) => ($(
+		impl UnsignedDigits for $ty {
+			#[inline]
+			/// # Digit Count.
+			///
+			/// Return the number of decimal digits in the value.
+			///
+			/// ## Examples
+			///
+			/// ```
+			/// use dactyl::traits::UnsignedDigits;
+			///
+			#[doc = concat!("assert_eq!(0_", stringify!($ty), ".digit_count(), 1);")]
+			#[doc = concat!("assert_eq!(", $ex, "_", stringify!($ty), ".digit_count(), ", $count, ");")]
+			/// ```
+			fn digit_count(self) -> u32 { digit_count(u128::saturating_from(self)) }
+
+			/// # Digit Sum.
+			///
+			/// Return the sum of the decimal digits in the value.
+			///
+			/// ## Examples
+			///
+			/// ```
+			/// use dactyl::traits::UnsignedDigits;
+			///
+			#[doc = concat!("assert_eq!(0_", stringify!($ty), ".digit_sum(), 0);")]
+			#[doc = concat!("assert_eq!(", $ex, "_", stringify!($ty), ".digit_sum(), ", $sum, ");")]
+			/// ```
+			fn digit_sum(mut self) -> u32 {
+				let mut sum = 0;
+				while self != 0 {
+					sum += u32::from(u8::saturating_from(self % 10));
+					self /= 10;
+				}
+				sum
+			}
+
+			/// # Digit Histogram.
+			///
+			/// Return the number of times each decimal digit — `0` through
+			/// `9` — appears in the value, indexed by digit.
+			///
+			/// ## Examples
+			///
+			/// ```
+			/// use dactyl::traits::UnsignedDigits;
+			///
+			#[doc = concat!("let hist = ", $ex, "_", stringify!($ty), ".digit_histogram();")]
+			#[doc = concat!("assert_eq!(hist.iter().map(|&n| u32::from(n)).sum::<u32>(), ", $count, ");")]
+			/// ```
+			fn digit_histogram(mut self) -> [u8; 10] {
+				let mut out = [0_u8; 10];
+				loop {
+					out[usize::from(u8::saturating_from(self % 10))] += 1;
+					self /= 10;
+					if self == 0 { break; }
+				}
+				out
+			}
+		}
+	)+);
+}
+
+#[must_use]
+/// # Digit Count.
+///
+/// Return the number of decimal digits in `num`. Zero has one digit.
+///
+/// This is the one `const` implementation behind
+/// [`UnsignedDigits::digit_count`], [`NiceU64::__const_len`](crate::NiceU64::__const_len),
+/// and the [`nice_width_*`](crate::nice_width_u64) helpers; smaller types can
+/// simply be cast up.
+pub(crate) const fn digit_count(num: u128) -> u32 {
+	match num.checked_ilog10() {
+		Some(n) => n + 1,
+		None => 1,
+	}
+}
+
+digits! {
+	u8,    "255", 3, 12;
+	u16,   "65535", 5, 24;
+	u32,   "4294967295", 10, 57;
+	u64,   "18446744073709551615", 20, 87;
+	u128,  "340282366920938463463374607431768211455", 39, 165;
+	usize, "12345", 5, 15;
+}
+
+
+
+#[must_use]
+/// # Luhn Checksum.
+///
+/// Calculate the [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) check
+/// digit — `0..=9` — for a payload of ASCII digits, i.e. the digit that
+/// should be appended to it.
+///
+/// To validate a number that already _includes_ its check digit, simply
+/// compare the last digit against the checksum of everything before it.
+///
+/// Returns `None` if `src` is empty or contains anything other than ASCII
+/// digits.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::luhn_checksum;
+///
+/// assert_eq!(luhn_checksum(b"7992739871"), Some(3));
+///
+/// // Validation.
+/// let card = b"4539578763621486";
+/// let (payload, check) = card.split_at(card.len() - 1);
+/// assert_eq!(luhn_checksum(payload), Some(check[0] - b'0'));
+///
+/// // Bad input.
+/// assert_eq!(luhn_checksum(b""), None);
+/// assert_eq!(luhn_checksum(b"1234-5678"), None);
+/// ```
+pub fn luhn_checksum(src: &[u8]) -> Option<u8> {
+	if src.is_empty() { return None; }
+
+	let mut sum = 0_u32;
+	for (idx, b) in src.iter().rev().enumerate() {
+		let mut digit = u32::from(b.checked_sub(b'0').filter(|d| *d < 10)?);
+
+		// Double every other digit, starting with the rightmost.
+		if idx & 1 == 0 {
+			digit *= 2;
+			if 9 < digit { digit -= 9; }
+		}
+		sum += digit;
+	}
+
+	Some(((10 - sum % 10) % 10) as u8)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Helper: Test Digits.
+	macro_rules! t_digits {
+		($($ty:ident),+) => ($(
+			let mut rng = fastrand::Rng::new();
+			for num in std::iter::repeat_with(|| rng.$ty(..) >> rng.u32(0..<$ty>::BITS)).take(5000).chain([0, 1, 9, 10, <$ty>::MAX]) {
+				let s = num.to_string();
+				assert_eq!(num.digit_count() as usize, s.len(), "{num}");
+				assert_eq!(
+					num.digit_sum(),
+					s.bytes().map(|b| u32::from(b - b'0')).sum::<u32>(),
+					"{num}",
+				);
+
+				let mut expected = [0_u8; 10];
+				for b in s.bytes() { expected[usize::from(b - b'0')] += 1; }
+				assert_eq!(num.digit_histogram(), expected, "{num}");
+			}
+		)+);
+	}
+
+	#[test]
+	fn t_digits() {
+		t_digits!(u8, u16, u32, u64, u128, usize);
+	}
+
+	#[test]
+	fn t_luhn_checksum() {
+		for valid in [
+			"79927398713",
+			"4111111111111111",
+			"5500005555555559",
+			"378282246310005",
+			"18",
+		] {
+			let (payload, check) = valid.as_bytes().split_at(valid.len() - 1);
+			assert_eq!(luhn_checksum(payload), Some(check[0] - b'0'), "{valid}");
+
+			// Changing any one digit should break it.
+			for idx in 0..payload.len() {
+				let mut bad = payload.to_vec();
+				bad[idx] = if bad[idx] == b'9' { b'0' } else { bad[idx] + 1 };
+				assert_ne!(luhn_checksum(&bad), Some(check[0] - b'0'), "{valid}");
+			}
+		}
+
+		assert_eq!(luhn_checksum(b" 1"), None);
+		assert_eq!(luhn_checksum(b"1a"), None);
+	}
+}
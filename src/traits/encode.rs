@@ -0,0 +1,228 @@
+/*!
+# Dactyl: Slice Encoding.
+*/
+
+use crate::{
+	NiceBigint,
+	traits::NiceMaxLen,
+};
+
+
+
+/// # Nice Encoding.
+///
+/// This trait — implemented for all of the `Nice*` types — allows formatted
+/// values to be copied straight into an existing byte buffer, e.g. an
+/// embedded frame buffer or FFI-provided memory.
+///
+/// Buffers can be sized ahead of time using the type's [`NiceMaxLen::MAX_LEN`],
+/// or just-in-time using [`NiceEncode::required_len`].
+///
+/// For owned output, [`NiceEncode::into_vec`] and [`NiceEncode::into_string`]
+/// allocate exactly once, while [`NiceEncode::write_to`] appends to an
+/// existing `String`.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceFloat, NiceU32, traits::NiceEncode};
+///
+/// let mut buf = [b' '; 16];
+/// let len = NiceU32::from(1_234_567_u32).encode_into(&mut buf).unwrap();
+/// assert_eq!(&buf[..len], b"1,234,567");
+///
+/// let nice = NiceFloat::from(1.5_f64);
+/// assert_eq!(nice.required_len(), 10);
+/// assert_eq!(nice.encode_into(&mut buf[len..]), None); // Too small!
+/// ```
+pub trait NiceEncode: AsRef<[u8]> {
+	#[inline]
+	/// # Required Length.
+	///
+	/// Return the number of bytes [`NiceEncode::encode_into`] needs to hold
+	/// the value, i.e. its formatted length.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceU16, traits::NiceEncode};
+	///
+	/// assert_eq!(NiceU16::from(1000_u16).required_len(), 5);
+	/// ```
+	fn required_len(&self) -> usize { self.as_ref().len() }
+
+	/// # Encode Into.
+	///
+	/// Copy the formatted value to the beginning of `out`, returning the
+	/// number of bytes written, or `None` (leaving `out` untouched) if it
+	/// doesn't fit.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceClock, traits::NiceEncode};
+	///
+	/// let clock = NiceClock::from(3661_u32);
+	///
+	/// let mut buf = [0_u8; 8];
+	/// assert_eq!(clock.encode_into(&mut buf), Some(8));
+	/// assert_eq!(&buf, b"01:01:01");
+	///
+	/// let mut buf = [0_u8; 7];
+	/// assert_eq!(clock.encode_into(&mut buf), None);
+	/// assert_eq!(buf, [0; 7]);
+	/// ```
+	fn encode_into(&self, out: &mut [u8]) -> Option<usize> {
+		let src = self.as_ref();
+		let len = src.len();
+		out.get_mut(..len)?.copy_from_slice(src);
+		Some(len)
+	}
+
+	#[must_use]
+	#[inline]
+	/// # Into Vec.
+	///
+	/// Return the formatted value as an owned byte vector, allocated once at
+	/// exactly the required length.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceU32, traits::NiceEncode};
+	///
+	/// let v = NiceU32::from(1_234_567_u32).into_vec();
+	/// assert_eq!(v, b"1,234,567");
+	/// assert_eq!(v.capacity(), 9);
+	/// ```
+	fn into_vec(self) -> Vec<u8>
+	where Self: Sized { self.as_ref().to_vec() }
+
+	#[must_use]
+	#[inline]
+	/// # Into String.
+	///
+	/// Return the formatted value as an owned string, allocated once at
+	/// exactly the required length.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NicePercent, traits::NiceEncode};
+	///
+	/// let s = NicePercent::from(0.5_f32).into_string();
+	/// assert_eq!(s, "50.00%");
+	/// assert_eq!(s.capacity(), 6);
+	/// ```
+	fn into_string(self) -> String
+	where Self: Sized + AsRef<str> { <Self as AsRef<str>>::as_ref(&self).to_owned() }
+
+	#[inline]
+	/// # Write To String.
+	///
+	/// Append the formatted value to the end of an existing string.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceClock, NiceU16, traits::NiceEncode};
+	///
+	/// let mut out = String::from("Elapsed: ");
+	/// NiceClock::from(61_u32).write_to(&mut out);
+	/// out.push_str("; Total: ");
+	/// NiceU16::from(1000_u16).write_to(&mut out);
+	/// assert_eq!(out, "Elapsed: 00:01:01; Total: 1,000");
+	/// ```
+	fn write_to(&self, out: &mut String)
+	where Self: AsRef<str> { out.push_str(<Self as AsRef<str>>::as_ref(self)); }
+}
+
+impl<T: NiceMaxLen + AsRef<[u8]>> NiceEncode for T {}
+
+impl NiceEncode for NiceBigint {
+	#[inline]
+	/// # Into Vec.
+	///
+	/// Bigints are heap-allocated already, so the buffer is simply reused.
+	fn into_vec(self) -> Vec<u8> { String::from(self).into_bytes() }
+
+	#[inline]
+	/// # Into String.
+	///
+	/// Bigints are heap-allocated already, so the buffer is simply reused.
+	fn into_string(self) -> String { String::from(self) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		NiceElapsed,
+		NiceFloat,
+		NiceU64,
+	};
+
+	#[test]
+	fn t_encode_into() {
+		let mut rng = fastrand::Rng::new();
+		let mut buf = [0_u8; NiceU64::MAX_LEN];
+		for num in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			let nice = NiceU64::from(num);
+			assert_eq!(nice.required_len(), nice.len());
+			assert_eq!(nice.encode_into(&mut buf), Some(nice.len()));
+			assert_eq!(&buf[..nice.len()], nice.as_bytes());
+
+			// One byte short.
+			assert_eq!(nice.encode_into(&mut buf[..nice.len() - 1]), None);
+		}
+
+		// Other types.
+		let mut buf = [0_u8; 64];
+		let nice = NiceElapsed::from(61_u32);
+		assert_eq!(nice.encode_into(&mut buf), Some(21));
+		assert_eq!(&buf[..21], b"1 minute and 1 second");
+
+		let nice = NiceFloat::from(-0.5_f64);
+		assert_eq!(nice.encode_into(&mut buf), Some(nice.required_len()));
+		assert_eq!(&buf[..nice.required_len()], b"-0.50000000");
+
+		let nice = NiceBigint::from_digits(b"12345678901234567890123").unwrap();
+		assert_eq!(nice.encode_into(&mut buf), Some(30));
+		assert_eq!(&buf[..30], b"12,345,678,901,234,567,890,123");
+
+		// Empty is fine.
+		assert_eq!(NiceU64::empty().encode_into(&mut []), Some(0));
+	}
+
+	#[test]
+	fn t_into_owned() {
+		let mut rng = fastrand::Rng::new();
+		let mut all = String::new();
+		let mut expected = String::new();
+		for num in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(1000) {
+			let nice = NiceU64::from(num);
+			let v = nice.into_vec();
+			assert_eq!(v, nice.as_bytes());
+			assert_eq!(v.capacity(), nice.len());
+
+			let s = nice.into_string();
+			assert_eq!(s, nice.as_str());
+			assert_eq!(s.capacity(), nice.len());
+
+			nice.write_to(&mut all);
+			expected.push_str(nice.as_str());
+		}
+		assert_eq!(all, expected);
+
+		// Other types.
+		let nice = NiceElapsed::from(61_u32);
+		assert_eq!(nice.into_string(), "1 minute and 1 second");
+		assert_eq!(nice.into_vec(), b"1 minute and 1 second");
+
+		let nice = NiceBigint::from_digits(b"12345678901234567890123").unwrap();
+		assert_eq!(nice.clone().into_string(), "12,345,678,901,234,567,890,123");
+		assert_eq!(nice.into_vec(), b"12,345,678,901,234,567,890,123");
+	}
+}
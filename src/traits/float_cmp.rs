@@ -0,0 +1,157 @@
+/*!
+# Dactyl — Float Comparison.
+*/
+
+
+
+/// # Float Comparison.
+///
+/// This trait adds approximate equality checks to `f32` and `f64`, useful
+/// for e.g. asserting that a value survived a round trip through
+/// [`NiceFloat`](crate::NiceFloat) formatting and parsing.
+///
+/// Two flavors are provided:
+/// * [`FloatCompare::approx_eq_ulps`]: the values are at most `ulps` representable floats apart;
+/// * [`FloatCompare::approx_eq_eps`]: the absolute difference is at most `eps`.
+///
+/// In both cases, `NaN` is never equal to anything, including itself, and
+/// infinities are only equal to themselves. Positive and negative zero are
+/// considered equal.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::FloatCompare;
+///
+/// let a = 0.1_f64 + 0.2;
+/// assert_ne!(a, 0.3);
+/// assert!(a.approx_eq_ulps(0.3, 1));
+/// assert!(a.approx_eq_eps(0.3, f64::EPSILON));
+///
+/// assert!(! f64::NAN.approx_eq_ulps(f64::NAN, u32::MAX));
+/// assert!(! f64::MAX.approx_eq_ulps(f64::INFINITY, 1));
+/// ```
+pub trait FloatCompare: Copy {
+	/// # Approximately Equal (ULPs).
+	///
+	/// Returns `true` if `self` and `other` are no more than `ulps` units in
+	/// the last place apart, i.e. there are fewer than `ulps` representable
+	/// values between them.
+	///
+	/// This scales naturally with magnitude, making it a good default.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::FloatCompare;
+	///
+	/// let a = 1.0_f32;
+	/// let b = f32::from_bits(a.to_bits() + 2);
+	/// assert!(! a.approx_eq_ulps(b, 1));
+	/// assert!(a.approx_eq_ulps(b, 2));
+	///
+	/// // The sign doesn't get in the way.
+	/// assert!(0.0_f32.approx_eq_ulps(-0.0, 0));
+	/// assert!(f32::from_bits(1).approx_eq_ulps(-f32::from_bits(1), 2));
+	/// ```
+	fn approx_eq_ulps(self, other: Self, ulps: u32) -> bool;
+
+	/// # Approximately Equal (Epsilon).
+	///
+	/// Returns `true` if the absolute difference between `self` and `other`
+	/// is no more than `eps`.
+	///
+	/// This is mostly useful near zero, where ULPs get very small.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::FloatCompare;
+	///
+	/// assert!(1.0_f64.approx_eq_eps(1.05, 0.1));
+	/// assert!(! 1.0_f64.approx_eq_eps(1.2, 0.1));
+	/// ```
+	fn approx_eq_eps(self, other: Self, eps: Self) -> bool;
+}
+
+
+
+/// # Helper: Generate Impls.
+macro_rules! float {
+	($($float:ty, $signed:ty, $unsigned:ty),+ $(,)?) => ($(
+		impl FloatCompare for $float {
+			#[expect(clippy::cast_possible_wrap, reason = "False positive.")]
+			fn approx_eq_ulps(self, other: Self, ulps: u32) -> bool {
+				/// # Ordered Bits.
+				///
+				/// Map the bits to a signed integer that sorts the same way
+				/// the floats do, with both zeroes landing on zero.
+				const fn ordered(num: $float) -> $signed {
+					let bits = num.to_bits() as $signed;
+					if bits < 0 { <$signed>::MIN - bits }
+					else { bits }
+				}
+
+				if self.is_nan() || other.is_nan() { false }
+				else if self.is_infinite() || other.is_infinite() { self.to_bits() == other.to_bits() }
+				else {
+					ordered(self).abs_diff(ordered(other)) <= <$unsigned>::from(ulps)
+				}
+			}
+
+			fn approx_eq_eps(self, other: Self, eps: Self) -> bool {
+				if self.is_nan() || other.is_nan() { false }
+				else if self.is_infinite() || other.is_infinite() { self.to_bits() == other.to_bits() }
+				else { (self - other).abs() <= eps }
+			}
+		}
+	)+);
+}
+
+float!(f32, i32, u32, f64, i64, u64);
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_approx_eq_ulps() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.f64() * f64::from(rng.i32(..))).take(10_000) {
+			assert!(num.approx_eq_ulps(num, 0));
+
+			// Walk a few steps away from zero.
+			let mut other = num;
+			for step in 1..5 {
+				other = f64::from_bits(other.to_bits() + 1);
+				assert!(num.approx_eq_ulps(other, step), "{num} {other}");
+				assert!(other.approx_eq_ulps(num, step), "{num} {other}");
+				assert!(! num.approx_eq_ulps(other, step - 1), "{num} {other}");
+			}
+		}
+
+		// Across zero.
+		let tiny = f32::from_bits(3);
+		assert!(tiny.approx_eq_ulps(-tiny, 6));
+		assert!(! tiny.approx_eq_ulps(-tiny, 5));
+
+		// Special values.
+		assert!(f64::INFINITY.approx_eq_ulps(f64::INFINITY, 0));
+		assert!(! f64::INFINITY.approx_eq_ulps(f64::NEG_INFINITY, u32::MAX));
+		assert!(! f32::NAN.approx_eq_ulps(1.0, u32::MAX));
+		assert!(f64::MIN.approx_eq_ulps(f64::MIN, 0));
+	}
+
+	#[test]
+	fn t_approx_eq_eps() {
+		assert!(0.1_f32.approx_eq_eps(0.1, 0.0));
+		assert!(0.0_f32.approx_eq_eps(-0.0, 0.0));
+		assert!(0.3_f64.approx_eq_eps(0.1 + 0.2, f64::EPSILON));
+		assert!(! 0.3_f64.approx_eq_eps(0.1 + 0.2, 0.0));
+		assert!(! f64::NAN.approx_eq_eps(f64::NAN, f64::INFINITY));
+		assert!(! f64::MAX.approx_eq_eps(f64::INFINITY, f64::INFINITY));
+		assert!(f32::NEG_INFINITY.approx_eq_eps(f32::NEG_INFINITY, 0.0));
+	}
+}
@@ -2,6 +2,11 @@
 # Dactyl: Hex Decode Trait
 */
 
+use crate::{
+	NiceHex,
+	ParseByteError,
+};
+
 /// # Not Hex Placeholder Value.
 const NIL: u8 = u8::MAX;
 
@@ -52,6 +57,36 @@ static UNHEX: [u8; 256] = [
 pub trait HexToUnsigned: Sized {
 	/// # Hex (Bytes) to Unsigned.
 	fn htou(hex: &[u8]) -> Option<Self>;
+
+	/// # Hex (Bytes) to Unsigned (Checked).
+	///
+	/// This works just like [`HexToUnsigned::htou`], but returns a
+	/// [`ParseByteError`] explaining the failure, if any.
+	///
+	/// Note that because lengths are capped, overlong slices are always
+	/// reported as [`ParseByteError::Overflow`], even if padded with zeroes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{
+	///     ParseByteError,
+	///     traits::HexToUnsigned,
+	/// };
+	///
+	/// assert_eq!(u8::htou_checked(b"0D"), Ok(13));
+	/// assert_eq!(u8::htou_checked(b""), Err(ParseByteError::Empty));
+	/// assert_eq!(u8::htou_checked(b"0G"), Err(ParseByteError::InvalidDigit(1)));
+	/// assert_eq!(u8::htou_checked(b"00D"), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty, contains non-hex characters,
+	/// or is too long for the type.
+	fn htou_checked(hex: &[u8]) -> Result<Self, ParseByteError> {
+		Self::htou(hex).ok_or_else(|| ParseByteError::from_hex(hex))
+	}
 }
 
 impl HexToUnsigned for u8 {
@@ -228,6 +263,32 @@ impl HexToUnsigned for u128 {
 pub trait HexToSigned: Sized {
 	/// # Hex (Bytes) to Signed.
 	fn htoi(hex: &[u8]) -> Option<Self>;
+
+	/// # Hex (Bytes) to Signed (Checked).
+	///
+	/// This works just like [`HexToSigned::htoi`], but returns a
+	/// [`ParseByteError`] explaining the failure, if any.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{
+	///     ParseByteError,
+	///     traits::HexToSigned,
+	/// };
+	///
+	/// assert_eq!(i8::htoi_checked(b"FB"), Ok(-5));
+	/// assert_eq!(i8::htoi_checked(b"-5"), Err(ParseByteError::InvalidDigit(0)));
+	/// assert_eq!(i8::htoi_checked(b"1FB"), Err(ParseByteError::Overflow));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Returns an error if the slice is empty, contains non-hex characters,
+	/// or is too long for the type.
+	fn htoi_checked(hex: &[u8]) -> Result<Self, ParseByteError> {
+		Self::htoi(hex).ok_or_else(|| ParseByteError::from_hex(hex))
+	}
 }
 
 /// # Helper: Signed Impls.
@@ -256,6 +317,67 @@ signed!(isize, usize);
 
 
 
+/// # Unsigned to Hex.
+///
+/// This trait is the formatting counterpart to [`HexToUnsigned`], exposing
+/// methods to encode unsigned integers as (unpadded, unprefixed) hex,
+/// returned as a [`NiceHex`].
+///
+/// The output is guaranteed to round-trip: decoding it with the same type's
+/// [`HexToUnsigned::htou`] always returns the original value, regardless of
+/// case.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::{HexToUnsigned, UnsignedToHex};
+///
+/// assert_eq!(3054_u16.to_hex_lower().as_str(), "bee");
+/// assert_eq!(3054_u16.to_hex_upper().as_str(), "BEE");
+///
+/// let num = u128::MAX - 12_345;
+/// assert_eq!(u128::htou(num.to_hex_upper().as_bytes()), Some(num));
+/// ```
+pub trait UnsignedToHex: Copy {
+	/// # To Hex (Lowercase).
+	///
+	/// Encode the value as lowercase hex.
+	fn to_hex_lower(self) -> NiceHex;
+
+	/// # To Hex (Uppercase).
+	///
+	/// Encode the value as uppercase hex.
+	fn to_hex_upper(self) -> NiceHex;
+}
+
+/// # Helper: Unsigned to Hex Impls.
+macro_rules! to_hex {
+	($($ty:ty),+) => ($(
+		impl UnsignedToHex for $ty {
+			#[inline]
+			/// # To Hex (Lowercase).
+			fn to_hex_lower(self) -> NiceHex { NiceHex::new(u128::from(self), false) }
+
+			#[inline]
+			/// # To Hex (Uppercase).
+			fn to_hex_upper(self) -> NiceHex { NiceHex::new(u128::from(self), true) }
+		}
+	)+);
+}
+
+to_hex!(u8, u16, u32, u64, u128);
+
+impl UnsignedToHex for usize {
+	#[inline]
+	/// # To Hex (Lowercase).
+	fn to_hex_lower(self) -> NiceHex { NiceHex::new(self as u128, false) }
+
+	#[inline]
+	/// # To Hex (Uppercase).
+	fn to_hex_upper(self) -> NiceHex { NiceHex::new(self as u128, true) }
+}
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -309,6 +431,32 @@ mod tests {
 		);
 	}
 
+	macro_rules! round_trip {
+		($tfn:ident, $ty:ident) => (
+			#[test]
+			fn $tfn() {
+				let mut rng = fastrand::Rng::new();
+				for i in std::iter::repeat_with(|| rng.$ty(..) >> rng.u32(0..<$ty>::BITS)).take(SAMPLE_SIZE / 10).chain([0, 1, <$ty>::MAX]) {
+					let lower = i.to_hex_lower();
+					assert_eq!(lower.as_str(), format!("{i:x}"));
+					assert_eq!(<$ty>::htou(lower.as_bytes()), Some(i));
+					assert_eq!(NiceHex::from(i), lower);
+
+					let upper = i.to_hex_upper();
+					assert_eq!(upper.as_str(), format!("{i:X}"));
+					assert_eq!(<$ty>::htou(upper.as_bytes()), Some(i));
+				}
+			}
+		);
+	}
+
+	round_trip!(t_hex_u8, u8);
+	round_trip!(t_hex_u16, u16);
+	round_trip!(t_hex_u32, u32);
+	round_trip!(t_hex_u64, u64);
+	round_trip!(t_hex_u128, u128);
+	round_trip!(t_hex_usize, usize);
+
 	// Test full set for small types.
 	test_all!(t_u8, htou, u8);
 	#[cfg(not(miri))] test_all!(t_u16, htou, u16);
@@ -328,4 +476,21 @@ mod tests {
 	test_rng!(t_i64, htoi, i64);
 	test_rng!(t_i128, htoi, i128);
 	test_rng!(t_isize, htoi, isize);
+
+	#[test]
+	fn t_checked() {
+		macro_rules! checked {
+			($fn:ident, $($ty:ty),+) => ($(
+				let width = std::mem::size_of::<$ty>() * 2;
+				let max = "f".repeat(width);
+				assert!(<$ty>::$fn(max.as_bytes()).is_ok());
+				assert_eq!(<$ty>::$fn(b""), Err(ParseByteError::Empty));
+				assert_eq!(<$ty>::$fn(b"0x1"), Err(ParseByteError::InvalidDigit(1)));
+				assert_eq!(<$ty>::$fn(format!("0{max}").as_bytes()), Err(ParseByteError::Overflow));
+			)+);
+		}
+
+		checked!(htou_checked, u8, u16, u32, u64, u128, usize);
+		checked!(htoi_checked, i8, i16, i32, i64, i128, isize);
+	}
 }
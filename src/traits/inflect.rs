@@ -11,6 +11,14 @@ use crate::{
 };
 use std::{
 	fmt,
+	hash::{
+		Hash,
+		Hasher,
+	},
+	ops::{
+		Deref,
+		RangeInclusive,
+	},
 	num::{
 		NonZeroU8,
 		NonZeroU16,
@@ -81,11 +89,102 @@ pub trait NiceInflection<const S: usize>: Inflection {
 	/// assert_eq!(nice.unit(), "books");
 	/// ```
 	fn nice_inflect<'a>(self, singular: &'a str, plural: &'a str) -> NiceInflected<'a, S>;
+
+	/// # Inflect a String via Callback (Prefixed w/ Value).
+	///
+	/// This is like [`RangeInflection::inflect_with`], but prefixes the
+	/// output with a nicely-formatted representation of the numeric value.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::NiceInflection;
+	///
+	/// let czech = |n: u128| match n {
+	///     1 => "kniha",
+	///     2..=4 => "knihy",
+	///     _ => "knih",
+	/// };
+	/// assert_eq!(3_u32.nice_inflect_with(czech).to_string(), "3 knihy");
+	/// assert_eq!((-1_i32).nice_inflect_with(czech).to_string(), "-1 kniha");
+	/// assert_eq!(1500_u32.nice_inflect_with(czech).to_string(), "1,500 knih");
+	/// ```
+	fn nice_inflect_with<'a, F>(self, cb: F) -> NiceInflected<'a, S>
+	where Self: RangeInflection, F: FnOnce(u128) -> &'a str {
+		let mut out = self.nice_inflect("", "");
+		out.unit = self.inflect_with(cb);
+		out
+	}
+}
+
+/// # Range Inflection.
+///
+/// This trait extends [`Inflection`] to support languages with more than two
+/// plural forms — e.g. Czech, which uses one form for `1`, another for `2–4`,
+/// and a third for everything else — by letting you pick the form yourself,
+/// either with a callback or a table of ranges.
+///
+/// Either way, the choice is made from the _absolute_ value of `self`.
+///
+/// This is implemented for `i/u/NonZeroU 8–128`.
+pub trait RangeInflection: Inflection {
+	/// # Inflect a String via Callback.
+	///
+	/// Pass the absolute value of `self` to the callback, and return whatever
+	/// it returns.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::RangeInflection;
+	///
+	/// // Russian plurals depend on the last digit(s).
+	/// let russian = |n: u128|
+	///     if n % 10 == 1 && n % 100 != 11 { "книга" }
+	///     else if (2..=4).contains(&(n % 10)) && ! (12..=14).contains(&(n % 100)) { "книги" }
+	///     else { "книг" };
+	///
+	/// assert_eq!(1_u8.inflect_with(russian), "книга");
+	/// assert_eq!(21_u8.inflect_with(russian), "книга");
+	/// assert_eq!(3_u8.inflect_with(russian), "книги");
+	/// assert_eq!(12_u8.inflect_with(russian), "книг");
+	/// assert_eq!((-25_i8).inflect_with(russian), "книг");
+	/// ```
+	fn inflect_with<'a, F>(self, cb: F) -> &'a str
+	where F: FnOnce(u128) -> &'a str;
+
+	/// # Inflect a String via Range Table.
+	///
+	/// Return the string paired with the first range containing the absolute
+	/// value of `self`, or `fallback` if none match.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::RangeInflection;
+	///
+	/// let table = [(1..=1, "kniha"), (2..=4, "knihy")];
+	/// assert_eq!(1_u16.inflect_ranges(&table, "knih"), "kniha");
+	/// assert_eq!(4_u16.inflect_ranges(&table, "knih"), "knihy");
+	/// assert_eq!(0_u16.inflect_ranges(&table, "knih"), "knih");
+	/// assert_eq!(5_u16.inflect_ranges(&table, "knih"), "knih");
+	/// ```
+	fn inflect_ranges<'a>(
+		self,
+		table: &[(RangeInclusive<u128>, &'a str)],
+		fallback: &'a str,
+	) -> &'a str {
+		self.inflect_with(|n|
+			table.iter()
+				.find_map(|(r, s)| if r.contains(&n) { Some(*s) } else { None })
+				.unwrap_or(fallback)
+		)
+	}
 }
 
 
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 /// # Nice Inflection Wrapper.
 ///
 /// This struct serves as the return type for [`NiceInflection::nice_inflect`].
@@ -117,6 +216,16 @@ pub struct NiceInflected<'a, const S: usize> {
 	unit: &'a str,
 }
 
+impl<const S: usize> fmt::Debug for NiceInflected<'_, S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NiceInflected")
+			.field("neg", &self.neg)
+			.field("nice", &format_args!("NiceWrapper<{S}>({:?})", self.nice.as_str()))
+			.field("unit", &self.unit)
+			.finish()
+	}
+}
+
 impl<const S: usize> fmt::Display for NiceInflected<'_, S> {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -135,6 +244,7 @@ impl<const S: usize> fmt::Display for NiceInflected<'_, S> {
 }
 
 impl<const S: usize> NiceInflected<'_, S> {
+	#[must_use]
 	/// # Length.
 	///
 	/// Return the length of the string.
@@ -155,9 +265,16 @@ impl<const S: usize> NiceInflected<'_, S> {
 	pub const fn len(&self) -> usize {
 		self.neg as usize + self.nice.len() + 1 + self.unit.len()
 	}
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// This is never true, but here for completeness.
+	pub const fn is_empty(&self) -> bool { false }
 }
 
 impl<const S: usize> NiceInflected<'_, S> {
+	#[must_use]
 	/// Is Negative?
 	///
 	/// Returns `true` if the original number was negative.
@@ -177,6 +294,7 @@ impl<const S: usize> NiceInflected<'_, S> {
 	/// ```
 	pub const fn is_negative(&self) -> bool { self.neg }
 
+	#[must_use]
 	/// Nice Number.
 	///
 	/// Returns the nicely-formatted number.
@@ -194,6 +312,7 @@ impl<const S: usize> NiceInflected<'_, S> {
 	/// ```
 	pub const fn nice(&self) -> NiceWrapper<S> { self.nice }
 
+	#[must_use]
 	/// Inflected Unit.
 	///
 	/// Returns the inflected unit.
@@ -209,6 +328,160 @@ impl<const S: usize> NiceInflected<'_, S> {
 	pub const fn unit(&self) -> &str { self.unit }
 }
 
+impl<const S: usize> NiceInflected<'_, S> {
+	#[must_use]
+	/// # To Buffer.
+	///
+	/// Render the complete phrase — sign, number, space, and unit — into a
+	/// fixed-size, stack-allocated [`NiceInflectedBuf`], from which it can be
+	/// borrowed as a `&str` or `&[u8]` without any heap allocation.
+	///
+	/// The buffer is large enough to hold any number, but the unit is capped
+	/// at [`NiceInflectedBuf::MAX_UNIT`] bytes; `None` is returned if it is
+	/// longer than that.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::NiceInflection;
+	///
+	/// let nice = (-3283_i32).nice_inflect("book", "books");
+	/// let buf = nice.to_buffer().unwrap();
+	/// assert_eq!(buf.as_str(), "-3,283 books");
+	/// assert_eq!(buf.as_str(), nice.to_string());
+	///
+	/// // Units can't be too big, though.
+	/// let nice = 2_u8.nice_inflect("", "pneumonoultramicroscopicsilicovolcanoconioses");
+	/// assert!(nice.to_buffer().is_none());
+	/// ```
+	pub fn to_buffer(&self) -> Option<NiceInflectedBuf> {
+		let unit = self.unit.as_bytes();
+		if NiceInflectedBuf::MAX_UNIT < unit.len() { return None; }
+
+		let mut out = NiceInflectedBuf {
+			inner: [b' '; NiceInflectedBuf::SIZE],
+			len: 0,
+		};
+		if self.neg { out.push(b"-"); }
+		out.push(self.nice.as_bytes());
+		out.push(b" ");
+		out.push(unit);
+		Some(out)
+	}
+}
+
+
+
+#[derive(Clone, Copy)]
+/// # Nice Inflected Buffer.
+///
+/// This struct holds a fully-rendered [`NiceInflected`] phrase, allowing it
+/// to be borrowed as a string slice without allocation. See
+/// [`NiceInflected::to_buffer`] for more details.
+pub struct NiceInflectedBuf {
+	/// # Buffer.
+	inner: [u8; Self::SIZE],
+
+	/// # Length.
+	len: usize,
+}
+
+impl AsRef<[u8]> for NiceInflectedBuf {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl AsRef<str> for NiceInflectedBuf {
+	#[inline]
+	fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl ::std::borrow::Borrow<str> for NiceInflectedBuf {
+	#[inline]
+	fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl fmt::Debug for NiceInflectedBuf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("NiceInflectedBuf")
+			.field(&self.as_str())
+			.finish()
+	}
+}
+
+impl Deref for NiceInflectedBuf {
+	type Target = [u8];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target { self.as_bytes() }
+}
+
+impl fmt::Display for NiceInflectedBuf {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl Eq for NiceInflectedBuf {}
+
+impl Hash for NiceInflectedBuf {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) { state.write(self.as_bytes()); }
+}
+
+impl PartialEq for NiceInflectedBuf {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool { self.as_bytes() == other.as_bytes() }
+}
+
+impl NiceInflectedBuf {
+	/// # Total Buffer Size.
+	const SIZE: usize = 64;
+
+	/// # Maximum Unit Length (Bytes).
+	///
+	/// The sign, longest `NiceU64`, and space take up to 28 bytes, leaving 36
+	/// for the unit.
+	pub const MAX_UNIT: usize = Self::SIZE - 28;
+
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the phrase as a byte slice.
+	pub fn as_bytes(&self) -> &[u8] { &self.inner[..self.len] }
+
+	#[expect(unsafe_code, reason = "Content is valid UTF-8.")]
+	#[must_use]
+	/// # As String.
+	///
+	/// Return the phrase as a string slice.
+	pub fn as_str(&self) -> &str {
+		debug_assert!(
+			std::str::from_utf8(self.as_bytes()).is_ok(),
+			"Bug: NiceInflectedBuf is not UTF."
+		);
+		// Safety: the buffer is made up of ASCII and a complete &str.
+		unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+	}
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the length of the phrase, in bytes.
+	pub const fn len(&self) -> usize { self.len }
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// This is never true, but here for completeness.
+	pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+	/// # Push.
+	fn push(&mut self, src: &[u8]) {
+		self.inner[self.len..self.len + src.len()].copy_from_slice(src);
+		self.len += src.len();
+	}
+}
+
 
 
 /// # Helper: Generate `Inflection` impls.
@@ -308,6 +581,41 @@ inflect_nice!(13, i32,          NiceU32, 1,         unsigned_abs);
 inflect_nice!(26, i64,          NiceU64, 1,         unsigned_abs);
 inflect_nice!(26, isize,        NiceU64, 1,         unsigned_abs);
 
+/// # Helper: Generate `RangeInflection` impls.
+macro_rules! inflect_range {
+	($($ty:ty: $n:ident => $abs:expr),+ $(,)?) => ($(
+		impl RangeInflection for $ty {
+			#[inline]
+			fn inflect_with<'a, F>(self, cb: F) -> &'a str
+			where F: FnOnce(u128) -> &'a str {
+				let $n = self;
+				cb($abs)
+			}
+		}
+	)+);
+}
+
+inflect_range!(
+	u8:           n => u128::from(n),
+	u16:          n => u128::from(n),
+	u32:          n => u128::from(n),
+	u64:          n => u128::from(n),
+	u128:         n => n,
+	usize:        n => n as u128,
+	i8:           n => u128::from(n.unsigned_abs()),
+	i16:          n => u128::from(n.unsigned_abs()),
+	i32:          n => u128::from(n.unsigned_abs()),
+	i64:          n => u128::from(n.unsigned_abs()),
+	i128:         n => n.unsigned_abs(),
+	isize:        n => n.unsigned_abs() as u128,
+	NonZeroU8:    n => u128::from(n.get()),
+	NonZeroU16:   n => u128::from(n.get()),
+	NonZeroU32:   n => u128::from(n.get()),
+	NonZeroU64:   n => u128::from(n.get()),
+	NonZeroU128:  n => n.get(),
+	NonZeroUsize: n => n.get() as u128,
+);
+
 // These aren't nice, but we can still do basic inflection.
 inflect!(u128, 1);
 inflect!(i128, 1, unsigned_abs);
@@ -351,11 +659,13 @@ mod tests {
 	macro_rules! t_nice_inflect {
 		($num:expr, $str:literal) => (
 			t_inflect!($num, $str);
+			let nice = $num.nice_inflect("book", "books");
 			assert_eq!(
-				$num.nice_inflect("book", "books").to_string(),
+				nice.to_string(),
 				format!(concat!("{} ", $str), $num.to_formatted_string(&Locale::en)),
 				"{}.nice_inflect()", $num
 			);
+			assert_eq!(nice.to_buffer().unwrap().as_str(), nice.to_string());
 		);
 	}
 
@@ -496,6 +806,38 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn t_range() {
+		/// # Czech.
+		const CZECH: [(RangeInclusive<u128>, &str); 2] = [(1..=1, "kniha"), (2..=4, "knihy")];
+
+		macro_rules! t_range {
+			($($ty:ty),+) => ($(
+				for (i, expected) in [(0, "knih"), (1, "kniha"), (2, "knihy"), (4, "knihy"), (5, "knih"), (100, "knih")] {
+					let num = <$ty>::try_from(i).unwrap();
+					assert_eq!(num.inflect_ranges(&CZECH, "knih"), expected);
+					assert_eq!(num.inflect_with(|n| { assert_eq!(n, i); expected }), expected);
+				}
+			)+);
+		}
+
+		t_range!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+		// Negatives should be abs'd.
+		assert_eq!((-3_i32).inflect_ranges(&CZECH, "knih"), "knihy");
+		assert_eq!(i128::MIN.inflect_with(|n| { assert_eq!(n, i128::MIN.unsigned_abs()); "x" }), "x");
+
+		// And the nice version.
+		assert_eq!(
+			(-1234_i64).nice_inflect_with(|n| if n == 1234 { "knih" } else { "?" }).to_string(),
+			"-1,234 knih",
+		);
+		assert_eq!(
+			NonZeroU16::new(2).unwrap().nice_inflect_with(|n| if n == 2 { "knihy" } else { "?" }).to_string(),
+			"2 knihy",
+		);
+	}
+
 	#[test]
 	fn t_f32() {
 		t_inflect!(0_f32, "books");
@@ -0,0 +1,195 @@
+/*!
+# Dactyl: Maximum Lengths.
+*/
+
+use crate::{
+	NiceBar,
+	NiceBits,
+	NiceClock,
+	NiceClock12,
+	NiceDateTime,
+	NiceDiff,
+	NiceDivision,
+	NiceElapsed,
+	NiceFixed,
+	NiceFloat,
+	NiceHex,
+	NiceIso8601,
+	NiceMoney,
+	NicePercent,
+	NicePercentChange,
+	NicePercentRatio,
+	NicePow2,
+	NiceShort,
+	NiceSignedPercent,
+	NiceU16,
+	NiceU32,
+	NiceU64,
+	NiceU8,
+};
+
+
+
+/// # Nice Maximum Length.
+///
+/// This trait exposes the maximum formatted length — and an example string of
+/// that length — for each of the `Nice*` types, allowing generic code to size
+/// fixed arrays and the like at compile time.
+///
+/// The same values are also available as inherent associated constants on
+/// each type, e.g. [`NiceU64::MAX_LEN`](crate::NiceU64::MAX_LEN).
+///
+/// See also [`max_len_for`].
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceU16, traits::NiceMaxLen};
+///
+/// fn max_len<T: NiceMaxLen>() -> usize { T::MAX_LEN }
+///
+/// assert_eq!(max_len::<NiceU16>(), 6);
+/// assert_eq!(<NiceU16 as NiceMaxLen>::MAX_STR, "65,535");
+/// ```
+pub trait NiceMaxLen {
+	/// # Maximum Length (in bytes).
+	const MAX_LEN: usize;
+
+	/// # Maximum-Length Example.
+	const MAX_STR: &'static str;
+}
+
+#[must_use]
+/// # Maximum Length For.
+///
+/// Return the maximum formatted length — in bytes — of a given `Nice*` type.
+///
+/// Because this is a `const fn`, it can be used to size arrays.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{max_len_for, NiceU32, NiceU64};
+///
+/// const LINE: usize = max_len_for::<NiceU32>() + 1 + max_len_for::<NiceU64>();
+/// let buf = [0_u8; LINE];
+/// assert_eq!(buf.len(), 40);
+/// ```
+pub const fn max_len_for<T: NiceMaxLen>() -> usize { T::MAX_LEN }
+
+/// # Helper: Maximum Length Constants.
+macro_rules! max_len {
+	($($nice:ident $max:literal),+ $(,)?) => ($(
+		impl $nice {
+			/// # Maximum Length (in bytes).
+			///
+			/// Note that custom separators, if any, are always single bytes,
+			/// so do not affect the length.
+			///
+			/// ```
+			#[doc = concat!("use dactyl::", stringify!($nice), ";")]
+			///
+			#[doc = concat!("assert_eq!(", stringify!($nice), "::MAX_LEN, ", stringify!($nice), "::MAX_STR.len());")]
+			/// ```
+			pub const MAX_LEN: usize = $max.len();
+
+			/// # Maximum-Length Example.
+			///
+			#[doc = concat!("`", $max, "`")]
+			pub const MAX_STR: &'static str = $max;
+		}
+
+		impl NiceMaxLen for $nice {
+			const MAX_LEN: usize = <$nice>::MAX_LEN;
+			const MAX_STR: &'static str = <$nice>::MAX_STR;
+		}
+	)+);
+}
+
+max_len!(
+	NiceU8 "255",
+	NiceU16 "65,535",
+	NiceU32 "4,294,967,295",
+	NiceU64 "18,446,744,073,709,551,615",
+	NiceFloat "-18,446,744,073,709,551,615.99999999",
+	NiceDivision "18,446,744,073,709,551,615.00000000",
+	NiceFixed "-9,223,372,036,854,775,808",
+	NiceMoney "(US$ 9,223,372,036,854,775,808)",
+	NicePercent "100.00%",
+	NiceSignedPercent "-100.00%",
+	NicePercentChange "-92,233,720,368,547,758.08%",
+	NicePercentRatio "99,999.99%",
+	NiceShort "18446.744Q",
+	NicePow2 "2^63 (9,223,372,036,854,775,808)",
+	NiceHex "ffffffffffffffffffffffffffffffff",
+	NiceDiff "-18,446,744,073,709,551,615",
+	NiceBits "64 bits (0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111)",
+	NiceBar "[================================================================] 100.00%",
+	NiceClock "23:59:59",
+	NiceClock12 "11:59:59 PM",
+	NiceDateTime "9999-12-31 23:59:59",
+	NiceElapsed "49,709 days, 23 hours, 59 minutes, and 59.999999999 seconds",
+	NiceIso8601 "PT5124095576030430H59M59.999S",
+);
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		FloatKind,
+		Scale,
+	};
+	use std::time::Duration;
+
+	#[test]
+	fn t_max_len() {
+		/// # Seconds for the longest ISO-8601.
+		const SECS: u64 = 5_124_095_576_030_430 * 3600 + 3599;
+
+		assert_eq!(NiceU8::MAX.as_str(), NiceU8::MAX_STR);
+		assert_eq!(NiceU16::MAX.as_str(), NiceU16::MAX_STR);
+		assert_eq!(NiceU32::MAX.as_str(), NiceU32::MAX_STR);
+		assert_eq!(NiceU64::MAX.as_str(), NiceU64::MAX_STR);
+		assert_eq!(
+			NiceFloat::from(FloatKind::Normal(u64::MAX, 99_999_999, true)).as_str(),
+			NiceFloat::MAX_STR,
+		);
+		assert_eq!(NiceDivision::decimal(u64::MAX, 1, 8).unwrap().as_str(), NiceDivision::MAX_STR);
+		assert_eq!(NiceFixed::from_scaled(i64::MIN, 0).as_str(), NiceFixed::MAX_STR);
+		assert_eq!(
+			NiceMoney::from_scaled(i64::MIN, 0).with_prefix("US$ ").with_parentheses(true).as_str(),
+			NiceMoney::MAX_STR,
+		);
+		assert_eq!(NicePercent::MAX.as_str(), NicePercent::MAX_STR);
+		assert_eq!(NiceSignedPercent::MIN.as_str(), NiceSignedPercent::MAX_STR);
+		assert_eq!(NicePercentChange::new(-1.0, f64::MIN).as_str(), NicePercentChange::MAX_STR);
+		assert_eq!(NicePercentRatio::MAX.as_str(), NicePercentRatio::MAX_STR);
+		assert_eq!(NiceShort::with_scale(u64::MAX, Scale::Short, 3).as_str(), NiceShort::MAX_STR);
+		assert_eq!(NicePow2::from(1_u64 << 63).as_str(), NicePow2::MAX_STR);
+		assert_eq!(NiceBits::from(u64::MAX).as_str(), NiceBits::MAX_STR);
+		assert_eq!(NiceHex::from(u128::MAX).as_str(), NiceHex::MAX_STR);
+		assert_eq!(NiceDiff::new(u64::MAX, 0).as_str(), NiceDiff::MAX_STR);
+		assert_eq!(NiceBar::with_width(1, 1, u8::MAX).as_str(), NiceBar::MAX_STR);
+		assert_eq!(NiceClock::MAX.as_str(), NiceClock::MAX_STR);
+		assert_eq!(NiceClock12::MAX.as_str(), NiceClock12::MAX_STR);
+		assert_eq!(NiceDateTime::MAX.as_str(), NiceDateTime::MAX_STR);
+		assert_eq!(
+			NiceElapsed::from_duration_precise(Duration::new(u64::from(u32::MAX - 23_296), 999_999_999), 9).as_str(),
+			NiceElapsed::MAX_STR,
+		);
+		assert_eq!(
+			NiceIso8601::from(Duration::new(SECS, 999_000_000)).as_str(),
+			NiceIso8601::MAX_STR,
+		);
+
+		// The lengths should match the buffer sizes.
+		assert_eq!(max_len_for::<NiceU8>(), 3);
+		assert_eq!(max_len_for::<NiceU64>(), NiceU64::MAX_LEN);
+		assert_eq!(max_len_for::<NiceFloat>(), 36);
+		assert_eq!(max_len_for::<NiceBar>(), 74);
+		assert_eq!(max_len_for::<NiceElapsed>(), 59);
+		assert_eq!(max_len_for::<NiceIso8601>(), 29);
+	}
+}
@@ -2,22 +2,65 @@
 # Dactyl: Traits
 */
 
+mod btod;
+mod btof;
 mod btoi;
 mod btou;
+mod digits;
+mod encode;
+mod float_cmp;
 mod hex;
 mod inflect;
 mod intdiv;
+mod max_len;
+mod nice_duration;
 mod saturating_from;
+mod saturating_ops;
+mod to_nice;
+mod write_io;
 
+pub use btod::BytesToDuration;
+pub use btof::BytesToFloat;
 pub use btoi::BytesToSigned;
-pub use btou::BytesToUnsigned;
+pub use btou::{
+	BytesToUnsigned,
+	BytesToUnsignedFixed,
+};
+pub use digits::{
+	luhn_checksum,
+	UnsignedDigits,
+};
+pub use encode::NiceEncode;
+pub use float_cmp::FloatCompare;
 pub use hex::{
 	HexToSigned,
 	HexToUnsigned,
+	UnsignedToHex,
 };
 pub use inflect::{
 	Inflection,
+	NiceInflected,
+	NiceInflectedBuf,
 	NiceInflection,
+	RangeInflection,
 };
 pub use intdiv::IntDivFloat;
-pub use saturating_from::SaturatingFrom;
+pub use max_len::{
+	max_len_for,
+	NiceMaxLen,
+};
+pub use nice_duration::NiceDuration;
+pub use saturating_from::{
+	saturating_cast_into,
+	saturating_cast_slice,
+	SaturatingFrom,
+};
+pub use saturating_ops::{
+	SaturatingAdd,
+	SaturatingMul,
+	SaturatingSub,
+};
+pub use to_nice::ToNice;
+pub use write_io::NiceWriteIo;
+
+pub(crate) use digits::digit_count;
@@ -0,0 +1,96 @@
+/*!
+# Dactyl: Nice Duration.
+*/
+
+use crate::{
+	NiceClock,
+	NiceElapsed,
+};
+use std::time::{
+	Duration,
+	Instant,
+};
+
+
+
+/// # Nice Duration.
+///
+/// This trait is implemented for the various "time-like" types supported by
+/// both [`NiceElapsed`] and [`NiceClock`] — [`Duration`], [`Instant`] (time
+/// elapsed since), and unsigned integers (seconds) — allowing generic code to
+/// accept any of them and leave the choice of rendering to the call site.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::NiceDuration;
+/// use std::time::Duration;
+///
+/// fn report<T: NiceDuration>(src: T, verbose: bool) -> String {
+///     if verbose { src.nice_elapsed().to_string() }
+///     else { src.nice_clock().to_string() }
+/// }
+///
+/// assert_eq!(report(3661_u32, true), "1 hour, 1 minute, and 1 second");
+/// assert_eq!(report(3661_u32, false), "01:01:01");
+/// assert_eq!(report(Duration::from_secs(90), false), "00:01:30");
+/// ```
+pub trait NiceDuration {
+	/// # Nice Elapsed.
+	///
+	/// Format the value as a [`NiceElapsed`], e.g. `1 hour and 2 minutes`.
+	fn nice_elapsed(&self) -> NiceElapsed;
+
+	/// # Nice Clock.
+	///
+	/// Format the value as a [`NiceClock`], e.g. `01:02:00`.
+	fn nice_clock(&self) -> NiceClock;
+}
+
+/// # Helper: Generate Impls.
+macro_rules! nice_duration {
+	($($ty:ty),+ $(,)?) => ($(
+		impl NiceDuration for $ty {
+			#[inline]
+			/// # Nice Elapsed.
+			fn nice_elapsed(&self) -> NiceElapsed { NiceElapsed::from(*self) }
+
+			#[inline]
+			/// # Nice Clock.
+			fn nice_clock(&self) -> NiceClock { NiceClock::from(*self) }
+		}
+	)+);
+}
+
+nice_duration!(Duration, Instant, u32, u64, usize);
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_nice_duration() {
+		let mut rng = fastrand::Rng::new();
+		for secs in std::iter::repeat_with(|| rng.u32(..) >> rng.u32(0..32)).take(10_000) {
+			let dur = Duration::from_secs(u64::from(secs));
+			let elapsed = NiceElapsed::from(secs);
+			let clock = NiceClock::from(secs);
+
+			assert_eq!(secs.nice_elapsed(), elapsed);
+			assert_eq!(u64::from(secs).nice_elapsed(), elapsed);
+			assert_eq!((secs as usize).nice_elapsed(), elapsed);
+			assert_eq!(dur.nice_elapsed(), elapsed);
+
+			assert_eq!(secs.nice_clock(), clock);
+			assert_eq!(u64::from(secs).nice_clock(), clock);
+			assert_eq!((secs as usize).nice_clock(), clock);
+			assert_eq!(dur.nice_clock(), clock);
+		}
+
+		// Instants are relative to now, so should be tiny.
+		let now = Instant::now();
+		assert_eq!(now.nice_clock(), NiceClock::MIN);
+	}
+}
@@ -22,6 +22,18 @@ assert_eq!(u8::saturating_from(-1026_i32), 0_u8);
 // Just right.
 assert_eq!(u8::saturating_from(99_u64), 99_u8);
 ```
+
+`NonZero` types are supported too. As sources, they convert like their
+primitive equivalents; as (unsigned) targets, zero saturates up to `MIN` (one).
+
+```
+use dactyl::traits::SaturatingFrom;
+use std::num::{NonZeroU8, NonZeroU32};
+
+assert_eq!(NonZeroU8::saturating_from(0_i32), NonZeroU8::MIN);
+assert_eq!(NonZeroU8::saturating_from(1026_u16), NonZeroU8::MAX);
+assert_eq!(u8::saturating_from(NonZeroU32::MAX), u8::MAX);
+```
 */
 
 #![expect(
@@ -32,6 +44,21 @@ assert_eq!(u8::saturating_from(99_u64), 99_u8);
 	reason = "We're doing a lot of this here.",
 )]
 
+use std::num::{
+	NonZeroI8,
+	NonZeroI16,
+	NonZeroI32,
+	NonZeroI64,
+	NonZeroI128,
+	NonZeroIsize,
+	NonZeroU8,
+	NonZeroU16,
+	NonZeroU32,
+	NonZeroU64,
+	NonZeroU128,
+	NonZeroUsize,
+};
+
 
 
 /// # Saturating From.
@@ -44,6 +71,33 @@ pub trait SaturatingFrom<T> {
 	/// Convert `T` to `Self`, clamping to `Self::MIN..=Self::MAX` as required
 	/// to prevent overflow or wrapping.
 	fn saturating_from(src: T) -> Self;
+
+	#[inline]
+	/// # Saturating From (w/ Overflow).
+	///
+	/// This works just like [`SaturatingFrom::saturating_from`], but also
+	/// returns a `bool` indicating whether or not clamping was required, e.g.
+	/// for keeping count of out-of-range inputs.
+	///
+	/// This is available for all integer (and `NonZero`) pairs.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::SaturatingFrom;
+	/// use std::num::NonZeroU8;
+	///
+	/// assert_eq!(u8::saturating_from_overflow(99_u64), (99, false));
+	/// assert_eq!(u8::saturating_from_overflow(1026_u16), (255, true));
+	/// assert_eq!(u8::saturating_from_overflow(-5_i32), (0, true));
+	/// assert_eq!(NonZeroU8::saturating_from_overflow(0_u32), (NonZeroU8::MIN, true));
+	/// ```
+	fn saturating_from_overflow(src: T) -> (Self, bool)
+	where Self: Copy, T: SaturatingFrom<Self> + Copy + PartialEq {
+		// If the value was in range, it'll survive the round trip.
+		let out = Self::saturating_from(src);
+		(out, T::saturating_from(out) != src)
+	}
 }
 
 // All the integer conversions are built at compile-time.
@@ -67,6 +121,158 @@ macro_rules! float {
 float!(f32, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 float!(f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
+/// # Helper: Generate `NonZero` Target Impls.
+///
+/// These piggy-back off the primitive conversions, bumping zero up to one.
+///
+/// Note that `usize`/`isize` sources are covered by the sized blanket
+/// implementations, and `NonZero*` sources by the blanket implementations
+/// below.
+macro_rules! nz_into {
+	(@impl $to:ty, $prim:ty, $($from:ty),+) => ($(
+		impl SaturatingFrom<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating From `", stringify!($from), "`")]
+			#[doc = ""]
+			#[doc = concat!("This method will safely recast any `", stringify!($from), "` into a `", stringify!($to), "`, clamping the values to `", stringify!($to), "::MIN..=", stringify!($to), "::MAX` to prevent overflow, wrapping, or zero.")]
+			fn saturating_from(src: $from) -> Self {
+				Self::new(<$prim>::saturating_from(src)).unwrap_or(Self::MIN)
+			}
+		}
+	)+);
+	($($to:ty, $prim:ty),+ $(,)?) => ($(
+		nz_into!(@impl $to, $prim, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+	)+);
+}
+
+nz_into!(
+	NonZeroU8, u8,
+	NonZeroU16, u16,
+	NonZeroU32, u32,
+	NonZeroU64, u64,
+	NonZeroU128, u128,
+	NonZeroUsize, usize,
+);
+
+/// # Helper: Generate `NonZero` Source Impls.
+///
+/// These use the primitive equivalent as a go-between.
+macro_rules! nz_from {
+	($($from:ty, $prim:ty),+ $(,)?) => ($(
+		impl<T: SaturatingFrom<$prim>> SaturatingFrom<$from> for T {
+			#[inline]
+			#[doc = concat!("# Saturating From `", stringify!($from), "`")]
+			#[doc = ""]
+			#[doc = concat!("This blanket implementation uses `", stringify!($prim), "` as a go-between.")]
+			fn saturating_from(src: $from) -> T { T::saturating_from(src.get()) }
+		}
+	)+);
+}
+
+nz_from!(
+	NonZeroU8, u8,
+	NonZeroU16, u16,
+	NonZeroU32, u32,
+	NonZeroU64, u64,
+	NonZeroU128, u128,
+	NonZeroUsize, usize,
+	NonZeroI8, i8,
+	NonZeroI16, i16,
+	NonZeroI32, i32,
+	NonZeroI64, i64,
+	NonZeroI128, i128,
+	NonZeroIsize, isize,
+);
+
+
+
+/// # Chunk Size.
+///
+/// The slice helpers work through their data in chunks of this size to give
+/// the compiler a better shot at vectorizing the conversions.
+const CHUNK: usize = 16;
+
+#[must_use]
+/// # Saturating Cast (Slice).
+///
+/// Convert each value in a slice via [`SaturatingFrom`], collecting the
+/// results into a new `Vec`.
+///
+/// This is handy for bulk conversions, like downsampling audio or image data
+/// from one bit depth to another.
+///
+/// See [`saturating_cast_into`] for a non-allocating alternative.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::saturating_cast_slice;
+///
+/// let src: &[u32] = &[0, 255, 65_535, 65_536, u32::MAX];
+/// assert_eq!(
+///     saturating_cast_slice::<u16, _>(src),
+///     [0_u16, 255, 65_535, 65_535, 65_535],
+/// );
+///
+/// let src: &[i32] = &[-5, 0, 5, 500];
+/// assert_eq!(saturating_cast_slice::<u8, _>(src), [0_u8, 0, 5, 255]);
+/// ```
+pub fn saturating_cast_slice<T, S>(src: &[S]) -> Vec<T>
+where T: SaturatingFrom<S>, S: Copy {
+	let mut out = Vec::with_capacity(src.len());
+	let chunks = src.chunks_exact(CHUNK);
+	let rem = chunks.remainder();
+	for chunk in chunks {
+		out.extend(chunk.iter().map(|&v| T::saturating_from(v)));
+	}
+	out.extend(rem.iter().map(|&v| T::saturating_from(v)));
+	out
+}
+
+/// # Saturating Cast (Slice, In Place).
+///
+/// Convert each value in `src` via [`SaturatingFrom`], writing the results
+/// to the corresponding positions in `dst`.
+///
+/// If the slices have different lengths, only the overlapping portion —
+/// `min(src.len(), dst.len())` — is converted; any extra values in `dst` are
+/// left as-is.
+///
+/// Returns the number of values written.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::saturating_cast_into;
+///
+/// let src: &[u32] = &[0, 255, 65_535, 65_536, u32::MAX];
+/// let mut dst = [1_u16; 6];
+/// assert_eq!(saturating_cast_into(src, &mut dst), 5);
+/// assert_eq!(dst, [0, 255, 65_535, 65_535, 65_535, 1]);
+///
+/// // Shorter destinations are fine too.
+/// let mut dst = [0_u8; 2];
+/// assert_eq!(saturating_cast_into(src, &mut dst), 2);
+/// assert_eq!(dst, [0, 255]);
+/// ```
+pub fn saturating_cast_into<T, S>(src: &[S], dst: &mut [T]) -> usize
+where T: SaturatingFrom<S>, S: Copy {
+	let len = src.len().min(dst.len());
+	let (src, dst) = (&src[..len], &mut dst[..len]);
+
+	let mut src_chunks = src.chunks_exact(CHUNK);
+	let mut dst_chunks = dst.chunks_exact_mut(CHUNK);
+	for (a, b) in src_chunks.by_ref().zip(dst_chunks.by_ref()) {
+		for (&v, d) in a.iter().zip(b) { *d = T::saturating_from(v); }
+	}
+
+	for (&v, d) in src_chunks.remainder().iter().zip(dst_chunks.into_remainder()) {
+		*d = T::saturating_from(v);
+	}
+
+	len
+}
+
 
 
 #[cfg(test)]
@@ -514,6 +720,35 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn t_saturating_nonzero() {
+		// Into NonZero.
+		assert_eq!(NonZeroU8::saturating_from(0_u64), NonZeroU8::MIN);
+		assert_eq!(NonZeroU8::saturating_from(-5_i8), NonZeroU8::MIN);
+		assert_eq!(NonZeroU8::saturating_from(-5.5_f32), NonZeroU8::MIN);
+		assert_eq!(NonZeroU8::saturating_from(300_u16), NonZeroU8::MAX);
+		assert_eq!(NonZeroU8::saturating_from(12_usize).get(), 12);
+		assert_eq!(NonZeroU32::saturating_from(0_isize), NonZeroU32::MIN);
+		assert_eq!(NonZeroU32::saturating_from(u128::MAX), NonZeroU32::MAX);
+		assert_eq!(NonZeroU64::saturating_from(i64::MAX).get(), i64::MAX as u64);
+		assert_eq!(NonZeroU128::saturating_from(i128::MIN), NonZeroU128::MIN);
+		assert_eq!(NonZeroUsize::saturating_from(0_u8), NonZeroUsize::MIN);
+
+		// From NonZero.
+		assert_eq!(u8::saturating_from(NonZeroU16::MAX), u8::MAX);
+		assert_eq!(u16::saturating_from(NonZeroU8::MAX), 255_u16);
+		assert_eq!(u32::saturating_from(NonZeroI32::MIN), 0_u32);
+		assert_eq!(i8::saturating_from(NonZeroI64::MIN), i8::MIN);
+		assert_eq!(usize::saturating_from(NonZeroU128::MAX), usize::MAX);
+		assert_eq!(isize::saturating_from(NonZeroIsize::MIN), isize::MIN);
+
+		// NonZero to NonZero.
+		assert_eq!(NonZeroU8::saturating_from(NonZeroU64::MAX), NonZeroU8::MAX);
+		assert_eq!(NonZeroU8::saturating_from(NonZeroI16::MIN), NonZeroU8::MIN);
+		assert_eq!(NonZeroU64::saturating_from(NonZeroU8::MAX).get(), 255);
+		assert_eq!(NonZeroUsize::saturating_from(NonZeroUsize::MAX), NonZeroUsize::MAX);
+	}
+
 	#[cfg(target_pointer_width = "16")]
 	#[test]
 	fn t_saturating_sized16() {
@@ -603,4 +838,67 @@ mod tests {
 			cast_assert_max!(usize, i, i128, u128);
 		}
 	}
+
+	#[test]
+	fn t_saturating_from_overflow() {
+		/// # Helper: Compare Against `TryFrom`.
+		macro_rules! overflow {
+			($raw:ident, $from:ty, $($to:ty),+) => ($(
+				assert_eq!(
+					<$to>::saturating_from_overflow($raw),
+					(<$to>::saturating_from($raw), <$to>::try_from($raw).is_err()),
+					concat!("{}_", stringify!($from), " to ", stringify!($to), "."),
+					$raw,
+				);
+			)+);
+		}
+
+		let mut rng = fastrand::Rng::new();
+		for i in std::iter::repeat_with(|| rng.i64(..) >> rng.u32(0..64)).take(SAMPLE_SIZE / 10) {
+			overflow!(i, i64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+			let i = i as u64;
+			overflow!(i, u64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+		}
+
+		for i in [i128::MIN, -1, 0, 1, i128::MAX] {
+			overflow!(i, i128, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+		}
+
+		// NonZero targets and sources.
+		assert_eq!(NonZeroU16::saturating_from_overflow(0_u8), (NonZeroU16::MIN, true));
+		assert_eq!(NonZeroU16::saturating_from_overflow(5_i64), (NonZeroU16::new(5).unwrap(), false));
+		assert_eq!(NonZeroU16::saturating_from_overflow(u32::MAX), (NonZeroU16::MAX, true));
+		assert_eq!(u8::saturating_from_overflow(NonZeroU32::MAX), (u8::MAX, true));
+		assert_eq!(u64::saturating_from_overflow(NonZeroU32::MAX), (u64::from(u32::MAX), false));
+	}
+
+	#[test]
+	fn t_saturating_cast_slice() {
+		let mut rng = fastrand::Rng::new();
+
+		// Test a variety of lengths to cover both chunks and remainders.
+		for len in [0, 1, CHUNK - 1, CHUNK, CHUNK + 1, CHUNK * 3 + 5, 1000] {
+			let src: Vec<i32> = std::iter::repeat_with(|| rng.i32(..)).take(len).collect();
+			let expected: Vec<u16> = src.iter().map(|&v| u16::saturating_from(v)).collect();
+
+			assert_eq!(saturating_cast_slice::<u16, _>(&src), expected);
+
+			let mut dst = vec![0_u16; len];
+			assert_eq!(saturating_cast_into(&src, &mut dst), len);
+			assert_eq!(dst, expected);
+
+			// Longer destination.
+			let mut dst = vec![7_u16; len + 3];
+			assert_eq!(saturating_cast_into(&src, &mut dst), len);
+			assert_eq!(dst[..len], expected);
+			assert_eq!(dst[len..], [7, 7, 7]);
+
+			// Shorter destination.
+			let half = len / 2;
+			let mut dst = vec![0_u16; half];
+			assert_eq!(saturating_cast_into(&src, &mut dst), half);
+			assert_eq!(dst, expected[..half]);
+		}
+	}
 }
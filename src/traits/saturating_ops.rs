@@ -0,0 +1,358 @@
+/*!
+# Dactyl: Saturating Arithmetic (Mixed Width).
+
+The `SaturatingAdd`, `SaturatingSub`, and `SaturatingMul` traits extend the
+standard library's saturating arithmetic to operands of _any_ primitive
+integer type, computing the exact result before clamping it to
+`Self::MIN..=Self::MAX`.
+
+This is handy for accumulating big or signed values into narrower counters
+without an intermediate [`SaturatingFrom`] conversion (which, for e.g. a
+negative operand, could give the wrong answer anyway).
+
+## Examples
+
+```
+use dactyl::traits::{
+    SaturatingAdd,
+    SaturatingMul,
+    SaturatingSub,
+};
+
+// Too big.
+assert_eq!(250_u8.saturating_add_from(1_000_u64), u8::MAX);
+
+// Negative operands work too.
+assert_eq!(250_u8.saturating_add_from(-50_i32), 200);
+assert_eq!(5_u8.saturating_sub_from(-10_i64), 15);
+
+// Too small.
+assert_eq!(5_u8.saturating_sub_from(10_u128), 0);
+assert_eq!((-5_i8).saturating_mul_from(1_000_u32), i8::MIN);
+```
+*/
+
+use super::SaturatingFrom;
+
+
+
+/// # Saturating Add (Mixed Width).
+///
+/// Add a value of any primitive integer type, clamping the result to
+/// `Self::MIN..=Self::MAX` to prevent overflow or wrapping.
+pub trait SaturatingAdd<T> {
+	#[must_use]
+	/// # Saturating Add From.
+	///
+	/// Return `self + rhs`, clamped to `Self::MIN..=Self::MAX`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::SaturatingAdd;
+	///
+	/// let mut total = 0_u16;
+	/// for n in [40_000_u64, 20_000, 10_000] {
+	///     total = total.saturating_add_from(n);
+	/// }
+	/// assert_eq!(total, u16::MAX);
+	/// ```
+	fn saturating_add_from(self, rhs: T) -> Self;
+}
+
+/// # Saturating Sub (Mixed Width).
+///
+/// Subtract a value of any primitive integer type, clamping the result to
+/// `Self::MIN..=Self::MAX` to prevent overflow or wrapping.
+pub trait SaturatingSub<T> {
+	#[must_use]
+	/// # Saturating Sub From.
+	///
+	/// Return `self - rhs`, clamped to `Self::MIN..=Self::MAX`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::SaturatingSub;
+	///
+	/// assert_eq!(10_u32.saturating_sub_from(u64::MAX), 0);
+	/// assert_eq!(10_i8.saturating_sub_from(100_u64), -90);
+	/// ```
+	fn saturating_sub_from(self, rhs: T) -> Self;
+}
+
+/// # Saturating Mul (Mixed Width).
+///
+/// Multiply by a value of any primitive integer type, clamping the result to
+/// `Self::MIN..=Self::MAX` to prevent overflow or wrapping.
+pub trait SaturatingMul<T> {
+	#[must_use]
+	/// # Saturating Mul From.
+	///
+	/// Return `self * rhs`, clamped to `Self::MIN..=Self::MAX`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::traits::SaturatingMul;
+	///
+	/// assert_eq!(3_u8.saturating_mul_from(50_i64), 150);
+	/// assert_eq!(3_u8.saturating_mul_from(-50_i64), 0);
+	/// assert_eq!(3_i16.saturating_mul_from(u128::MAX), i16::MAX);
+	/// ```
+	fn saturating_mul_from(self, rhs: T) -> Self;
+}
+
+/// # Helper: Generate Impls.
+///
+/// The build script works out which arm applies to each integer pair.
+macro_rules! saturating_ops {
+	// Widen both sides to a type big enough for either.
+	($to:ty, $from:ty, $wide:ty) => (
+		impl SaturatingAdd<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Add From `", stringify!($from), "`.")]
+			fn saturating_add_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$wide>::from(self).saturating_add(<$wide>::from(rhs)))
+			}
+		}
+
+		impl SaturatingSub<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Sub From `", stringify!($from), "`.")]
+			fn saturating_sub_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$wide>::from(self).saturating_sub(<$wide>::from(rhs)))
+			}
+		}
+
+		impl SaturatingMul<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Mul From `", stringify!($from), "`.")]
+			fn saturating_mul_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$wide>::from(self).saturating_mul(<$wide>::from(rhs)))
+			}
+		}
+	);
+
+	// Signed into `u128`; nothing is wide enough, so work from the sign.
+	(@unsigned $from:ty) => (
+		impl SaturatingAdd<$from> for u128 {
+			#[inline]
+			#[doc = concat!("# Saturating Add From `", stringify!($from), "`.")]
+			fn saturating_add_from(self, rhs: $from) -> Self {
+				let abs = Self::from(rhs.unsigned_abs());
+				if rhs < 0 { self.saturating_sub(abs) }
+				else { self.saturating_add(abs) }
+			}
+		}
+
+		impl SaturatingSub<$from> for u128 {
+			#[inline]
+			#[doc = concat!("# Saturating Sub From `", stringify!($from), "`.")]
+			fn saturating_sub_from(self, rhs: $from) -> Self {
+				let abs = Self::from(rhs.unsigned_abs());
+				if rhs < 0 { self.saturating_add(abs) }
+				else { self.saturating_sub(abs) }
+			}
+		}
+
+		impl SaturatingMul<$from> for u128 {
+			#[inline]
+			#[doc = concat!("# Saturating Mul From `", stringify!($from), "`.")]
+			fn saturating_mul_from(self, rhs: $from) -> Self {
+				if rhs < 0 { 0 }
+				else { self.saturating_mul(Self::from(rhs.unsigned_abs())) }
+			}
+		}
+	);
+
+	// `u128` into signed; nothing is wide enough, so work from the sign.
+	(@u128 $to:ty) => (
+		impl SaturatingAdd<u128> for $to {
+			#[inline]
+			/// # Saturating Add From `u128`.
+			fn saturating_add_from(self, rhs: u128) -> Self {
+				Self::saturating_from(i128::from(self).saturating_add_unsigned(rhs))
+			}
+		}
+
+		impl SaturatingSub<u128> for $to {
+			#[inline]
+			/// # Saturating Sub From `u128`.
+			fn saturating_sub_from(self, rhs: u128) -> Self {
+				Self::saturating_from(i128::from(self).saturating_sub_unsigned(rhs))
+			}
+		}
+
+		impl SaturatingMul<u128> for $to {
+			#[inline]
+			/// # Saturating Mul From `u128`.
+			fn saturating_mul_from(self, rhs: u128) -> Self {
+				match i128::try_from(rhs) {
+					Ok(rhs) => Self::saturating_from(i128::from(self).saturating_mul(rhs)),
+					// The magnitude is too big for anything but zero.
+					Err(_) => match self.cmp(&0) {
+						std::cmp::Ordering::Less => Self::MIN,
+						std::cmp::Ordering::Equal => 0,
+						std::cmp::Ordering::Greater => Self::MAX,
+					},
+				}
+			}
+		}
+	);
+
+	// Sized targets use their fixed-size equivalent as a go-between.
+	(@sized $to:ty as $alias:ty, $($from:ty),+) => ($(
+		impl SaturatingAdd<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Add From `", stringify!($from), "`.")]
+			fn saturating_add_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$alias>::saturating_from(self).saturating_add_from(rhs))
+			}
+		}
+
+		impl SaturatingSub<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Sub From `", stringify!($from), "`.")]
+			fn saturating_sub_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$alias>::saturating_from(self).saturating_sub_from(rhs))
+			}
+		}
+
+		impl SaturatingMul<$from> for $to {
+			#[inline]
+			#[doc = concat!("# Saturating Mul From `", stringify!($from), "`.")]
+			fn saturating_mul_from(self, rhs: $from) -> Self {
+				Self::saturating_from(<$alias>::saturating_from(self).saturating_mul_from(rhs))
+			}
+		}
+	)+);
+
+	// As do sized operands, via blanket implementations.
+	(@blanket $from:ty as $alias:ty) => (
+		impl<T: SaturatingAdd<$alias>> SaturatingAdd<$from> for T {
+			#[inline]
+			#[doc = concat!("# Saturating Add From `", stringify!($from), "`.")]
+			#[doc = ""]
+			#[doc = concat!("This blanket implementation uses `", stringify!($alias), "` as a go-between, since it is equivalent to `", stringify!($from), "`.")]
+			fn saturating_add_from(self, rhs: $from) -> Self {
+				self.saturating_add_from(<$alias>::saturating_from(rhs))
+			}
+		}
+
+		impl<T: SaturatingSub<$alias>> SaturatingSub<$from> for T {
+			#[inline]
+			#[doc = concat!("# Saturating Sub From `", stringify!($from), "`.")]
+			#[doc = ""]
+			#[doc = concat!("This blanket implementation uses `", stringify!($alias), "` as a go-between, since it is equivalent to `", stringify!($from), "`.")]
+			fn saturating_sub_from(self, rhs: $from) -> Self {
+				self.saturating_sub_from(<$alias>::saturating_from(rhs))
+			}
+		}
+
+		impl<T: SaturatingMul<$alias>> SaturatingMul<$from> for T {
+			#[inline]
+			#[doc = concat!("# Saturating Mul From `", stringify!($from), "`.")]
+			#[doc = ""]
+			#[doc = concat!("This blanket implementation uses `", stringify!($alias), "` as a go-between, since it is equivalent to `", stringify!($from), "`.")]
+			fn saturating_mul_from(self, rhs: $from) -> Self {
+				self.saturating_mul_from(<$alias>::saturating_from(rhs))
+			}
+		}
+	);
+}
+
+// All the integer pairings are worked out at compile-time.
+include!(concat!(env!("OUT_DIR"), "/dactyl-saturating-ops.rs"));
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Helper: Check Against `i128`/`u128` Math.
+	///
+	/// For pairs that fit, the exact answer can be computed in a wider type
+	/// and clamped after the fact.
+	macro_rules! t_ops {
+		($rng:ident, $to:ident, $($from:ident),+) => ($(
+			for _ in 0..1000 {
+				let a = $rng.$to(..);
+				let b = $rng.$from(..);
+				let (wa, wb) = (i128::from(a), i128::from(b));
+				assert_eq!(
+					a.saturating_add_from(b),
+					<$to>::saturating_from(wa + wb),
+					concat!("{}_", stringify!($to), " + {}_", stringify!($from)), a, b,
+				);
+				assert_eq!(
+					a.saturating_sub_from(b),
+					<$to>::saturating_from(wa - wb),
+					concat!("{}_", stringify!($to), " - {}_", stringify!($from)), a, b,
+				);
+				assert_eq!(
+					a.saturating_mul_from(b),
+					<$to>::saturating_from(wa.saturating_mul(wb)),
+					concat!("{}_", stringify!($to), " * {}_", stringify!($from)), a, b,
+				);
+			}
+		)+);
+	}
+
+	#[test]
+	fn t_saturating_ops() {
+		let mut rng = fastrand::Rng::new();
+		t_ops!(rng, u8,  u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, u16, u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, u32, u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, i8,  u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, i16, u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, i32, u8, u16, u32, i8, i16, i32, i64);
+		t_ops!(rng, i64, u8, u16, u32, i8, i16, i32);
+	}
+
+	#[test]
+	fn t_saturating_ops_128() {
+		// u128 with signed operands.
+		assert_eq!(5_u128.saturating_add_from(-10_i8), 0);
+		assert_eq!(5_u128.saturating_add_from(10_i128), 15);
+		assert_eq!(u128::MAX.saturating_add_from(1_i8), u128::MAX);
+		assert_eq!(u128::MAX.saturating_add_from(i128::MIN), u128::MAX >> 1);
+		assert_eq!(5_u128.saturating_sub_from(-10_i64), 15);
+		assert_eq!(5_u128.saturating_sub_from(10_i64), 0);
+		assert_eq!(u128::MAX.saturating_sub_from(-1_i32), u128::MAX);
+		assert_eq!(5_u128.saturating_mul_from(-1_i16), 0);
+		assert_eq!(5_u128.saturating_mul_from(3_i16), 15);
+		assert_eq!(u128::MAX.saturating_mul_from(2_i128), u128::MAX);
+
+		// Signed with u128 operands.
+		assert_eq!(i128::MIN.saturating_add_from(u128::MAX >> 1), -1);
+		assert_eq!(i128::MIN.saturating_add_from(u128::MAX), i128::MAX);
+		assert_eq!((-5_i8).saturating_add_from(10_u128), 5);
+		assert_eq!((-5_i8).saturating_add_from(u128::MAX), i8::MAX);
+		assert_eq!(5_i8.saturating_sub_from(10_u128), -5);
+		assert_eq!(i128::MAX.saturating_sub_from(u128::MAX), i128::MIN);
+		assert_eq!((-1_i128).saturating_mul_from(u128::MAX), i128::MIN);
+		assert_eq!(0_i128.saturating_mul_from(u128::MAX), 0);
+		assert_eq!(1_i32.saturating_mul_from(u128::MAX), i32::MAX);
+		assert_eq!((-3_i32).saturating_mul_from(5_u128), -15);
+
+		// u128 with u128.
+		assert_eq!(u128::MAX.saturating_add_from(1_u128), u128::MAX);
+		assert_eq!(1_u8.saturating_sub_from(u128::MAX), 0);
+		assert_eq!(1_u8.saturating_add_from(u128::MAX), u8::MAX);
+	}
+
+	#[test]
+	fn t_saturating_ops_sized() {
+		assert_eq!(5_usize.saturating_add_from(-10_i8), 0);
+		assert_eq!(5_usize.saturating_add_from(u128::MAX), usize::MAX);
+		assert_eq!(5_isize.saturating_sub_from(10_u8), -5);
+		assert_eq!(5_isize.saturating_mul_from(-2_isize), -10);
+		assert_eq!(5_u8.saturating_add_from(10_usize), 15);
+		assert_eq!(5_u8.saturating_sub_from(-10_isize), 15);
+		assert_eq!(200_u8.saturating_mul_from(usize::MAX), u8::MAX);
+		assert_eq!(usize::MAX.saturating_add_from(usize::MAX), usize::MAX);
+	}
+}
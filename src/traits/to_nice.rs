@@ -0,0 +1,128 @@
+/*!
+# Dactyl: To Nice.
+*/
+
+use crate::{
+	NiceSeparator,
+	NiceU8,
+	NiceU16,
+	NiceU32,
+	NiceU64,
+};
+
+
+
+/// # To Nice.
+///
+/// This extension trait lets unsigned integers format themselves, which can
+/// read a little more fluently than calling the corresponding `Nice*::from`
+/// directly.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::{NiceSeparator, NiceU64, traits::ToNice};
+///
+/// let size = 1_234_567_usize;
+/// assert_eq!(size.nice(), NiceU64::from(size));
+/// assert_eq!(size.nice().as_str(), "1,234,567");
+/// assert_eq!(size.nice_sep(NiceSeparator::Underscore).as_str(), "1_234_567");
+/// ```
+pub trait ToNice: Copy {
+	/// # Nice Type.
+	type Nice;
+
+	/// # Nice.
+	///
+	/// Format the value with commas at each thousand.
+	fn nice(self) -> Self::Nice;
+
+	/// # Nice (Custom Separator).
+	///
+	/// Format the value with an alternative [`NiceSeparator`].
+	fn nice_sep<S: Into<NiceSeparator>>(self, sep: S) -> Self::Nice;
+}
+
+impl ToNice for u8 {
+	type Nice = NiceU8;
+
+	#[inline]
+	/// # Nice.
+	fn nice(self) -> Self::Nice { NiceU8::from(self) }
+
+	#[inline]
+	/// # Nice (Custom Separator).
+	///
+	/// A `u8` is never big enough to need a separator, so this is the same
+	/// as [`ToNice::nice`].
+	fn nice_sep<S: Into<NiceSeparator>>(self, _sep: S) -> Self::Nice { NiceU8::from(self) }
+}
+
+/// # Helper: Generate Impls.
+macro_rules! to_nice {
+	($($ty:ty, $nice:ty),+ $(,)?) => ($(
+		impl ToNice for $ty {
+			type Nice = $nice;
+
+			#[inline]
+			/// # Nice.
+			fn nice(self) -> Self::Nice { <$nice>::from(self) }
+
+			#[inline]
+			/// # Nice (Custom Separator).
+			fn nice_sep<S: Into<NiceSeparator>>(self, sep: S) -> Self::Nice {
+				<$nice>::with_nice_separator(self, sep)
+			}
+		}
+	)+);
+}
+
+to_nice!(u16, NiceU16, u32, NiceU32, u64, NiceU64);
+
+impl ToNice for usize {
+	type Nice = NiceU64;
+
+	#[inline]
+	/// # Nice.
+	fn nice(self) -> Self::Nice { NiceU64::from(self) }
+
+	#[inline]
+	/// # Nice (Custom Separator).
+	fn nice_sep<S: Into<NiceSeparator>>(self, sep: S) -> Self::Nice {
+		NiceU64::with_nice_separator(self as u64, sep)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_to_nice() {
+		let mut rng = fastrand::Rng::new();
+		for num in std::iter::repeat_with(|| rng.u64(..) >> rng.u32(0..64)).take(10_000) {
+			assert_eq!(num.nice(), NiceU64::from(num));
+			assert_eq!(num.nice_sep(NiceSeparator::Comma), NiceU64::from(num));
+			assert_eq!(num.nice_sep(NiceSeparator::Space), NiceU64::with_const_separator::<b' '>(num));
+
+			if let Ok(n) = usize::try_from(num) {
+				assert_eq!(n.nice(), NiceU64::from(num));
+				assert_eq!(n.nice_sep(NiceSeparator::Period), NiceU64::with_const_separator::<b'.'>(num));
+			}
+			if let Ok(n) = u32::try_from(num) {
+				assert_eq!(n.nice(), NiceU32::from(n));
+				assert_eq!(n.nice_sep(NiceSeparator::Apostrophe), NiceU32::with_const_separator::<b'\''>(n));
+			}
+			if let Ok(n) = u16::try_from(num) {
+				assert_eq!(n.nice(), NiceU16::from(n));
+				assert_eq!(n.nice_sep(NiceSeparator::Underscore), NiceU16::with_const_separator::<b'_'>(n));
+			}
+			if let Ok(n) = u8::try_from(num) {
+				assert_eq!(n.nice(), NiceU8::from(n));
+				assert_eq!(n.nice_sep(NiceSeparator::Underscore), NiceU8::from(n));
+			}
+		}
+	}
+}
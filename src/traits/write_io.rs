@@ -0,0 +1,153 @@
+/*!
+# Dactyl: I/O Writing.
+*/
+
+use crate::{
+	NiceFloat,
+	NicePercent,
+	NiceU8,
+	NiceU16,
+	NiceU32,
+	NiceU64,
+	traits::NiceMaxLen,
+};
+use std::io;
+
+
+
+/// # Helper: Write Methods.
+///
+/// The `NiceU*` methods are all basically the same.
+macro_rules! write_nice {
+	($($fn:ident $ty:ty, $nice:ident, $num:literal, $expected:literal),+ $(,)?) => ($(
+		/// # Write Nice Number.
+		///
+		#[doc = concat!("Format a `", stringify!($ty), "` as a [`", stringify!($nice), "`] and write it to `self`.")]
+		///
+		/// Returns the number of bytes written.
+		///
+		/// ## Examples
+		///
+		/// ```
+		/// use dactyl::traits::NiceWriteIo;
+		///
+		/// let mut out = Vec::new();
+		#[doc = concat!("assert_eq!(out.", stringify!($fn), "(", stringify!($num), ").unwrap(), ", $expected, ".len());")]
+		#[doc = concat!("assert_eq!(out, ", $expected, ".as_bytes());")]
+		/// ```
+		///
+		/// ## Errors
+		///
+		/// This will bubble up any errors returned by the writer.
+		fn $fn(&mut self, num: $ty) -> io::Result<usize> {
+			self.write_nice(&$nice::from(num))
+		}
+	)+);
+}
+
+
+
+/// # Nice I/O Writing.
+///
+/// This extension trait — implemented for everything implementing
+/// [`io::Write`] — allows `Nice*` values to be written straight from their
+/// fixed buffers, without any intermediate `String` allocations.
+///
+/// All methods return the number of bytes written, and bubble up any errors
+/// from the underlying writer.
+///
+/// ## Examples
+///
+/// ```
+/// use dactyl::traits::NiceWriteIo;
+/// use std::io::Write;
+///
+/// let mut out = Vec::new();
+/// out.write_all(b"Total: ").unwrap();
+/// out.write_nice_u64(12_345_678).unwrap();
+/// out.write_all(b" (").unwrap();
+/// out.write_nice_percent(0.5).unwrap();
+/// out.write_all(b")").unwrap();
+///
+/// assert_eq!(out, b"Total: 12,345,678 (50.00%)");
+/// ```
+pub trait NiceWriteIo: io::Write {
+	/// # Write Nice Value.
+	///
+	/// Write any of the `Nice*` types to `self`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use dactyl::{NiceClock, traits::NiceWriteIo};
+	///
+	/// let mut out = Vec::new();
+	/// assert_eq!(out.write_nice(&NiceClock::from(3661_u32)).unwrap(), 8);
+	/// assert_eq!(out, b"01:01:01");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors returned by the writer.
+	fn write_nice<T: NiceMaxLen + AsRef<[u8]>>(&mut self, nice: &T) -> io::Result<usize> {
+		let bytes = nice.as_ref();
+		self.write_all(bytes)?;
+		Ok(bytes.len())
+	}
+
+	write_nice!(
+		write_nice_u8 u8, NiceU8, 255, "\"255\"",
+		write_nice_u16 u16, NiceU16, 12345, "\"12,345\"",
+		write_nice_u32 u32, NiceU32, 1234567, "\"1,234,567\"",
+		write_nice_u64 u64, NiceU64, 1234567890, "\"1,234,567,890\"",
+		write_nice_usize usize, NiceU64, 1234567890, "\"1,234,567,890\"",
+		write_nice_float f64, NiceFloat, 1234.5, "\"1,234.50000000\"",
+		write_nice_percent f32, NicePercent, 0.125, "\"12.50%\"",
+	);
+}
+
+impl<W: io::Write + ?Sized> NiceWriteIo for W {}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NiceElapsed;
+
+	#[test]
+	fn t_write_nice() {
+		let mut out = Vec::new();
+		for num in [0_u64, 255, 1000, 1_234_567, u64::MAX] {
+			let nice = NiceU64::from(num);
+			assert_eq!(out.write_nice_u64(num).unwrap(), nice.len());
+			out.push(b' ');
+			assert_eq!(out.write_nice(&nice).unwrap(), nice.len());
+			out.push(b' ');
+			if let Ok(num) = u8::try_from(num) {
+				assert_eq!(out.write_nice_u8(num).unwrap(), nice.len());
+				out.push(b' ');
+			}
+			out.push(b'\n');
+		}
+		assert_eq!(
+			std::str::from_utf8(&out).unwrap(),
+			"0 0 0 \n\
+			255 255 255 \n\
+			1,000 1,000 \n\
+			1,234,567 1,234,567 \n\
+			18,446,744,073,709,551,615 18,446,744,073,709,551,615 \n",
+		);
+
+		// Not just numbers.
+		out.clear();
+		assert_eq!(out.write_nice(&NiceElapsed::from(61_u32)).unwrap(), 21);
+		assert_eq!(out, b"1 minute and 1 second");
+
+		// Slices are writers too.
+		let mut buf = [0_u8; 4];
+		let mut slice = &mut buf[..];
+		assert!(slice.write_nice_u16(1000).is_err());
+		assert_eq!(buf, *b"1,00");
+	}
+}
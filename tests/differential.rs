@@ -0,0 +1,367 @@
+/*!
+# Dactyl: Differential Tests.
+
+This test binary throws random values at the various `Nice*` types and
+traits, comparing their output byte-for-byte against reference
+implementations from `std` and `num-format`.
+
+It is slow, so is only built when the `differential` feature is enabled:
+
+```bash
+cargo test --release --features differential --test differential
+```
+*/
+
+use dactyl::{
+	NiceClock,
+	NiceElapsed,
+	NiceFloat,
+	NicePercent,
+	NiceSeparator,
+	NiceU16,
+	NiceU32,
+	NiceU64,
+	NiceU8,
+	traits::{
+		BytesToSigned,
+		BytesToUnsigned,
+		HexToUnsigned,
+		NiceInflection,
+		SaturatingFrom,
+	},
+};
+use num_format::{
+	Locale,
+	ToFormattedString,
+};
+
+
+
+#[cfg(not(miri))]
+/// # Sample Size.
+const SAMPLE_SIZE: usize = 500_000;
+
+#[cfg(miri)]
+/// # Sample Size.
+const SAMPLE_SIZE: usize = 250;
+
+/// # Separators.
+///
+/// Custom thousands separators to test alongside the default comma.
+const SEPARATORS: [NiceSeparator; 4] = [
+	NiceSeparator::Underscore,
+	NiceSeparator::Period,
+	NiceSeparator::Space,
+	NiceSeparator::Apostrophe,
+];
+
+
+
+/// # Integer Tests.
+///
+/// Compare the integer wrappers against `num-format`, with and without
+/// custom separators, and make sure replacement arrives at the same place.
+macro_rules! nice_int {
+	($fn:ident, $nice:ty, $ty:ty, $rng:ident) => (
+		#[test]
+		fn $fn() {
+			let mut rng = fastrand::Rng::new();
+			let mut last = <$nice>::MIN;
+
+			let iter = [<$ty>::MIN, <$ty>::MAX].into_iter()
+				.chain(std::iter::repeat_with(|| rng.$rng(..)).take(SAMPLE_SIZE));
+			for i in iter {
+				let expected = i.to_formatted_string(&Locale::en);
+				let nice = <$nice>::from(i);
+				assert_eq!(nice.as_str(), expected, "{i}");
+				assert_eq!(nice.as_bytes(), expected.as_bytes(), "{i}");
+				assert_eq!(nice.to_string(), expected, "{i}");
+
+				last.replace(i);
+				assert_eq!(last, nice, "{i}");
+
+				for sep in SEPARATORS {
+					let sep_char = char::from(sep.as_byte());
+					let expected = expected.replace(',', &sep_char.to_string());
+					assert_eq!(
+						<$nice>::with_nice_separator(i, sep).as_str(),
+						expected,
+						"{i} ({sep_char})",
+					);
+				}
+			}
+		}
+	);
+}
+
+nice_int!(t_nice_u16, NiceU16, u16, u16);
+nice_int!(t_nice_u32, NiceU32, u32, u32);
+nice_int!(t_nice_u64, NiceU64, u64, u64);
+
+#[test]
+fn t_nice_u8() {
+	let mut last = NiceU8::MIN;
+	for i in 0..=u8::MAX {
+		let expected = i.to_string();
+		let nice = NiceU8::from(i);
+		assert_eq!(nice.as_str(), expected);
+		assert_eq!(nice.as_str2(), format!("{i:02}"));
+		assert_eq!(nice.as_str3(), format!("{i:03}"));
+
+		last.replace(i);
+		assert_eq!(last, nice);
+	}
+}
+
+#[test]
+fn t_nice_float() {
+	let mut rng = fastrand::Rng::new();
+
+	// Integers should match num-format, with eight zeroes tacked on.
+	for i in std::iter::repeat_with(|| rng.u64(0..(1_u64 << 53))).take(SAMPLE_SIZE) {
+		#[expect(clippy::cast_precision_loss, reason = "Fits in the mantissa.")]
+		let float = i as f64;
+		let expected = format!("{}.00000000", i.to_formatted_string(&Locale::en));
+		assert_eq!(NiceFloat::from(float).as_str(), expected, "{i}");
+		assert_eq!(NiceFloat::from(-float).as_str(), if i == 0 { expected } else { format!("-{expected}") }, "-{i}");
+	}
+
+	// Fractions should match std's (correctly-rounded) formatting so long as
+	// the value is exactly representable at eight decimal places. Random
+	// halves, quarters, eighths, etc., fit the bill.
+	for _ in 0..SAMPLE_SIZE {
+		let top = if rng.bool() { 0 } else { rng.u32(..) };
+		let bottom = rng.u8(..);
+		let float = f64::from(top) + f64::from(bottom) / 256.0;
+		for (float, sep, point) in [
+			(float, NiceSeparator::Comma, b'.'),
+			(-float, NiceSeparator::Period, b','),
+		] {
+			let mut expected = format!("{float:.8}");
+			let (int, frac) = expected.split_once('.').unwrap();
+			let (neg, int) = int.strip_prefix('-').map_or((false, int), |i| (true, i));
+			let int = int.parse::<u64>().unwrap().to_formatted_string(&Locale::en);
+			expected = format!(
+				"{}{}{}{frac}",
+				if neg && float != 0.0 { "-" } else { "" },
+				int.replace(',', &char::from(sep.as_byte()).to_string()),
+				char::from(point),
+			);
+
+			assert_eq!(
+				NiceFloat::with_nice_separator(float, sep, point).as_str(),
+				expected,
+				"{float}",
+			);
+		}
+	}
+
+	// Arbitrary floats are a mess, but their integer parts should at least
+	// agree with a simple cast.
+	for float in std::iter::repeat_with(|| rng.f64() * 1_000_000.0).take(SAMPLE_SIZE) {
+		#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, reason = "Fits.")]
+		let int = (float as u64).to_formatted_string(&Locale::en);
+		let nice = NiceFloat::from(float);
+		let (a, _) = nice.as_str().split_once('.').unwrap();
+
+		// Rounding can bump the integer by one.
+		if a != int {
+			#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, reason = "Fits.")]
+			let int = (float as u64 + 1).to_formatted_string(&Locale::en);
+			assert_eq!(a, int, "{float}");
+			assert!(nice.as_str().ends_with(".00000000"), "{float}");
+		}
+	}
+}
+
+#[test]
+fn t_nice_percent() {
+	let mut rng = fastrand::Rng::new();
+	let mut last = NicePercent::MIN;
+
+	// Stick with hundredths of a percent to keep float rounding from muddying
+	// the comparison.
+	for i in std::iter::repeat_with(|| rng.u16(0..=10_000)).take(SAMPLE_SIZE) {
+		let fraction = f64::from(i) / 10_000.0;
+		let expected = format!("{:.2}%", f64::from(i) / 100.0);
+		let nice = NicePercent::from(fraction);
+		assert_eq!(nice.as_str(), expected, "{i}");
+
+		#[expect(clippy::cast_possible_truncation, reason = "Close enough.")]
+		last.replace(fraction as f32);
+		assert_eq!(last, nice, "{i}");
+
+		assert_eq!(
+			NicePercent::try_from((u32::from(i), 10_000_u32)).ok(),
+			Some(nice),
+			"{i}",
+		);
+	}
+}
+
+#[test]
+fn t_nice_clock() {
+	let mut rng = fastrand::Rng::new();
+	let mut last = NiceClock::MIN;
+
+	let iter = [0, 59, 60, 3599, 3600, 86_399, 86_400, u32::MAX].into_iter()
+		.chain(std::iter::repeat_with(|| rng.u32(0..200_000)).take(SAMPLE_SIZE));
+	for i in iter {
+		let (h, m, s) =
+			if i < 86_400 { (i / 3600, (i % 3600) / 60, i % 60) }
+			else { (23, 59, 59) };
+		let expected = format!("{h:02}:{m:02}:{s:02}");
+		let nice = NiceClock::from(i);
+		assert_eq!(nice.as_str(), expected, "{i}");
+
+		last.replace(i);
+		assert_eq!(last, nice, "{i}");
+	}
+}
+
+#[test]
+fn t_nice_elapsed() {
+	/// # Reference Implementation.
+	fn elapsed(num: u32) -> String {
+		if num == 0 { return "0 seconds".to_owned(); }
+
+		let d = num / 86_400;
+		let h = (num % 86_400) / 3600;
+		let m = (num % 3600) / 60;
+		let s = num % 60;
+
+		let parts: Vec<String> = [(d, "day"), (h, "hour"), (m, "minute"), (s, "second")]
+			.into_iter()
+			.filter(|(n, _)| 0 != *n)
+			.map(|(n, unit)| format!("{n} {unit}{}", if n == 1 { "" } else { "s" }))
+			.collect();
+
+		match parts.len() {
+			1 => parts[0].clone(),
+			2 => format!("{} and {}", parts[0], parts[1]),
+			n => format!("{}, and {}", parts[..n - 1].join(", "), parts[n - 1]),
+		}
+	}
+
+	let mut rng = fastrand::Rng::new();
+	let iter = [0, 1, 59, 60, 61, 3600, 3661, 86_399, 86_400, 90_061].into_iter()
+		.chain(std::iter::repeat_with(|| rng.u32(0..10_000_000)).take(SAMPLE_SIZE));
+	for i in iter {
+		assert_eq!(NiceElapsed::from(i).as_str(), elapsed(i), "{i}");
+	}
+}
+
+#[test]
+fn t_inflection() {
+	let mut rng = fastrand::Rng::new();
+	for i in std::iter::repeat_with(|| rng.u64(..)).take(SAMPLE_SIZE) {
+		let expected = format!(
+			"{} {}",
+			i.to_formatted_string(&Locale::en),
+			if i == 1 { "book" } else { "books" },
+		);
+		assert_eq!(i.nice_inflect("book", "books").to_string(), expected, "{i}");
+	}
+}
+
+/// # Parsing Tests.
+///
+/// Build random byte strings from a small alphabet and make sure the dactyl
+/// parsers agree with `str::parse`. (The unsigned parsers, unlike `std`, do
+/// not accept a leading `+`.)
+macro_rules! parse {
+	($fn:ident, $trait:ident, $method:ident, $plus:literal, $($ty:ty),+) => (
+		#[test]
+		fn $fn() {
+			const ALPHABET: &[u8] = b"00000123456789+-a ";
+
+			let mut rng = fastrand::Rng::new();
+			let mut buf = Vec::with_capacity(48);
+			for _ in 0..SAMPLE_SIZE {
+				buf.clear();
+				let len = rng.usize(0..=45);
+				buf.extend(
+					std::iter::repeat_with(|| ALPHABET[rng.usize(..ALPHABET.len())])
+						.take(len)
+				);
+				let s = std::str::from_utf8(&buf).unwrap();
+
+				$(
+					let expected = s.parse::<$ty>().ok();
+					assert_eq!(
+						<$ty as $trait>::$method(&buf),
+						if ! $plus && s.starts_with('+') { None }
+						else { expected },
+						"{s:?} ({})", stringify!($ty),
+					);
+				)+
+			}
+
+			// Valid numbers are the interesting part, so make sure we get
+			// plenty of those too.
+			$(
+				for i in std::iter::repeat_with(|| rng.i128(..)).take(SAMPLE_SIZE) {
+					let s = i.to_string();
+					assert_eq!(
+						<$ty as $trait>::$method(s.as_bytes()),
+						s.parse::<$ty>().ok(),
+						"{s} ({})", stringify!($ty),
+					);
+				}
+			)+
+		}
+	);
+}
+
+parse!(t_btou, BytesToUnsigned, btou, false, u8, u16, u32, u64, u128, usize);
+parse!(t_btoi, BytesToSigned, btoi, true, i8, i16, i32, i64, i128, isize);
+
+/// # Hex Tests.
+macro_rules! hex {
+	($($ty:ty, $rng:ident),+) => (
+		#[test]
+		fn t_htou() {
+			let mut rng = fastrand::Rng::new();
+			$(
+				for i in std::iter::repeat_with(|| rng.$rng(..)).take(SAMPLE_SIZE) {
+					for s in [format!("{i:x}"), format!("{i:X}"), format!("{i:0w$x}", w = size_of::<$ty>() * 2)] {
+						assert_eq!(
+							<$ty>::htou(s.as_bytes()),
+							<$ty>::from_str_radix(&s, 16).ok(),
+							"{s} ({})", stringify!($ty),
+						);
+					}
+				}
+			)+
+		}
+	);
+}
+
+hex!(u8, u8, u16, u16, u32, u32, u64, u64, u128, u128);
+
+/// # Saturation Tests.
+///
+/// Compare saturating casts against a `TryFrom`-based clamp.
+macro_rules! saturating {
+	($fn:ident, $from:ty, $rng:ident, $($to:ty),+) => (
+		#[test]
+		fn $fn() {
+			let mut rng = fastrand::Rng::new();
+			let iter = [<$from>::MIN, <$from>::MAX, 0].into_iter()
+				.chain(std::iter::repeat_with(|| rng.$rng(..)).take(SAMPLE_SIZE));
+			for i in iter {
+				$(
+					let expected = <$to>::try_from(i).unwrap_or_else(|_|
+						if i <= 0 { <$to>::MIN } else { <$to>::MAX }
+					);
+					assert_eq!(<$to>::saturating_from(i), expected, "{i} ({})", stringify!($to));
+				)+
+			}
+		}
+	);
+}
+
+saturating!(t_saturating_u64, u64, u64, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+saturating!(t_saturating_i64, i64, i64, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+saturating!(t_saturating_u128, u128, u128, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+saturating!(t_saturating_i128, i128, i128, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);